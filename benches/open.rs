@@ -0,0 +1,129 @@
+//! Measures the time and retained memory of opening an epub with a
+//! large package, such as an image-heavy book with 10,000 manifest items.
+//!
+//! Run using `cargo bench --bench open`.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::io::{Cursor, Write};
+use std::sync::atomic::{AtomicIsize, Ordering};
+use std::time::{Duration, Instant};
+
+use rbook::Ebook;
+
+const ITEMS: usize = 10_000;
+const RUNS: usize = 20;
+
+// Tracks the allocations currently alive to measure retained memory
+struct CountingAllocator;
+
+static LIVE_BYTES: AtomicIsize = AtomicIsize::new(0);
+static LIVE_ALLOCATIONS: AtomicIsize = AtomicIsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        LIVE_BYTES.fetch_add(layout.size() as isize, Ordering::Relaxed);
+        LIVE_ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        LIVE_BYTES.fetch_sub(layout.size() as isize, Ordering::Relaxed);
+        LIVE_ALLOCATIONS.fetch_sub(1, Ordering::Relaxed);
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn main() {
+    let data = build_epub();
+    let mut times = Vec::with_capacity(RUNS);
+    let mut retained = (0, 0);
+
+    for _ in 0..RUNS {
+        let cursor = Cursor::new(data.clone());
+        let bytes = LIVE_BYTES.load(Ordering::Relaxed);
+        let allocations = LIVE_ALLOCATIONS.load(Ordering::Relaxed);
+
+        let start = Instant::now();
+        let epub = rbook::Epub::read_from(cursor).unwrap();
+        times.push(start.elapsed());
+
+        retained = (
+            LIVE_BYTES.load(Ordering::Relaxed) - bytes,
+            LIVE_ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        );
+        assert_eq!(ITEMS + 1, epub.manifest().len());
+    }
+
+    times.sort();
+    println!("open ({ITEMS} manifest items, {RUNS} runs)");
+    println!("  median:   {:?}", times[RUNS / 2]);
+    println!("  fastest:  {:?}", times[0]);
+    println!(
+        "  retained: {} KiB in {} allocations",
+        retained.0 / 1024,
+        retained.1
+    );
+
+    let total: Duration = times.iter().sum();
+    println!("  mean:     {:?}", total / RUNS as u32);
+}
+
+fn build_epub() -> Vec<u8> {
+    let mut package = String::from(
+        r#"<package version="3.0" unique-identifier="uid">
+        <metadata>
+            <dc:identifier id="uid">id</dc:identifier>
+            <dc:title>Benchmark</dc:title>
+            <dc:language>en</dc:language>
+        </metadata>
+        <manifest>
+            <item id="nav" href="nav.xhtml" media-type="application/xhtml+xml" properties="nav"/>
+"#,
+    );
+    let mut spine = String::from("<spine>\n");
+
+    for index in 0..ITEMS {
+        let (href, media_type) = match index % 2 {
+            0 => (format!("images/{index}.jpg"), "image/jpeg"),
+            _ => (format!("text/{index}.xhtml"), "application/xhtml+xml"),
+        };
+        package.push_str(&format!(
+            "<item id=\"item{index}\" href=\"{href}\" media-type=\"{media_type}\"/>\n"
+        ));
+        if media_type != "image/jpeg" {
+            spine.push_str(&format!("<itemref idref=\"item{index}\"/>\n"));
+        }
+    }
+    package.push_str("</manifest>\n");
+    package.push_str(&spine);
+    package.push_str("</spine>\n</package>");
+
+    let files = [
+        ("mimetype", "application/epub+zip".to_string()),
+        (
+            "META-INF/container.xml",
+            r#"<container><rootfiles>
+    <rootfile full-path="content.opf" media-type="application/oebps-package+xml"/>
+</rootfiles></container>"#
+                .to_string(),
+        ),
+        ("content.opf", package),
+        (
+            "nav.xhtml",
+            r#"<nav epub:type="toc"><ol><li><a href="text/1.xhtml">1</a></li></ol></nav>"#
+                .to_string(),
+        ),
+    ];
+
+    let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    for (name, data) in files {
+        writer
+            .start_file(name, zip::write::FileOptions::default())
+            .unwrap();
+        writer.write_all(data.as_bytes()).unwrap();
+    }
+    writer.finish().unwrap().into_inner()
+}
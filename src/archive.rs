@@ -1,28 +1,71 @@
 use std::borrow::Cow;
-use std::fs;
-use std::io::{Read, Seek};
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{BufReader, Cursor, Read, Seek};
+use std::num::NonZeroUsize;
+use std::ops::Range;
 use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
 use thiserror::Error;
 use zip::{self, read};
 
+use crate::formats::epub::{ContentDecryptor, FilenameDecoding};
 use crate::formats::EbookError;
-use crate::utility::{self, Lock};
+use crate::utility::{self, Lock, Shared};
 
 #[cfg(feature = "multi-thread")]
 pub trait Archive: Send + Sync {
     fn read_file(&self, path: &Path) -> Result<String, ArchiveError>;
     fn read_bytes_file(&self, path: &Path) -> Result<Vec<u8>, ArchiveError>;
+    // Borrow the contents of a file if the archive already holds them in
+    // memory, otherwise the contents are read as with `read_bytes_file`
+    fn read_bytes_ref(&self, path: &Path) -> Result<Cow<'_, [u8]>, ArchiveError> {
+        self.read_bytes_file(path).map(Cow::Owned)
+    }
+    // Uncompressed size of a file in bytes
+    fn file_size(&self, path: &Path) -> Result<u64, ArchiveError>;
+    // Stored size of a file in bytes, which equals the
+    // uncompressed size if the file is not compressed
+    fn compressed_file_size(&self, path: &Path) -> Result<u64, ArchiveError>;
+    // Information about a file, such as its size and modification time
+    fn file_info(&self, path: &Path) -> Result<EntryInfo, ArchiveError>;
+    // Information about all files, excluding directories
+    fn entries(&self) -> Result<Vec<EntryInfo>, ArchiveError>;
+    // Find the path of a file that matches the given path, ignoring case
+    fn find_ignore_case(&self, path: &Path) -> Option<PathBuf>;
+    // Files with names that were not stored as valid utf-8
+    fn decoded_names(&self) -> &[DecodedName];
 }
 #[cfg(not(feature = "multi-thread"))]
 pub trait Archive {
     fn read_file(&self, path: &Path) -> Result<String, ArchiveError>;
     fn read_bytes_file(&self, path: &Path) -> Result<Vec<u8>, ArchiveError>;
+    // Borrow the contents of a file if the archive already holds them in
+    // memory, otherwise the contents are read as with `read_bytes_file`
+    fn read_bytes_ref(&self, path: &Path) -> Result<Cow<'_, [u8]>, ArchiveError> {
+        self.read_bytes_file(path).map(Cow::Owned)
+    }
+    // Uncompressed size of a file in bytes
+    fn file_size(&self, path: &Path) -> Result<u64, ArchiveError>;
+    // Stored size of a file in bytes, which equals the
+    // uncompressed size if the file is not compressed
+    fn compressed_file_size(&self, path: &Path) -> Result<u64, ArchiveError>;
+    // Information about a file, such as its size and modification time
+    fn file_info(&self, path: &Path) -> Result<EntryInfo, ArchiveError>;
+    // Information about all files, excluding directories
+    fn entries(&self) -> Result<Vec<EntryInfo>, ArchiveError>;
+    // Find the path of a file that matches the given path, ignoring case
+    fn find_ignore_case(&self, path: &Path) -> Option<PathBuf>;
+    // Files with names that were not stored as valid utf-8
+    fn decoded_names(&self) -> &[DecodedName];
 }
 
 /// Possible errors for an Archive
 /// - [InvalidPath](Self::InvalidPath)
 /// - [CannotRead](Self::CannotRead)
 /// - [InvalidEncoding](Self::InvalidEncoding)
+/// - [UnsafePath](Self::UnsafePath)
 #[derive(Error, Debug)]
 pub enum ArchiveError {
     /// When a given path does not point to a valid location.
@@ -34,57 +77,173 @@ pub enum ArchiveError {
     /// When a path contains characters from an unsupported encoding.
     #[error("[InvalidEncoding][{cause}]: {description}")]
     InvalidEncoding { cause: String, description: String },
+    /// When a path attempts to access a location outside the ebook,
+    /// such as through `../` traversal or a disallowed symbolic link.
+    #[error("[UnsafePath][{cause}]: {description}")]
+    UnsafePath { cause: String, description: String },
+}
+
+/// Name of a file within a zip archive that was not stored as valid
+/// utf-8, along with its original bytes. The name is decoded according
+/// to the [FilenameDecoding](crate::epub::FilenameDecoding) setting.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecodedName {
+    name: String,
+    raw: Vec<u8>,
+}
+
+impl DecodedName {
+    /// Retrieve the decoded name, which is used to retrieve the file.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Retrieve the original bytes of the name.
+    pub fn raw(&self) -> &[u8] {
+        &self.raw
+    }
+}
+
+/// Information about a file within the container of an ebook, such as
+/// its size, retrieved without reading its contents.
+///
+/// See [Epub::resource_info](crate::Epub::resource_info) for usage.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EntryInfo {
+    path: String,
+    size: u64,
+    compressed_size: u64,
+    crc32: Option<u32>,
+    modified: Option<SystemTime>,
+    compression: Option<Compression>,
+}
+
+impl EntryInfo {
+    /// Retrieve the path of the file within the container, such as
+    /// `OEBPS/chapter_001.xhtml`.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Retrieve the uncompressed size of the file in bytes.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Retrieve the stored size of the file in bytes, which equals
+    /// the [size](Self::size) if the file is not compressed.
+    pub fn compressed_size(&self) -> u64 {
+        self.compressed_size
+    }
+
+    /// Retrieve the CRC-32 checksum of the uncompressed contents
+    /// stored by a zip archive. Returns [None] for unzipped epubs.
+    pub fn crc32(&self) -> Option<u32> {
+        self.crc32
+    }
+
+    /// Retrieve the last modification time of the file, if available.
+    ///
+    /// Zip archives store timestamps without a time zone, which are
+    /// interpreted as UTC.
+    pub fn modified(&self) -> Option<SystemTime> {
+        self.modified
+    }
+
+    /// Retrieve the compression method of the file within a zip archive.
+    /// Returns [None] for unzipped epubs.
+    pub fn compression(&self) -> Option<Compression> {
+        self.compression
+    }
+}
+
+/// Compression method of a file within a zip archive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Compression {
+    /// The file is stored without compression.
+    Stored,
+    /// The file is compressed using deflate.
+    Deflated,
+    /// The file is compressed using another method, such as bzip2.
+    Other,
+}
+
+// Decoded names of all zip files mapped to their index in the archive
+struct ZipNames {
+    indices: HashMap<String, usize>,
+    // Lowercase names mapped to the name they were derived from
+    lowercase: HashMap<String, String>,
+    decoded: Vec<DecodedName>,
+}
+
+impl ZipNames {
+    fn new<R: Read + Seek>(
+        archive: &mut zip::ZipArchive<R>,
+        decoding: FilenameDecoding,
+    ) -> Result<Self, ArchiveError> {
+        let mut names = Self {
+            indices: HashMap::new(),
+            lowercase: HashMap::new(),
+            decoded: Vec::new(),
+        };
+
+        for index in 0..archive.len() {
+            // Raw access avoids decompressing file contents
+            let zip_file =
+                archive
+                    .by_index_raw(index)
+                    .map_err(|error| ArchiveError::CannotRead {
+                        cause: "Unable to access zip file".to_string(),
+                        description: format!(
+                            "Unable to retrieve file #{index} from zip archive: {error}"
+                        ),
+                    })?;
+            let raw = zip_file.name_raw();
+            let name = decoding.decode(raw, zip_file.name());
+
+            if name != zip_file.name() || std::str::from_utf8(raw).is_err() {
+                names.decoded.push(DecodedName {
+                    name: name.clone(),
+                    raw: raw.to_vec(),
+                });
+            }
+
+            // The first file takes precedence if decoded names collide
+            names
+                .lowercase
+                .entry(name.to_lowercase())
+                .or_insert_with(|| name.clone());
+            names.indices.entry(name).or_insert(index);
+        }
+
+        Ok(names)
+    }
 }
 
 // Wrapper struct
-pub struct ZipArchive<R>(Lock<zip::ZipArchive<R>>);
+pub struct ZipArchive<R> {
+    archive: Lock<zip::ZipArchive<R>>,
+    names: ZipNames,
+}
 
 impl<
         #[cfg(feature = "multi-thread")] R: Read + Seek + Send + Sync,
         #[cfg(not(feature = "multi-thread"))] R: Read + Seek,
     > ZipArchive<R>
 {
-    pub fn new(zip: R) -> Result<Self, EbookError> {
-        zip::ZipArchive::new(zip)
-            .map(|archive| Self(Lock::new(archive)))
-            .map_err(|error| EbookError::IO {
-                cause: "Unable to access zip archive".to_string(),
-                description: error.to_string(),
-            })
-    }
-
-    fn get_file<P: AsRef<Path>>(
-        archive: &mut zip::ZipArchive<R>,
-        path: P,
-    ) -> Result<ZipFile, ArchiveError> {
-        let normalized_path = utility::normalize_path(&path);
-
-        let mut path_str = normalized_path
-            .to_str()
-            .ok_or_else(|| ArchiveError::InvalidEncoding {
-                cause: "Non UTF-8 encoded path".to_string(),
-                description: format!(
-                    "The provided path does not contain valid utf-8: '{:?}'",
-                    path.as_ref()
-                ),
-            })?
-            .to_string();
-
-        // Paths on windows contain backslashes. However, paths to files
-        // in a zip archive requires only forward slashes.
-        if cfg!(windows) {
-            path_str = path_str.replace('\\', "/");
-        }
+    pub fn new(zip: R, decoding: FilenameDecoding) -> Result<Self, EbookError> {
+        let to_error = |description: String| EbookError::IO {
+            cause: "Unable to access zip archive".to_string(),
+            description,
+        };
+        let mut archive = zip::ZipArchive::new(zip).map_err(|error| to_error(error.to_string()))?;
+        let names =
+            ZipNames::new(&mut archive, decoding).map_err(|error| to_error(error.to_string()))?;
 
-        archive
-            .by_name(&path_str)
-            .map(ZipFile)
-            .map_err(|error| ArchiveError::InvalidPath {
-                cause: "Unable to access zip file".to_string(),
-                description: format!(
-                    "Unable to retrieve file '{path_str}' from zip archive: {error}"
-                ),
-            })
+        Ok(Self {
+            archive: Lock::new(archive),
+            names,
+        })
     }
 }
 
@@ -94,16 +253,315 @@ impl<
     > Archive for ZipArchive<R>
 {
     fn read_file(&self, path: &Path) -> Result<String, ArchiveError> {
-        let mut lock = acquire_archive_lock(&self.0)?;
-        let mut zip_file = ZipArchive::get_file(&mut lock, path)?;
+        let mut lock = acquire_archive_lock(&self.archive)?;
+        let mut zip_file = get_zip_file(&mut lock, &self.names, path)?;
         zip_file.read()
     }
 
     fn read_bytes_file(&self, path: &Path) -> Result<Vec<u8>, ArchiveError> {
-        let mut lock = acquire_archive_lock(&self.0)?;
-        let mut zip_file = ZipArchive::get_file(&mut lock, path)?;
+        let mut lock = acquire_archive_lock(&self.archive)?;
+        let mut zip_file = get_zip_file(&mut lock, &self.names, path)?;
         zip_file.read_bytes()
     }
+
+    fn file_size(&self, path: &Path) -> Result<u64, ArchiveError> {
+        let mut lock = acquire_archive_lock(&self.archive)?;
+        let zip_file = get_zip_file(&mut lock, &self.names, path)?;
+        Ok(zip_file.size())
+    }
+
+    fn compressed_file_size(&self, path: &Path) -> Result<u64, ArchiveError> {
+        let mut lock = acquire_archive_lock(&self.archive)?;
+        let zip_file = get_zip_file(&mut lock, &self.names, path)?;
+        Ok(zip_file.compressed_size())
+    }
+
+    fn file_info(&self, path: &Path) -> Result<EntryInfo, ArchiveError> {
+        let mut lock = acquire_archive_lock(&self.archive)?;
+        let zip_file = get_zip_file(&mut lock, &self.names, path)?;
+        Ok(zip_file.info())
+    }
+
+    fn entries(&self) -> Result<Vec<EntryInfo>, ArchiveError> {
+        let mut lock = acquire_archive_lock(&self.archive)?;
+        zip_entries(&mut lock, &self.names)
+    }
+
+    fn find_ignore_case(&self, path: &Path) -> Option<PathBuf> {
+        find_zip_name_ignore_case(&self.names, path)
+    }
+
+    fn decoded_names(&self) -> &[DecodedName] {
+        &self.names.decoded
+    }
+}
+
+// Zip archive held in memory. Stored files are borrowed from
+// the buffer instead of being copied.
+pub struct ZipBytesArchive {
+    bytes: SharedBytes,
+    archive: ZipArchive<Cursor<SharedBytes>>,
+}
+
+// Buffer shared between the archive and its reader
+#[derive(Clone)]
+struct SharedBytes(Shared<Vec<u8>>);
+
+impl AsRef<[u8]> for SharedBytes {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl ZipBytesArchive {
+    pub fn new(bytes: Vec<u8>, decoding: FilenameDecoding) -> Result<Self, EbookError> {
+        let bytes = SharedBytes(Shared::new(bytes));
+        let archive = ZipArchive::new(Cursor::new(bytes.clone()), decoding)?;
+
+        Ok(Self { bytes, archive })
+    }
+}
+
+impl Archive for ZipBytesArchive {
+    fn read_file(&self, path: &Path) -> Result<String, ArchiveError> {
+        self.archive.read_file(path)
+    }
+
+    fn read_bytes_file(&self, path: &Path) -> Result<Vec<u8>, ArchiveError> {
+        self.archive.read_bytes_file(path)
+    }
+
+    fn read_bytes_ref(&self, path: &Path) -> Result<Cow<'_, [u8]>, ArchiveError> {
+        let mut lock = acquire_archive_lock(&self.archive.archive)?;
+        let range = get_zip_file(&mut lock, &self.archive.names, path)?.stored_range();
+        drop(lock);
+
+        // Compressed files must be inflated into a new buffer
+        match range.and_then(|range| self.bytes.as_ref().get(range)) {
+            Some(data) => Ok(Cow::Borrowed(data)),
+            None => self.read_bytes_file(path).map(Cow::Owned),
+        }
+    }
+
+    fn file_size(&self, path: &Path) -> Result<u64, ArchiveError> {
+        self.archive.file_size(path)
+    }
+
+    fn compressed_file_size(&self, path: &Path) -> Result<u64, ArchiveError> {
+        self.archive.compressed_file_size(path)
+    }
+
+    fn file_info(&self, path: &Path) -> Result<EntryInfo, ArchiveError> {
+        self.archive.file_info(path)
+    }
+
+    fn entries(&self) -> Result<Vec<EntryInfo>, ArchiveError> {
+        self.archive.entries()
+    }
+
+    fn find_ignore_case(&self, path: &Path) -> Option<PathBuf> {
+        self.archive.find_ignore_case(path)
+    }
+
+    fn decoded_names(&self) -> &[DecodedName] {
+        self.archive.decoded_names()
+    }
+}
+
+// Zip archive backed by a file. Each read checks out its own handle
+// from a pool, allowing concurrent reads to decompress independently
+// instead of waiting on a single archive lock.
+//
+// Every handle holds an open file descriptor. At most `max_idle` handles,
+// one per available core, are kept open between reads. Handles opened
+// beyond that by additional concurrent reads are closed once returned.
+pub struct ZipFileArchive {
+    path: PathBuf,
+    pool: Lock<Vec<zip::ZipArchive<BufReader<File>>>>,
+    max_idle: usize,
+    names: ZipNames,
+}
+
+impl ZipFileArchive {
+    pub fn new<P: AsRef<Path>>(path: P, decoding: FilenameDecoding) -> Result<Self, EbookError> {
+        let path_buf = path.as_ref().to_path_buf();
+        let to_error = |error: ArchiveError| EbookError::IO {
+            cause: "Unable to access zip archive".to_string(),
+            description: error.to_string(),
+        };
+        let mut archive = Self::open(&path_buf).map_err(to_error)?;
+        let names = ZipNames::new(&mut archive, decoding).map_err(to_error)?;
+
+        Ok(Self {
+            path: path_buf,
+            pool: Lock::new(vec![archive]),
+            max_idle: thread::available_parallelism().map_or(1, NonZeroUsize::get),
+            names,
+        })
+    }
+
+    fn open(path: &Path) -> Result<zip::ZipArchive<BufReader<File>>, ArchiveError> {
+        let file = File::open(path).map_err(|error| ArchiveError::CannotRead {
+            cause: "Unable to open file".to_string(),
+            description: format!("File path: '{path:?}': {error}"),
+        })?;
+
+        zip::ZipArchive::new(BufReader::new(file)).map_err(|error| ArchiveError::CannotRead {
+            cause: "Unable to access zip archive".to_string(),
+            description: format!("File path: '{path:?}': {error}"),
+        })
+    }
+
+    // Retrieve an idle handle or open a new one if all are in use
+    fn checkout(&self) -> Result<zip::ZipArchive<BufReader<File>>, ArchiveError> {
+        let idle = lock_pool(&self.pool).pop();
+
+        match idle {
+            Some(archive) => Ok(archive),
+            None => Self::open(&self.path),
+        }
+    }
+
+    // Return a handle for reuse, closing it if enough handles are idle
+    fn checkin(&self, archive: zip::ZipArchive<BufReader<File>>) {
+        let mut pool = lock_pool(&self.pool);
+
+        if pool.len() < self.max_idle {
+            pool.push(archive);
+        }
+    }
+
+    fn with_file<T, F>(&self, path: &Path, f: F) -> Result<T, ArchiveError>
+    where
+        F: FnOnce(&mut ZipFile<'_>) -> Result<T, ArchiveError>,
+    {
+        let mut archive = self.checkout()?;
+        let result =
+            get_zip_file(&mut archive, &self.names, path).and_then(|mut zip_file| f(&mut zip_file));
+
+        self.checkin(archive);
+        result
+    }
+}
+
+impl Archive for ZipFileArchive {
+    fn read_file(&self, path: &Path) -> Result<String, ArchiveError> {
+        self.with_file(path, |zip_file| zip_file.read())
+    }
+
+    fn read_bytes_file(&self, path: &Path) -> Result<Vec<u8>, ArchiveError> {
+        self.with_file(path, |zip_file| zip_file.read_bytes())
+    }
+
+    fn file_size(&self, path: &Path) -> Result<u64, ArchiveError> {
+        self.with_file(path, |zip_file| Ok(zip_file.size()))
+    }
+
+    fn compressed_file_size(&self, path: &Path) -> Result<u64, ArchiveError> {
+        self.with_file(path, |zip_file| Ok(zip_file.compressed_size()))
+    }
+
+    fn file_info(&self, path: &Path) -> Result<EntryInfo, ArchiveError> {
+        self.with_file(path, |zip_file| Ok(zip_file.info()))
+    }
+
+    fn entries(&self) -> Result<Vec<EntryInfo>, ArchiveError> {
+        let mut archive = self.checkout()?;
+        let result = zip_entries(&mut archive, &self.names);
+
+        self.checkin(archive);
+        result
+    }
+
+    fn find_ignore_case(&self, path: &Path) -> Option<PathBuf> {
+        find_zip_name_ignore_case(&self.names, path)
+    }
+
+    fn decoded_names(&self) -> &[DecodedName] {
+        &self.names.decoded
+    }
+}
+
+// Retrieve information about all files in the order they are stored
+fn zip_entries<R: Read + Seek>(
+    archive: &mut zip::ZipArchive<R>,
+    names: &ZipNames,
+) -> Result<Vec<EntryInfo>, ArchiveError> {
+    let mut names: Vec<_> = names.indices.iter().collect();
+    names.sort_unstable_by_key(|(_, index)| **index);
+
+    names
+        .into_iter()
+        .filter_map(|(name, index)| {
+            // Raw access avoids decompressing file contents
+            match archive.by_index_raw(*index) {
+                Ok(zip_file) if zip_file.is_dir() => None,
+                Ok(zip_file) => Some(Ok(EntryInfo {
+                    path: name.clone(),
+                    ..ZipFile(zip_file).info()
+                })),
+                Err(error) => Some(Err(ArchiveError::CannotRead {
+                    cause: "Unable to access zip file".to_string(),
+                    description: format!(
+                        "Unable to retrieve file #{index} from zip archive: {error}"
+                    ),
+                })),
+            }
+        })
+        .collect()
+}
+
+fn find_zip_name_ignore_case(names: &ZipNames, path: &Path) -> Option<PathBuf> {
+    let path = utility::normalize_path(&path)
+        .to_string_lossy()
+        .replace('\\', "/")
+        .to_lowercase();
+
+    names.lowercase.get(&path).map(PathBuf::from)
+}
+
+fn get_zip_file<'a, R: Read + Seek, P: AsRef<Path>>(
+    archive: &'a mut zip::ZipArchive<R>,
+    names: &ZipNames,
+    path: P,
+) -> Result<ZipFile<'a>, ArchiveError> {
+    let normalized_path = utility::normalize_path(&path);
+
+    let mut path_str = normalized_path
+        .to_str()
+        .ok_or_else(|| ArchiveError::InvalidEncoding {
+            cause: "Non UTF-8 encoded path".to_string(),
+            description: format!(
+                "The provided path does not contain valid utf-8: '{:?}'",
+                path.as_ref()
+            ),
+        })?
+        .to_string();
+
+    // Paths on windows contain backslashes. However, paths to files
+    // in a zip archive requires only forward slashes.
+    if cfg!(windows) {
+        path_str = path_str.replace('\\', "/");
+    }
+
+    let index = names
+        .indices
+        .get(&path_str)
+        .ok_or_else(|| ArchiveError::InvalidPath {
+            cause: "Unable to access zip file".to_string(),
+            description: format!(
+                "Unable to retrieve file '{path_str}' from zip archive: \
+                specified file not found in archive"
+            ),
+        })?;
+
+    archive
+        .by_index(*index)
+        .map(ZipFile)
+        .map_err(|error| ArchiveError::InvalidPath {
+            cause: "Unable to access zip file".to_string(),
+            description: format!("Unable to retrieve file '{path_str}' from zip archive: {error}"),
+        })
 }
 
 // Wrapper struct
@@ -125,27 +583,276 @@ impl ZipFile<'_> {
         })
     }
 
+    pub fn size(&self) -> u64 {
+        self.0.size()
+    }
+
+    pub fn compressed_size(&self) -> u64 {
+        self.0.compressed_size()
+    }
+
+    pub fn info(&self) -> EntryInfo {
+        let compression = match self.0.compression() {
+            zip::CompressionMethod::Stored => Compression::Stored,
+            zip::CompressionMethod::Deflated => Compression::Deflated,
+            _ => Compression::Other,
+        };
+
+        EntryInfo {
+            path: self.0.name().to_string(),
+            size: self.0.size(),
+            compressed_size: self.0.compressed_size(),
+            crc32: Some(self.0.crc32()),
+            modified: to_system_time(self.0.last_modified()),
+            compression: Some(compression),
+        }
+    }
+
+    // Position of the data within the archive if the file is stored
+    // without compression. The data is used as is, without a crc check.
+    fn stored_range(&self) -> Option<Range<usize>> {
+        let size = self.0.size();
+
+        match self.0.compression() {
+            zip::CompressionMethod::Stored if self.0.compressed_size() == size => {
+                let start = usize::try_from(self.0.data_start()).ok()?;
+                Some(start..start.checked_add(usize::try_from(size).ok()?)?)
+            }
+            _ => None,
+        }
+    }
+
     pub fn read_bytes(&mut self) -> Result<Vec<u8>, ArchiveError> {
+        let size = self.size();
         let mut buf = Vec::new();
 
-        self.0
+        // Stop reading once the declared size is exceeded, as the
+        // data of malformed archives may expand beyond it
+        (&mut self.0)
+            .take(size.saturating_add(1))
             .read_to_end(&mut buf)
-            .map(|_| buf)
             .map_err(|error| ArchiveError::CannotRead {
                 cause: "Cannot read zip file contents to bytes vector".to_string(),
                 description: error.to_string(),
-            })
+            })?;
+
+        match buf.len() as u64 > size {
+            true => Err(ArchiveError::CannotRead {
+                cause: "Zip file exceeds its declared size".to_string(),
+                description: format!("The declared size is {size} bytes"),
+            }),
+            false => Ok(buf),
+        }
+    }
+}
+
+// Convert the timestamp of a zip file, interpreted as UTC
+fn to_system_time(date_time: zip::DateTime) -> Option<SystemTime> {
+    let (year, month, day) = (
+        i64::from(date_time.year()),
+        i64::from(date_time.month()),
+        i64::from(date_time.day()),
+    );
+
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    // Days since the unix epoch of a date in the proleptic gregorian calendar
+    let shifted_year = if month <= 2 { year - 1 } else { year };
+    let era = shifted_year.div_euclid(400);
+    let year_of_era = shifted_year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468;
+
+    let seconds = days * 86_400
+        + i64::from(date_time.hour()) * 3_600
+        + i64::from(date_time.minute()) * 60
+        + i64::from(date_time.second());
+
+    u64::try_from(seconds)
+        .ok()
+        .map(|seconds| SystemTime::UNIX_EPOCH + Duration::from_secs(seconds))
+}
+
+// Archive that rejects files larger than a maximum size
+// before they are read
+pub struct LimitedArchive {
+    archive: Shared<dyn Archive>,
+    max_file_size: u64,
+}
+
+impl LimitedArchive {
+    pub fn new(archive: Shared<dyn Archive>, max_file_size: u64) -> Self {
+        Self {
+            archive,
+            max_file_size,
+        }
+    }
+
+    fn check_size(&self, path: &Path) -> Result<(), ArchiveError> {
+        let size = self.archive.file_size(path)?;
+
+        match size > self.max_file_size {
+            true => Err(ArchiveError::CannotRead {
+                cause: "File exceeds the maximum file size".to_string(),
+                description: format!(
+                    "Path: '{:?}' is {size} bytes, exceeding the maximum of {} bytes",
+                    path.display(),
+                    self.max_file_size
+                ),
+            }),
+            false => Ok(()),
+        }
+    }
+}
+
+impl Archive for LimitedArchive {
+    fn read_file(&self, path: &Path) -> Result<String, ArchiveError> {
+        self.check_size(path)?;
+        self.archive.read_file(path)
+    }
+
+    fn read_bytes_file(&self, path: &Path) -> Result<Vec<u8>, ArchiveError> {
+        self.check_size(path)?;
+        self.archive.read_bytes_file(path)
+    }
+
+    fn read_bytes_ref(&self, path: &Path) -> Result<Cow<'_, [u8]>, ArchiveError> {
+        self.check_size(path)?;
+        self.archive.read_bytes_ref(path)
+    }
+
+    fn file_size(&self, path: &Path) -> Result<u64, ArchiveError> {
+        self.archive.file_size(path)
+    }
+
+    fn compressed_file_size(&self, path: &Path) -> Result<u64, ArchiveError> {
+        self.archive.compressed_file_size(path)
+    }
+
+    fn file_info(&self, path: &Path) -> Result<EntryInfo, ArchiveError> {
+        self.archive.file_info(path)
+    }
+
+    fn entries(&self) -> Result<Vec<EntryInfo>, ArchiveError> {
+        self.archive.entries()
+    }
+
+    fn find_ignore_case(&self, path: &Path) -> Option<PathBuf> {
+        self.archive.find_ignore_case(path)
+    }
+
+    fn decoded_names(&self) -> &[DecodedName] {
+        self.archive.decoded_names()
+    }
+}
+
+// Decrypts the files listed as encrypted using a registered decryptor
+pub struct DecryptingArchive {
+    archive: Shared<dyn Archive>,
+    decryptor: Shared<dyn ContentDecryptor>,
+    // Normalized paths of encrypted files mapped to their algorithm
+    encrypted: HashMap<PathBuf, String>,
+}
+
+impl DecryptingArchive {
+    pub fn new(
+        archive: Shared<dyn Archive>,
+        decryptor: Shared<dyn ContentDecryptor>,
+        encrypted: Vec<(PathBuf, String)>,
+    ) -> Self {
+        Self {
+            archive,
+            decryptor,
+            encrypted: encrypted.into_iter().collect(),
+        }
+    }
+
+    fn decrypt(&self, path: &Path, data: Vec<u8>) -> Result<Vec<u8>, ArchiveError> {
+        let normalized = utility::normalize_path(&path);
+
+        match self.encrypted.get(normalized.as_ref()) {
+            Some(algorithm) => {
+                let path = normalized.to_string_lossy().replace('\\', "/");
+                self.decryptor.decrypt(&path, algorithm, data)
+            }
+            None => Ok(data),
+        }
+    }
+}
+
+impl Archive for DecryptingArchive {
+    fn read_file(&self, path: &Path) -> Result<String, ArchiveError> {
+        let mut bytes = self.read_bytes_file(path)?;
+        let data = utility::to_utf8(&bytes);
+
+        // Retrieve converted bytes
+        if let Cow::Owned(_) = data {
+            bytes = data.into_owned();
+        }
+
+        String::from_utf8(bytes).map_err(|error| ArchiveError::CannotRead {
+            cause: "Cannot read file contents to string".to_string(),
+            description: format!("Path: '{:?}': {error}", path.display()),
+        })
+    }
+
+    fn read_bytes_file(&self, path: &Path) -> Result<Vec<u8>, ArchiveError> {
+        let data = self.archive.read_bytes_file(path)?;
+        self.decrypt(path, data)
+    }
+
+    fn read_bytes_ref(&self, path: &Path) -> Result<Cow<'_, [u8]>, ArchiveError> {
+        let normalized = utility::normalize_path(&path);
+
+        // Only files that are not encrypted can be borrowed as is
+        match self.encrypted.contains_key(normalized.as_ref()) {
+            true => self.read_bytes_file(path).map(Cow::Owned),
+            false => self.archive.read_bytes_ref(path),
+        }
+    }
+
+    fn file_size(&self, path: &Path) -> Result<u64, ArchiveError> {
+        self.archive.file_size(path)
+    }
+
+    fn compressed_file_size(&self, path: &Path) -> Result<u64, ArchiveError> {
+        self.archive.compressed_file_size(path)
+    }
+
+    fn file_info(&self, path: &Path) -> Result<EntryInfo, ArchiveError> {
+        self.archive.file_info(path)
+    }
+
+    fn entries(&self) -> Result<Vec<EntryInfo>, ArchiveError> {
+        self.archive.entries()
+    }
+
+    fn find_ignore_case(&self, path: &Path) -> Option<PathBuf> {
+        self.archive.find_ignore_case(path)
+    }
+
+    fn decoded_names(&self) -> &[DecodedName] {
+        self.archive.decoded_names()
     }
 }
 
-pub struct DirArchive(PathBuf);
+pub struct DirArchive {
+    root: PathBuf,
+    follow_symlinks: bool,
+}
 
 impl DirArchive {
-    pub fn new<P: AsRef<Path>>(path: P) -> Result<Self, EbookError> {
+    pub fn new<P: AsRef<Path>>(path: P, follow_symlinks: bool) -> Result<Self, EbookError> {
         let path_buf = path.as_ref().to_path_buf();
 
         match path_buf.try_exists() {
-            Ok(exists) if exists => Ok(Self(path_buf)),
+            Ok(exists) if exists => Ok(Self {
+                root: path_buf,
+                follow_symlinks,
+            }),
             Ok(_) => Err(EbookError::IO {
                 cause: "Broken symbolic link".to_string(),
                 description: format!("Path `{:?}` is a broken symbolic link", path_buf.display()),
@@ -158,7 +865,7 @@ impl DirArchive {
     }
 
     pub fn get_path<P: AsRef<Path>>(&self, path: P) -> Result<PathBuf, ArchiveError> {
-        let mut joined_path = self.0.join(&path);
+        let mut joined_path = self.root.join(&path);
         let normalized_path = utility::normalize_path(&joined_path);
 
         // Retrieve converted path
@@ -167,7 +874,21 @@ impl DirArchive {
         }
 
         // Path traversal mitigation
-        if joined_path.starts_with(&self.0) && joined_path.is_file() {
+        if !joined_path.starts_with(&self.root) {
+            return Err(ArchiveError::UnsafePath {
+                cause: "Path traversal outside of the ebook directory".to_string(),
+                description: format!(
+                    "The provided path leads outside the ebook: '{:?}'",
+                    path.as_ref().display()
+                ),
+            });
+        }
+
+        if !self.follow_symlinks {
+            self.reject_symlinks(&joined_path, path.as_ref())?;
+        }
+
+        if joined_path.is_file() {
             Ok(joined_path)
         } else {
             Err(ArchiveError::InvalidPath {
@@ -181,6 +902,62 @@ impl DirArchive {
     }
 }
 
+impl DirArchive {
+    // Recursively collect the paths of all files relative to the root
+    fn collect_paths(
+        &self,
+        directory: &Path,
+        paths: &mut Vec<PathBuf>,
+    ) -> Result<(), ArchiveError> {
+        let to_error = |error: std::io::Error| ArchiveError::CannotRead {
+            cause: "Cannot read directory".to_string(),
+            description: format!("Path: '{:?}': {error}", directory.display()),
+        };
+
+        for entry in fs::read_dir(directory).map_err(to_error)? {
+            let path = entry.map_err(to_error)?.path();
+            let is_symlink = path
+                .symlink_metadata()
+                .is_ok_and(|metadata| metadata.is_symlink());
+
+            if is_symlink && !self.follow_symlinks {
+                continue;
+            } else if path.is_dir() {
+                // Symbolic links to directories are not followed to avoid cycles
+                if !is_symlink {
+                    self.collect_paths(&path, paths)?;
+                }
+            } else if let Ok(relative_path) = path.strip_prefix(&self.root) {
+                paths.push(relative_path.to_path_buf());
+            }
+        }
+
+        Ok(())
+    }
+
+    // Ensure no component of a path within the ebook directory is a symbolic link
+    fn reject_symlinks(&self, joined_path: &Path, path: &Path) -> Result<(), ArchiveError> {
+        let relative_path = joined_path.strip_prefix(&self.root).unwrap_or(joined_path);
+        let mut current = self.root.clone();
+
+        for component in relative_path.components() {
+            current.push(component);
+
+            if fs::symlink_metadata(&current).is_ok_and(|metadata| metadata.is_symlink()) {
+                return Err(ArchiveError::UnsafePath {
+                    cause: "Symbolic links are not followed".to_string(),
+                    description: format!(
+                        "The provided path contains a symbolic link: '{:?}'",
+                        path.display()
+                    ),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl Archive for DirArchive {
     fn read_file(&self, path: &Path) -> Result<String, ArchiveError> {
         let mut bytes = self.read_bytes_file(path)?;
@@ -205,12 +982,76 @@ impl Archive for DirArchive {
             description: format!("Path: '{:?}': {error}", path.display()),
         })
     }
+
+    fn file_size(&self, path: &Path) -> Result<u64, ArchiveError> {
+        let path = self.get_path(path)?;
+
+        fs::metadata(&path)
+            .map(|metadata| metadata.len())
+            .map_err(|error| ArchiveError::CannotRead {
+                cause: "Cannot read file metadata".to_string(),
+                description: format!("Path: '{:?}': {error}", path.display()),
+            })
+    }
+
+    // Files within a directory are not compressed
+    fn compressed_file_size(&self, path: &Path) -> Result<u64, ArchiveError> {
+        self.file_size(path)
+    }
+
+    fn file_info(&self, path: &Path) -> Result<EntryInfo, ArchiveError> {
+        let joined_path = self.get_path(path)?;
+        let metadata = fs::metadata(&joined_path).map_err(|error| ArchiveError::CannotRead {
+            cause: "Cannot read file metadata".to_string(),
+            description: format!("Path: '{:?}': {error}", joined_path.display()),
+        })?;
+        let relative_path = joined_path.strip_prefix(&self.root).unwrap_or(path);
+
+        Ok(EntryInfo {
+            path: relative_path.to_string_lossy().replace('\\', "/"),
+            size: metadata.len(),
+            compressed_size: metadata.len(),
+            crc32: None,
+            modified: metadata.modified().ok(),
+            compression: None,
+        })
+    }
+
+    fn entries(&self) -> Result<Vec<EntryInfo>, ArchiveError> {
+        let mut paths = Vec::new();
+        self.collect_paths(&self.root, &mut paths)?;
+        paths.sort_unstable();
+
+        paths.iter().map(|path| self.file_info(path)).collect()
+    }
+
+    fn find_ignore_case(&self, path: &Path) -> Option<PathBuf> {
+        let mut resolved = PathBuf::new();
+
+        // Resolve each component of the path against the directory entries
+        for component in utility::normalize_path(&path).components() {
+            let name = component.as_os_str().to_string_lossy().to_lowercase();
+            let entry = fs::read_dir(self.root.join(&resolved))
+                .ok()?
+                .filter_map(Result::ok)
+                .find(|entry| entry.file_name().to_string_lossy().to_lowercase() == name)?;
+
+            resolved.push(entry.file_name());
+        }
+
+        Some(resolved)
+    }
+
+    fn decoded_names(&self) -> &[DecodedName] {
+        // Names of directory entries are provided by the filesystem
+        &[]
+    }
 }
 
 #[cfg(feature = "multi-thread")]
 pub(crate) fn acquire_archive_lock<T>(
     lock: &Lock<T>,
-) -> Result<std::sync::MutexGuard<T>, ArchiveError> {
+) -> Result<std::sync::MutexGuard<'_, T>, ArchiveError> {
     lock.lock().map_err(|error| ArchiveError::CannotRead {
         cause: "Unable to acquire lock for archive".to_string(),
         description: format!("Failed to lock zip archive: {error}"),
@@ -219,6 +1060,18 @@ pub(crate) fn acquire_archive_lock<T>(
 #[cfg(not(feature = "multi-thread"))]
 pub(crate) fn acquire_archive_lock<T>(
     lock: &Lock<T>,
-) -> Result<std::cell::RefMut<T>, ArchiveError> {
+) -> Result<std::cell::RefMut<'_, T>, ArchiveError> {
     Ok(lock.borrow_mut())
 }
+
+// Lock a pool of handles. A poisoned pool is recovered, as handles
+// are only pushed and popped, leaving the pool consistent.
+#[cfg(feature = "multi-thread")]
+fn lock_pool<T>(lock: &Lock<T>) -> std::sync::MutexGuard<'_, T> {
+    lock.lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+#[cfg(not(feature = "multi-thread"))]
+fn lock_pool<T>(lock: &Lock<T>) -> std::cell::RefMut<'_, T> {
+    lock.borrow_mut()
+}
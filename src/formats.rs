@@ -31,6 +31,10 @@ pub trait Ebook {
     /// If the given path does not support the ebook format, an
     /// [EbookError] from a result will be returned.
     ///
+    /// On targets without a filesystem, such as `wasm32-unknown-unknown`,
+    /// an [IO](EbookError::IO) error is always returned. Use
+    /// [read_from(...)](Self::read_from) instead.
+    ///
     /// # Examples
     /// Basic usage:
     /// ```
@@ -51,6 +55,11 @@ pub trait Ebook {
     /// If the given instance does not support the ebook format, an
     /// [EbookError] from a result will be returned.
     ///
+    /// # WASM
+    /// As no filesystem access is required, this is the supported way
+    /// of creating an ebook object on `wasm32-unknown-unknown`, e.g.,
+    /// by wrapping the bytes of a fetched file in a [Cursor](std::io::Cursor).
+    ///
     /// # Examples
     /// Basic usage:
     /// ```
@@ -74,6 +83,10 @@ pub trait Ebook {
 /// - [IO](Self::IO)
 /// - [Parse](Self::Parse)
 /// - [Archive](Self::Archive)
+/// - [File](Self::File)
+///
+/// Underlying errors are available using
+/// [source()](std::error::Error::source).
 #[derive(Error, Debug)]
 pub enum EbookError {
     /// When a given ebook path is not valid.
@@ -86,5 +99,56 @@ pub enum EbookError {
     Parse { cause: String, description: String },
     /// When access to files in an ebook archive fails.
     #[error("[Archive Error]{0}")]
-    Archive(ArchiveError),
+    Archive(#[source] ArchiveError),
+    /// When an error occurs while processing a specific file
+    /// of an ebook, such as the package `.opf` file.
+    #[error("[File Error][{path}]: {source}")]
+    File {
+        path: String,
+        source: Box<EbookError>,
+    },
+}
+
+impl EbookError {
+    /// Retrieve a multi-line description of the error that lists
+    /// the file being processed before the underlying cause.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use rbook::result::EbookError;
+    ///
+    /// let error = EbookError::File {
+    ///     path: "EPUB/toc.xhtml".to_string(),
+    ///     source: Box::new(EbookError::Parse {
+    ///         cause: "Missing toc element".to_string(),
+    ///         description: "Toc must contain a nav element".to_string(),
+    ///     }),
+    /// };
+    ///
+    /// assert_eq!(
+    ///     "while processing 'EPUB/toc.xhtml':\n  [Parse Error][Missing toc element]: Toc must contain a nav element",
+    ///     error.pretty(),
+    /// );
+    /// ```
+    pub fn pretty(&self) -> String {
+        let mut lines = Vec::new();
+        let mut error = self;
+
+        while let EbookError::File { path, source } = error {
+            lines.push(format!("while processing '{path}':"));
+            error = source;
+        }
+
+        lines.push(error.to_string());
+        lines.join("\n  ")
+    }
+
+    // Associate the error with the file being processed
+    pub(crate) fn in_file<P: AsRef<Path>>(self, path: P) -> Self {
+        EbookError::File {
+            path: path.as_ref().to_string_lossy().replace('\\', "/"),
+            source: Box::new(self),
+        }
+    }
 }
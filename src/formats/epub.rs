@@ -1,28 +1,60 @@
-mod constants;
+#[cfg(feature = "classification")]
+mod classification;
+mod collection;
+pub(crate) mod constants;
+mod decryption;
+mod dictionary;
+mod fingerprint;
 mod guide;
+mod headings;
+mod image;
+#[cfg(feature = "language-detection")]
+mod language;
+mod layout;
+mod localized;
 mod manifest;
+mod media;
 mod metadata;
+mod profile;
+#[cfg(feature = "reader")]
+mod reader_settings;
+#[cfg(feature = "relators")]
+mod relator;
+mod resource;
+mod settings;
+mod sorting;
 mod spine;
 mod table_of_contents;
+mod title;
+mod vendor;
+mod vocabulary;
 
+use lol_html::html_content::Element as LolElement;
 use lol_html::{
     doc_text, element, text, DocumentContentHandlers, ElementContentHandlers, HtmlRewriter,
     Selector, Settings,
 };
 use std::borrow::Cow;
-use std::cell::RefCell;
-use std::collections::HashMap;
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
 use std::fmt::{Debug, Formatter};
-use std::io::{BufReader, Read, Seek};
+use std::io::{Read, Seek};
 use std::path::{Path, PathBuf};
+use std::rc::Rc;
 
-use crate::archive::{Archive, DirArchive, ZipArchive};
+use crate::archive::{
+    Archive, ArchiveError, DecodedName, DecryptingArchive, DirArchive, EntryInfo, LimitedArchive,
+    ZipArchive, ZipBytesArchive, ZipFileArchive,
+};
 use crate::formats::xml::utility as xmlutil;
 use crate::formats::xml::{self, Attribute, Element};
 use crate::formats::{Ebook, EbookError, EbookResult};
-use crate::utility::{self, Shared, Weak};
+use crate::lint::{Finding, RuleSet};
+use crate::utility::{self, OnceCell, Shared, Weak};
 use crate::xml::TempElement;
 
+#[cfg(all(feature = "reader", feature = "multi-thread"))]
+use crate::reader::PageLoader;
 #[cfg(feature = "reader")]
 use crate::reader::{
     content::{Content, ContentType},
@@ -31,8 +63,36 @@ use crate::reader::{
 #[cfg(feature = "statistics")]
 use crate::statistics::Stats;
 
+use self::collection::TempCollection;
+
+#[cfg(feature = "classification")]
+pub use self::classification::{Classification, ClassificationScheme};
+#[cfg(feature = "image-meta")]
+pub use self::image::ImageFormat;
+#[cfg(feature = "reader")]
+pub use self::reader_settings::EpubReaderSettings;
+#[cfg(feature = "relators")]
+pub use self::relator::Relator;
 pub use self::{
-    guide::Guide, manifest::Manifest, metadata::Metadata, spine::Spine, table_of_contents::Toc,
+    collection::Collection,
+    decryption::ContentDecryptor,
+    dictionary::{SearchKeyGroup, SearchKeyMap, SearchKeyMatch},
+    guide::Guide,
+    headings::HeadingOptions,
+    image::ImageInfo,
+    layout::{LayoutHints, TextDirection},
+    localized::LocalizedMetadata,
+    manifest::{FallbackChain, Manifest},
+    media::{Duration, MediaMetadata},
+    metadata::Metadata,
+    profile::ReadingSystemProfile,
+    resource::{RemoteReferences, Resource},
+    settings::{EpubSettings, FilenameDecoding, ParseLevel},
+    spine::{Spine, SpineWeights, Spread},
+    table_of_contents::Toc,
+    title::{TitleKind, TitleStyle},
+    vendor::{AppleDisplayOptions, VendorMetadata},
+    vocabulary::{Vocabulary, VocabularyEntries},
 };
 
 /// Electronic Publication (epub) format
@@ -65,22 +125,93 @@ pub use self::{
 ///
 /// assert_eq!(143, reader.current_index());
 /// ```
+///
+/// # Concurrency
+/// When created from a file path using [new(...)](Ebook::new), each read
+/// of the underlying archive uses its own decompression state. With the
+/// `multi-thread` feature enabled, an [Epub] can be shared using an
+/// [Arc](std::sync::Arc) and read from several threads, e.g., by multiple
+/// reader instances, without serializing on a single archive lock.
+///
+/// Each concurrent read holds its own file descriptor. Up to one
+/// descriptor per available core is kept open for reuse, and the rest
+/// are closed once their read completes.
+///
+/// # Cloning
+/// Cloning an [Epub] is cheap. The underlying archive and the parsed
+/// contents, such as the [Manifest] and [Toc], are shared between clones,
+/// so neither the package nor resources are parsed or read again:
+/// ```
+/// # use rbook::Ebook;
+/// let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+/// let clone = epub.clone();
+///
+/// assert!(std::ptr::eq(epub.manifest(), clone.manifest()));
+/// assert_eq!(epub.metadata().title(), clone.metadata().title());
+/// assert_eq!(
+///     epub.read_bytes_file("chapter_001.xhtml").unwrap(),
+///     clone.read_bytes_file("chapter_001.xhtml").unwrap(),
+/// );
+/// ```
+#[derive(Clone)]
 pub struct Epub {
-    archive: Box<dyn Archive>,
+    archive: Shared<dyn Archive>,
+    settings: EpubSettings,
     root_file: PathBuf,
-    metadata: Metadata,
-    manifest: Manifest,
-    spine: Spine,
-    guide: Guide,
-    toc: Toc,
+    metadata: Shared<Metadata>,
+    manifest: Shared<Manifest>,
+    spine: Shared<Spine>,
+    guide: Shared<Guide>,
+    toc: Shared<OnceCell<Toc>>,
+    empty_toc: OnceCell<Toc>,
+    collections: Shared<Vec<Collection>>,
+    foreign_elements: Shared<Vec<Element>>,
 }
 
 impl Epub {
     #[cfg(feature = "reader")]
-    pub fn reader(&self) -> Reader {
+    pub fn reader(&self) -> Reader<'_> {
         Reader::new(self)
     }
 
+    /// Create a reader using custom [EpubReaderSettings], such as
+    /// to skip certain spine entries.
+    ///
+    /// Spine entries that do not reference a valid manifest element are
+    /// always included, allowing the reader to report the error.
+    #[cfg(feature = "reader")]
+    pub fn reader_with(&self, settings: EpubReaderSettings) -> Reader<'_> {
+        let pages = self
+            .spine
+            .elements()
+            .into_iter()
+            .enumerate()
+            .filter(
+                |(_, spine_element)| match self.manifest.by_id(spine_element.name()) {
+                    Some(manifest_element) => {
+                        settings.includes(spine_element, manifest_element)
+                            && match &settings.profile {
+                                Some(profile) => {
+                                    self.is_displayed(profile, spine_element, manifest_element)
+                                }
+                                None => true,
+                            }
+                    }
+                    None => true,
+                },
+            )
+            .map(|(index, _)| index)
+            .collect();
+
+        #[cfg_attr(not(feature = "multi-thread"), allow(unused_mut))]
+        let mut reader = Reader::with_pages(self, pages, settings.on_error);
+
+        #[cfg(feature = "multi-thread")]
+        reader.set_prefetch(settings.prefetch);
+
+        reader
+    }
+
     /// Access ebook metadata such as author, title, date, etc.
     pub fn metadata(&self) -> &Metadata {
         &self.metadata
@@ -105,8 +236,93 @@ impl Epub {
     }
 
     /// Access the table of contents
+    ///
+    /// If the epub was opened with
+    /// [lazy_toc](EpubSettings::lazy_toc) enabled, the table of contents
+    /// is parsed upon the first call. An empty table of contents is
+    /// returned if parsing fails; use [try_toc()](Self::try_toc) to
+    /// retrieve the error instead.
     pub fn toc(&self) -> &Toc {
-        &self.toc
+        self.try_toc()
+            .unwrap_or_else(|_| self.empty_toc.get_or_init(|| Toc::new(HashMap::new())))
+    }
+
+    /// Access the table of contents, parsing it if the epub was opened
+    /// with [lazy_toc](EpubSettings::lazy_toc) enabled and it has not
+    /// been parsed yet.
+    ///
+    /// Failures are not cached, each call retries parsing until the
+    /// table of contents is successfully parsed.
+    ///
+    /// # Errors
+    /// Returns an error if the table of contents cannot be read or parsed.
+    ///
+    /// # Examples
+    /// ```
+    /// # use rbook::Ebook;
+    /// use rbook::epub::EpubSettings;
+    ///
+    /// let settings = EpubSettings::new().lazy_toc(true);
+    /// let epub = rbook::Epub::open_with("tests/ebooks/moby-dick.epub", settings).unwrap();
+    ///
+    /// let toc = epub.try_toc().unwrap();
+    /// assert_eq!(141, toc.elements().len());
+    /// ```
+    pub fn try_toc(&self) -> EbookResult<&Toc> {
+        if let Some(toc) = self.toc.get() {
+            return Ok(toc);
+        }
+
+        let toc = load_toc(
+            &*self.archive,
+            self.root_file_directory(),
+            &self.manifest,
+            &self.settings,
+        )?;
+
+        Ok(self.toc.get_or_init(|| toc))
+    }
+
+    /// Access the `collection` elements of the package, which group
+    /// related resources such as a preview or an index.
+    ///
+    /// Nested collections can be accessed using
+    /// [collections()](Collection::collections) of each [Collection].
+    pub fn collections(&self) -> &[Collection] {
+        &self.collections
+    }
+
+    /// Retrieve all top-level collections that match a given `role`.
+    pub fn collections_by_role(&self, role: &str) -> Vec<&Collection> {
+        self.collections
+            .iter()
+            .filter(|collection| collection.role() == role)
+            .collect()
+    }
+
+    /// Retrieve the children of the `package` element that rbook does
+    /// not support, such as vendor extensions, in the order they appear.
+    ///
+    /// Unknown children of the `manifest` and `spine` elements are
+    /// available using [Manifest::foreign_elements] and
+    /// [Spine::foreign_elements], while unknown metadata entries are
+    /// part of [Metadata::elements].
+    ///
+    /// For convenience the tag name, including any prefix, is the `name`
+    /// field of the element. Attributes are retained, while text content
+    /// and nested elements are not.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// # let epub = rbook::Epub::new("tests/ebooks/example_epub").unwrap();
+    /// assert!(epub.foreign_elements().is_empty());
+    /// assert!(epub.manifest().foreign_elements().is_empty());
+    /// assert!(epub.spine().foreign_elements().is_empty());
+    /// ```
+    pub fn foreign_elements(&self) -> Vec<&Element> {
+        self.foreign_elements.iter().collect()
     }
 
     /// Retrieve the cover image element from the [manifest](Manifest)
@@ -128,6 +344,519 @@ impl Epub {
         }
     }
 
+    /// Retrieve the search key maps of an epub dictionary, which are
+    /// referenced by manifest elements with the `search-key-map` property.
+    ///
+    /// See [SearchKeyMap] for more details.
+    ///
+    /// # Errors
+    /// If a search key map cannot be read or parsed, an [EbookError]
+    /// will be returned.
+    pub fn search_key_maps(&self) -> EbookResult<Vec<SearchKeyMap>> {
+        self.manifest
+            .all_by_property(constants::SEARCH_KEY_MAP_PROPERTY)
+            .into_iter()
+            .map(|element| {
+                let data = self.read_bytes_file(element.value())?;
+                dictionary::parse_search_key_map(&data, element.value())
+                    .map_err(|error| error.in_file(element.value()))
+            })
+            .collect()
+    }
+
+    /// Retrieve the weights of all spine entries to calculate the
+    /// reading progress of the ebook. See [SpineWeights] for more details.
+    ///
+    /// Entries with a resource that cannot be found have a weight of `0`.
+    /// As the weights require a lookup of every spine entry, it is
+    /// recommended to retrieve them once and reuse the result.
+    ///
+    /// # Examples
+    /// Calculating reading progress:
+    /// ```
+    /// # use rbook::Ebook;
+    /// let epub = rbook::Epub::new("tests/ebooks/example_epub").unwrap();
+    /// let spine_weights = epub.spine_weights();
+    ///
+    /// // The cover is not linear and has no weight
+    /// assert_eq!(0, spine_weights.weights()[0]);
+    /// assert_eq!(Some(0.0), spine_weights.progress(0, 1.0));
+    ///
+    /// // Halfway through the last entry
+    /// let progress = spine_weights.progress(3, 0.5).unwrap();
+    /// assert!(progress > 0.5 && progress < 1.0);
+    /// assert_eq!(Some(1.0), spine_weights.progress(3, 1.0));
+    /// ```
+    pub fn spine_weights(&self) -> SpineWeights {
+        let weights = self
+            .spine
+            .elements()
+            .into_iter()
+            .map(|spine_element| {
+                if spine_element.get_attribute(constants::LINEAR) == Some(constants::NO) {
+                    return 0;
+                }
+
+                self.manifest
+                    .by_id(spine_element.name())
+                    .and_then(|manifest_element| {
+                        let href = manifest_element.value();
+                        self.read_archive(&self.parse_path(&href), |archive, path| {
+                            archive.file_size(path)
+                        })
+                        .ok()
+                    })
+                    .unwrap_or_default()
+            })
+            .collect();
+
+        SpineWeights::new(weights)
+    }
+
+    /// Retrieve the remote resources referenced by each content document,
+    /// such as `<img src="https://...">`. Documents without remote
+    /// references are excluded. Hyperlinks are not resources and are
+    /// therefore not included.
+    ///
+    /// Useful to check that each document declares the
+    /// `remote-resources` manifest property as required.
+    /// Documents that cannot be read or parsed are skipped.
+    ///
+    /// # Examples
+    /// Finding documents that do not declare their remote resources:
+    /// ```
+    /// # use rbook::Ebook;
+    /// # let epub = rbook::Epub::new("tests/ebooks/content_epub").unwrap();
+    /// let undeclared: Vec<_> = epub
+    ///     .remote_references()
+    ///     .into_iter()
+    ///     .filter(|references| !references.is_declared())
+    ///     .collect();
+    ///
+    /// assert_eq!(1, undeclared.len());
+    /// assert_eq!("c2", undeclared[0].element().name());
+    /// assert_eq!(vec!["https://example.com/font.css"], undeclared[0].urls());
+    /// ```
+    pub fn remote_references(&self) -> Vec<RemoteReferences<'_>> {
+        self.manifest
+            .elements()
+            .into_iter()
+            .filter(|element| {
+                element
+                    .get_attribute(constants::MEDIA_TYPE)
+                    .is_some_and(|media_type| constants::MARKUP_TYPES.contains(&media_type))
+            })
+            .filter_map(|element| {
+                let href = element.value();
+                let data = self.read_bytes_file(href).ok()?;
+                let urls = extract_remote_references(&data).ok()?;
+
+                (!urls.is_empty()).then(|| RemoteReferences::new(element, urls))
+            })
+            .collect()
+    }
+
+    /// Retrieve the xhtml content documents of the manifest that never
+    /// appear in the reading order, in manifest order.
+    ///
+    /// A document is reachable if it is referenced by the spine, linked
+    /// or embedded by a reachable document, or part of the
+    /// [fallback chain](Manifest::fallback_chain) of a reachable resource.
+    /// The navigation document is never considered unreachable, as it is
+    /// not required to be within the spine.
+    ///
+    /// # Examples
+    /// Finding orphaned documents:
+    /// ```
+    /// # use rbook::Ebook;
+    /// # let epub = rbook::Epub::new("tests/ebooks/fallback_epub").unwrap();
+    /// let unreachable = epub.unreachable_content();
+    ///
+    /// // The fallback of an image that is not referenced by any chapter
+    /// assert_eq!(1, unreachable.len());
+    /// assert_eq!("chart-table", unreachable[0].name());
+    /// ```
+    pub fn unreachable_content(&self) -> Vec<&Element> {
+        // Ids of the manifest elements that were reached
+        let mut reachable = HashSet::new();
+        let mut queue: Vec<&Element> = self
+            .spine
+            .elements()
+            .into_iter()
+            .filter_map(|spine_element| self.manifest.by_id(spine_element.name()))
+            .collect();
+
+        while let Some(element) = queue.pop() {
+            if !reachable.insert(element.name()) {
+                continue;
+            }
+            queue.extend(self.manifest.fallback_chain(element).skip(1));
+
+            let is_markup = element
+                .get_attribute(constants::MEDIA_TYPE)
+                .is_some_and(|media_type| constants::MARKUP_TYPES.contains(&media_type));
+            if !is_markup {
+                continue;
+            }
+
+            if let Ok(data) = self.read_bytes_file(element.value()) {
+                let mut values = extract_reference_values(&data).unwrap_or_default();
+                values.extend(extract_hyperlink_values(&data).unwrap_or_default());
+
+                queue.extend(
+                    resolve_references(values, element.value())
+                        .iter()
+                        .filter_map(|href| self.manifest.by_href(href)),
+                );
+            }
+        }
+
+        let nav = self.manifest.by_property(constants::NAV_PROPERTY);
+
+        self.manifest
+            .elements()
+            .into_iter()
+            .filter(|element| {
+                element.get_attribute(constants::MEDIA_TYPE) == Some(constants::XHTML_TYPE)
+                    && !nav.is_some_and(|nav| std::ptr::eq(nav, *element))
+                    && !reachable.contains(element.name())
+            })
+            .collect()
+    }
+
+    /// Guess the language of the ebook from the text of its linear spine
+    /// documents, returning a BCP 47 language tag, such as `en`.
+    ///
+    /// Useful to populate a missing `dc:language` element. The guess is
+    /// based on the writing system of the text and, for the latin script,
+    /// the frequency of common words. Supported languages are English,
+    /// French, German, Spanish, Italian, Portuguese, Dutch, Russian, Greek,
+    /// Arabic, Hebrew, Thai, Hindi, Chinese, Japanese, and Korean.
+    ///
+    /// Returns [None] if there is too little text or no language matches.
+    /// Documents that cannot be read or parsed are skipped.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// # let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    /// assert_eq!(Some("en"), epub.detect_language());
+    /// ```
+    #[cfg(feature = "language-detection")]
+    pub fn detect_language(&self) -> Option<&'static str> {
+        // Number of characters sampled from the start of the ebook
+        const SAMPLE_SIZE: usize = 20_000;
+        let mut sample = String::new();
+        let mut sampled = 0;
+
+        for spine_element in self.spine.elements() {
+            if sampled >= SAMPLE_SIZE {
+                break;
+            }
+            if spine_element.get_attribute(constants::LINEAR) == Some(constants::NO) {
+                continue;
+            }
+
+            let text = self
+                .manifest
+                .by_id(spine_element.name())
+                .and_then(|element| self.read_bytes_file(element.value()).ok())
+                .and_then(|data| crate::reader::text::extract(&data).ok());
+
+            if let Some(text) = text {
+                sampled += text.text().chars().count() + 1;
+                sample.push_str(text.text());
+                sample.push(' ');
+            }
+        }
+
+        language::detect(&sample)
+    }
+
+    /// Retrieve the media overlay of a spine `itemref` element, which is
+    /// the manifest element referenced by the `media-overlay` attribute
+    /// of the `itemref`'s manifest element.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// # let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    /// let itemref = &epub.spine()[0];
+    ///
+    /// assert_eq!(None, epub.media_overlay(itemref));
+    /// ```
+    pub fn media_overlay(&self, itemref: &Element) -> Option<&Element> {
+        self.manifest
+            .by_id(itemref.name())?
+            .get_attribute(constants::MEDIA_OVERLAY)
+            .and_then(|id| self.manifest.by_id(id))
+    }
+
+    /// Retrieve the media overlay entries of a spine `itemref` element,
+    /// such as `media:duration` and `media:narrator`.
+    ///
+    /// Entries that refine the `itemref` take precedence over those
+    /// that refine its [media overlay](Self::media_overlay). Other
+    /// refinements of an `itemref` are available as its
+    /// [children](Element::children).
+    ///
+    /// See [MediaMetadata] for more details.
+    pub fn spine_media<'a>(&'a self, itemref: &'a Element) -> MediaMetadata<'a> {
+        let mut elements = itemref.children();
+
+        if let Some(overlay) = self.media_overlay(itemref) {
+            elements.extend(overlay.children());
+        }
+
+        MediaMetadata::new(elements)
+    }
+
+    /// Generate a table of contents from the headings of the content
+    /// documents in the spine, such as for converted ebooks whose
+    /// navigation only contains a single entry.
+    ///
+    /// Headings are nested by level, where an `h2` following an `h1`
+    /// becomes its child. The name of each element is the text of the
+    /// heading, and the value is the href of its document, which is
+    /// relative to the root file directory like manifest hrefs. The
+    /// `id` of a heading is appended as the fragment. As ebooks are
+    /// read-only, anchors are not created for headings without an
+    /// `id`, which instead reference the start of their document.
+    ///
+    /// Documents that cannot be read are skipped.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// use rbook::epub::HeadingOptions;
+    ///
+    /// let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    /// let toc = epub.generate_toc_from_headings(&HeadingOptions::new());
+    ///
+    /// let chapter = toc
+    ///     .elements()
+    ///     .into_iter()
+    ///     .find(|element| element.name() == "Chapter 1. Loomings.")
+    ///     .unwrap();
+    /// assert_eq!("chapter_001.xhtml", chapter.value());
+    /// ```
+    pub fn generate_toc_from_headings(&self, options: &HeadingOptions) -> Toc {
+        let headings: Vec<_> = self
+            .spine
+            .elements()
+            .into_iter()
+            .filter(|itemref| {
+                options.includes_non_linear()
+                    || itemref.get_attribute(constants::LINEAR) != Some(constants::NO)
+            })
+            .filter_map(|itemref| self.manifest.by_id(itemref.name()))
+            .filter_map(|element| {
+                let content = self.read_file(element.value()).ok()?;
+                let headings = headings::find_headings(&content, options).ok()?;
+
+                Some(
+                    headings
+                        .into_iter()
+                        .map(|heading| (element.value().to_string(), heading)),
+                )
+            })
+            .flatten()
+            .collect();
+
+        let root = TempElement {
+            name: constants::TOC.to_string(),
+            children: Some(headings::to_toc_elements(headings)),
+            ..TempElement::default()
+        };
+
+        Toc::new(to_rc_nav_groups(HashMap::from([(
+            constants::TOC.to_string(),
+            root,
+        )])))
+    }
+
+    /// Retrieve the hints that determine the layout of the ebook,
+    /// combining the `page-progression-direction` of the spine, the
+    /// primary language, and the `writing-mode` declared by the
+    /// stylesheets of the manifest.
+    ///
+    /// Stylesheets that cannot be read are skipped.
+    ///
+    /// See [LayoutHints] for more details.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// # let epub = rbook::Epub::new("tests/ebooks/example_epub").unwrap();
+    /// let hints = epub.layout_hints();
+    ///
+    /// assert_eq!(Some("ltr"), hints.page_progression_direction());
+    /// assert_eq!(None, hints.writing_mode());
+    /// ```
+    pub fn layout_hints(&self) -> LayoutHints {
+        let writing_mode = self
+            .manifest
+            .all_by_media_type(constants::CSS_TYPE)
+            .into_iter()
+            .filter_map(|element| self.read_file(element.value()).ok())
+            .find_map(|css| layout::find_writing_mode(&css));
+
+        LayoutHints::new(
+            self.spine
+                .get_attribute(constants::PAGE_PROGRESSION_DIRECTION),
+            self.metadata
+                .language()
+                .map(|element| element.value().trim()),
+            writing_mode,
+        )
+    }
+
+    /// Run a set of rules against the ebook, returning their findings
+    /// in the order the rules were added.
+    ///
+    /// See [lint](crate::lint) for more details.
+    ///
+    /// # Examples
+    /// Checking for errors:
+    /// ```
+    /// # use rbook::Ebook;
+    /// use rbook::lint::{RuleSet, Severity};
+    ///
+    /// let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    /// let findings = epub.lint(&RuleSet::recommended());
+    ///
+    /// assert!(findings
+    ///     .iter()
+    ///     .all(|finding| finding.severity() < Severity::Error));
+    /// ```
+    pub fn lint(&self, rules: &RuleSet) -> Vec<Finding> {
+        rules.run(self)
+    }
+
+    /// Retrieve a digest that identifies the content of the ebook,
+    /// as 32 hexadecimal characters.
+    ///
+    /// The digest combines the release identifier (or the unique
+    /// identifier if there is no modified date), the hrefs of the spine,
+    /// and the content of every manifest resource in manifest order.
+    /// Differences in how the archive is packaged, such as the order
+    /// of zip entries, timestamps, or compression, do not affect the
+    /// digest, allowing copies of the same book to be deduplicated.
+    ///
+    /// The digest is stable across platforms and versions of rbook.
+    /// Resources that cannot be read contribute a placeholder instead.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// let epub = rbook::Epub::new("tests/ebooks/example_epub").unwrap();
+    /// let fingerprint = epub.fingerprint();
+    ///
+    /// assert_eq!(32, fingerprint.len());
+    /// assert_eq!(fingerprint, epub.fingerprint());
+    /// ```
+    pub fn fingerprint(&self) -> String {
+        let mut fingerprint = fingerprint::Fingerprint::new();
+        let identifier = self.metadata.release_identifier().or_else(|| {
+            self.metadata
+                .unique_identifier()
+                .map(|element| element.value().to_string())
+        });
+
+        fingerprint.write(identifier.unwrap_or_default().trim().as_bytes());
+
+        for spine_element in self.spine.elements() {
+            let href = self
+                .manifest
+                .by_id(spine_element.name())
+                .map(|element| self.archive_href(element.value()))
+                .unwrap_or_default();
+            fingerprint.write(href.as_bytes());
+        }
+
+        for element in self.manifest.elements() {
+            let href = element.value();
+            fingerprint.write(self.archive_href(href).as_bytes());
+
+            match self.read_bytes_file(href) {
+                Ok(data) => fingerprint.write(&data),
+                // Distinguish unreadable resources from empty resources
+                Err(_) => fingerprint.write(b"\0unreadable"),
+            }
+        }
+
+        fingerprint.finish()
+    }
+
+    /// Check if two ebooks have the same content, ignoring differences
+    /// in how their archives are packaged.
+    ///
+    /// Equivalent to comparing their [fingerprints](Self::fingerprint).
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// let epub1 = rbook::Epub::new("tests/ebooks/example_epub").unwrap();
+    /// let epub2 = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    ///
+    /// assert!(epub1.equivalent_to(&epub1));
+    /// assert!(!epub1.equivalent_to(&epub2));
+    /// ```
+    pub fn equivalent_to(&self, other: &Self) -> bool {
+        self.fingerprint() == other.fingerprint()
+    }
+
+    /// Retrieve information about the images of the manifest, such as
+    /// their size, in the same order as [Manifest::images].
+    ///
+    /// With the `image-meta` feature enabled, each image is read to
+    /// retrieve its format and dimensions from its header.
+    ///
+    /// See [ImageInfo] for more details.
+    ///
+    /// # Examples
+    /// Basic Usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// # let epub = rbook::Epub::new("tests/ebooks/childrens-literature.epub").unwrap();
+    /// let images = epub.images_with_info();
+    ///
+    /// assert_eq!("images/cover.png", images[0].element().value());
+    /// assert_eq!(Some(41134), images[0].size());
+    /// ```
+    pub fn images_with_info(&self) -> Vec<ImageInfo<'_>> {
+        self.manifest
+            .images()
+            .into_iter()
+            .map(|element| {
+                let href = element.value();
+                let path = self.parse_path(&href);
+                let size = self
+                    .read_archive(&path, |archive, path| archive.file_size(path))
+                    .ok();
+                let compressed_size = self
+                    .read_archive(&path, |archive, path| archive.compressed_file_size(path))
+                    .ok();
+
+                #[cfg_attr(not(feature = "image-meta"), allow(unused_mut))]
+                let mut image_info = ImageInfo::new(element, size, compressed_size);
+
+                #[cfg(feature = "image-meta")]
+                if let Ok(data) = self.read_bytes_file(&path) {
+                    image_info.read_header(&data);
+                }
+
+                image_info
+            })
+            .collect()
+    }
+
     /// Retrieve the root ".opf" file associated with the ebook.
     ///
     /// # Examples
@@ -144,6 +873,70 @@ impl Epub {
         &self.root_file
     }
 
+    /// Retrieve the files of a zipped epub with names that were not stored
+    /// as valid utf-8, which were decoded according to the
+    /// [FilenameDecoding] setting. Unzipped epub directories always
+    /// return an empty slice.
+    ///
+    /// # Examples
+    /// Basic Usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// let epub = rbook::Epub::new("tests/ebooks/encoding.epub").unwrap();
+    ///
+    /// let names: Vec<_> = epub.decoded_names().iter().map(|name| name.name()).collect();
+    /// assert_eq!(vec!["OEBPS/chapitre_é.xhtml", "OEBPS/café.txt"], names);
+    /// ```
+    pub fn decoded_names(&self) -> &[DecodedName] {
+        self.archive.decoded_names()
+    }
+
+    /// Retrieve the raw contents of the root ".opf" package file.
+    ///
+    /// Useful for validators and tools that require information not
+    /// retained when parsing, such as comments or the original formatting.
+    ///
+    /// # Errors
+    /// If the package file cannot be read, an [EbookError] will be returned.
+    ///
+    /// # Examples
+    /// Basic Usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// # let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    /// let package_xml = epub.package_xml().unwrap();
+    ///
+    /// assert!(package_xml.starts_with(b"<?xml"));
+    /// ```
+    pub fn package_xml(&self) -> EbookResult<Vec<u8>> {
+        self.archive
+            .read_bytes_file(&self.root_file)
+            .map_err(EbookError::Archive)
+    }
+
+    /// Retrieve the display options of Apple Books, stored in
+    /// `META-INF/com.apple.ibooks.display-options.xml`.
+    /// Returns [None] if the file does not exist.
+    ///
+    /// See [AppleDisplayOptions] for more details.
+    ///
+    /// # Errors
+    /// If the file cannot be read or parsed, an [EbookError] will be returned.
+    pub fn apple_display_options(&self) -> EbookResult<Option<AppleDisplayOptions>> {
+        let data = match self
+            .archive
+            .read_bytes_file(Path::new(constants::APPLE_DISPLAY_OPTIONS))
+        {
+            Ok(data) => data,
+            Err(ArchiveError::InvalidPath { .. }) => return Ok(None),
+            Err(error) => return Err(EbookError::Archive(error)),
+        };
+
+        vendor::parse_display_options(&data)
+            .map(Some)
+            .map_err(|error| error.in_file(constants::APPLE_DISPLAY_OPTIONS))
+    }
+
     /// Retrieve the root file directory of the ebook where
     /// resources are stored
     ///
@@ -156,89 +949,452 @@ impl Epub {
     /// let root_file_dir = epub.root_file_directory();
     /// assert_eq!(PathBuf::from("OPS"), root_file_dir);
     ///
-    /// let root_file = root_file_dir.join("package.opf");
-    /// assert_eq!(PathBuf::from("OPS/package.opf"), root_file);
-    /// ```
-    pub fn root_file_directory(&self) -> &Path {
-        utility::get_parent_path(&self.root_file)
+    /// let root_file = root_file_dir.join("package.opf");
+    /// assert_eq!(PathBuf::from("OPS/package.opf"), root_file);
+    /// ```
+    pub fn root_file_directory(&self) -> &Path {
+        utility::get_parent_path(&self.root_file)
+    }
+
+    /// Retrieve the file contents.
+    ///
+    /// The given path is normalized and appended to the root file directory
+    /// if it does not contain it. However, retrieving content within `META-INF`
+    /// is an exception. Please note that the root file directory varies
+    /// between ebooks.
+    ///
+    /// # Examples:
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// # let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    /// // Without providing the root file directory
+    /// let content1 = epub.read_file("package.opf").unwrap();
+    /// // Providing the root file directory
+    /// let content2 = epub.read_file("OPS/package.opf").unwrap();
+    ///
+    /// assert_eq!(content1, content2);
+    /// // Retrieving container.xml
+    /// let content3 = epub.read_file("META-INF/container.xml").unwrap();
+    /// // Providing a path that needs to be normalized
+    /// let content4 = epub.read_file("../META-INF//./container.xml").unwrap();
+    ///
+    /// assert_eq!(content3, content4);
+    /// ```
+    pub fn read_file<P: AsRef<Path>>(&self, path: P) -> EbookResult<String> {
+        let path = self.parse_path(&path);
+        self.read_archive(&path, |archive, path| archive.read_file(path))
+    }
+
+    /// Retrieve the file contents in bytes.
+    ///
+    /// The given path is normalized and appended to the root file directory
+    /// if it does not contain it. However, retrieving content within `META-INF`
+    /// is an exception. Please note that the root file directory varies
+    /// between ebooks.
+    ///
+    /// # Examples:
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// # let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    /// // Without providing the root file directory
+    /// let content1 = epub.read_bytes_file("images/9780316000000.jpg").unwrap();
+    /// // Providing the root file directory
+    /// let content2 = epub.read_bytes_file("OPS/images/9780316000000.jpg").unwrap();
+    ///
+    /// assert_eq!(content1, content2)
+    /// ```
+    pub fn read_bytes_file<P: AsRef<Path>>(&self, path: P) -> EbookResult<Vec<u8>> {
+        let path = self.parse_path(&path);
+        self.read_archive(&path, |archive, path| archive.read_bytes_file(path))
+    }
+
+    // Retrieve the contents or information of a file from the archive
+    fn read_archive<'a, T, F>(&'a self, path: &Path, read: F) -> EbookResult<T>
+    where
+        F: Fn(&'a dyn Archive, &Path) -> Result<T, ArchiveError>,
+    {
+        read_archive(&*self.archive, &self.settings, path, read)
+    }
+
+    // Retrieve the path of a manifest href that only exists within
+    // the archive when ignoring case, regardless of the settings
+    pub(crate) fn case_mismatched_path(&self, href: &str) -> Option<String> {
+        let path = self.parse_path(&href);
+
+        match self.archive.file_size(&path) {
+            Err(ArchiveError::InvalidPath { .. }) => self
+                .archive
+                .find_ignore_case(&path)
+                .map(|resolved| resolved.to_string_lossy().replace('\\', "/")),
+            _ => None,
+        }
+    }
+
+    /// Retrieve the manifest element that a [Resource] references.
+    ///
+    /// Useful to map between the different identifiers of a resource,
+    /// such as retrieving the `id` of the resource at a spine index.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// use rbook::epub::Resource;
+    ///
+    /// let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    ///
+    /// let element = epub.manifest_element(Resource::SpineIndex(31)).unwrap();
+    /// assert_eq!("xchapter_026", element.name());
+    /// assert_eq!("chapter_026.xhtml", element.value());
+    ///
+    /// let element2 = epub.manifest_element(Resource::Href("chapter_026.xhtml")).unwrap();
+    /// assert_eq!(element, element2);
+    /// ```
+    pub fn manifest_element(&self, resource: Resource<'_>) -> Option<&Element> {
+        match resource {
+            Resource::Id(id) => self.manifest.by_id(id),
+            Resource::Href(href) => self.manifest.by_href(href),
+            Resource::SpineIndex(index) => {
+                let spine_elements = self.spine.elements();
+                self.manifest.by_id(spine_elements.get(index)?.name())
+            }
+        }
+    }
+
+    /// Retrieve the contents of a resource using a manifest `id`,
+    /// href, or spine index.
+    ///
+    /// # Errors
+    /// If the resource does not reference a manifest element, or its
+    /// contents cannot be read, an [EbookError] will be returned.
+    /// Resources retrieved by href are not required to be in the manifest.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// use rbook::epub::Resource;
+    ///
+    /// let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    ///
+    /// let content1 = epub.read_resource(Resource::Id("xchapter_026")).unwrap();
+    /// let content2 = epub.read_resource(Resource::Href("chapter_026.xhtml")).unwrap();
+    /// let content3 = epub.read_resource(Resource::SpineIndex(31)).unwrap();
+    ///
+    /// assert_eq!(content1, content2);
+    /// assert_eq!(content2, content3);
+    /// ```
+    pub fn read_resource(&self, resource: Resource<'_>) -> EbookResult<String> {
+        match resource {
+            Resource::Href(href) => self.read_file(href),
+            _ => self.read_file(self.resource_href(resource)?),
+        }
+    }
+
+    /// Retrieve the contents of a resource in bytes using a manifest
+    /// `id`, href, or spine index.
+    ///
+    /// # Errors
+    /// If the resource does not reference a manifest element, or its
+    /// contents cannot be read, an [EbookError] will be returned.
+    /// Resources retrieved by href are not required to be in the manifest.
+    pub fn read_resource_bytes(&self, resource: Resource<'_>) -> EbookResult<Vec<u8>> {
+        match resource {
+            Resource::Href(href) => self.read_bytes_file(href),
+            _ => self.read_bytes_file(self.resource_href(resource)?),
+        }
+    }
+
+    /// Retrieve the contents of a resource in bytes, borrowing them if
+    /// the archive already holds them in memory.
+    ///
+    /// Avoids the allocation of [read_resource_bytes](Self::read_resource_bytes)
+    /// for backends that keep file contents in memory. Files stored without
+    /// compression are borrowed from epubs created with
+    /// [from_bytes](Self::from_bytes). Otherwise, files are read on demand
+    /// and returned as [Cow::Owned].
+    ///
+    /// # Errors
+    /// If the resource does not reference a manifest element, or its
+    /// contents cannot be read, an [EbookError] will be returned.
+    /// Resources retrieved by href are not required to be in the manifest.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// use rbook::epub::Resource;
+    ///
+    /// let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    /// let data = epub.read_resource_ref(Resource::Id("cover-image")).unwrap();
+    ///
+    /// assert!(data.starts_with(&[0xFF, 0xD8]));
+    /// ```
+    pub fn read_resource_ref(&self, resource: Resource<'_>) -> EbookResult<Cow<'_, [u8]>> {
+        let href = match resource {
+            Resource::Href(href) => href,
+            _ => self.resource_href(resource)?,
+        };
+        let path = self.parse_path(&href);
+
+        self.read_archive(&path, |archive, path| archive.read_bytes_ref(path))
+    }
+
+    /// Retrieve information about the file of a resource within the
+    /// container, such as its compressed size, CRC-32 checksum, and
+    /// modification time, without reading its contents.
+    ///
+    /// # Errors
+    /// Returns an [Archive](EbookError::Archive) error if the resource
+    /// does not reference a manifest element, or if its file cannot
+    /// be accessed.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use rbook::Ebook;
+    /// use rbook::epub::{Compression, Resource};
+    ///
+    /// let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    /// let info = epub.resource_info(Resource::Id("xchapter_001")).unwrap();
+    ///
+    /// assert_eq!("OPS/chapter_001.xhtml", info.path());
+    /// assert_eq!(Some(Compression::Deflated), info.compression());
+    /// assert!(info.compressed_size() < info.size());
+    /// ```
+    pub fn resource_info(&self, resource: Resource<'_>) -> EbookResult<EntryInfo> {
+        let href = self.resource_href(resource)?;
+        self.read_archive(&self.parse_path(&href), |archive, path| {
+            archive.file_info(path)
+        })
+    }
+
+    /// Retrieve information about every file within the container,
+    /// including files not declared in the [Manifest], such as
+    /// `META-INF/container.xml` or orphaned files. Directories are
+    /// excluded.
+    ///
+    /// Files of zip archives are in the order they are stored, while
+    /// files of unzipped epubs are sorted by path. Within unzipped
+    /// epubs, symbolic links to directories are not followed.
+    ///
+    /// # Errors
+    /// Returns an [Archive](EbookError::Archive) error if the container
+    /// cannot be listed.
+    ///
+    /// # Examples
+    /// Finding files not declared in the manifest:
+    /// ```
+    /// # use rbook::Ebook;
+    /// let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    /// let entries = epub.container_entries().unwrap();
+    ///
+    /// assert_eq!("mimetype", entries[0].path());
+    ///
+    /// let undeclared: Vec<_> = entries
+    ///     .iter()
+    ///     .map(|entry| entry.path())
+    ///     .filter(|path| epub.manifest().by_href(path.trim_start_matches("OPS/")).is_none())
+    ///     .collect();
+    ///
+    /// assert!(undeclared.contains(&"META-INF/container.xml"));
+    /// ```
+    pub fn container_entries(&self) -> EbookResult<Vec<EntryInfo>> {
+        self.archive.entries().map_err(EbookError::Archive)
+    }
+
+    // Retrieve the href of the manifest element that a resource references
+    fn resource_href(&self, resource: Resource<'_>) -> EbookResult<&str> {
+        self.manifest_element(resource)
+            .map(|element| element.value())
+            .ok_or_else(|| {
+                EbookError::Archive(ArchiveError::InvalidPath {
+                    cause: "Resource not found".to_string(),
+                    description: format!("{resource:?} does not reference a manifest element"),
+                })
+            })
+    }
+
+    // Retrieve the path within the archive of a manifest href
+    pub(crate) fn archive_href(&self, href: &str) -> String {
+        utility::normalize_path(&self.parse_path(&href))
+            .to_string_lossy()
+            .replace('\\', "/")
+    }
+
+    // Retrieve the path within the archive of a toc href, which
+    // is relative to the location of the table of contents file
+    pub(crate) fn toc_archive_href(&self, href: &str) -> String {
+        match get_toc_href(&self.manifest) {
+            Ok(location) => self.archive_href(&resolve_href(location, href)),
+            Err(_) => self.archive_href(href),
+        }
+    }
+
+    // Transform a given path into a valid path if necessary
+    // to access the proper contents of the ebook
+    fn parse_path<'a, P: AsRef<Path>>(&self, path: &'a P) -> Cow<'a, Path> {
+        let root_file_dir = self.root_file_directory();
+        let path = path.as_ref();
+
+        // If the path is the container or contains the root file dir, return the
+        // original. If not, concat the user supplied path to the root file dir.
+        match path.starts_with(constants::META_INF) || path.starts_with(root_file_dir) {
+            true => Cow::Borrowed(path),
+            false => Cow::Owned(root_file_dir.join(path)),
+        }
+    }
+
+    /// Creates a new epub object from a directory or file using
+    /// custom [EpubSettings].
+    ///
+    /// # Errors
+    /// See [Ebook::new] for possible errors.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use rbook::epub::EpubSettings;
+    ///
+    /// let settings = EpubSettings::new().href_case_insensitive(true);
+    /// let epub = rbook::Epub::open_with("tests/ebooks/example_epub", settings).unwrap();
+    ///
+    /// let element = epub.manifest().by_href("C1.XHTML").unwrap();
+    /// assert_eq!("c1", element.name());
+    /// ```
+    pub fn open_with<P: AsRef<Path>>(path: P, settings: EpubSettings) -> EbookResult<Self> {
+        // Fail early as there is no filesystem to access
+        if cfg!(all(target_arch = "wasm32", target_os = "unknown")) {
+            return Err(EbookError::IO {
+                cause: "Filesystem is unavailable".to_string(),
+                description: format!(
+                    "Unable to open '{:?}' on wasm32-unknown-unknown. \
+                    Please use `read_from` with the contents of the ebook instead.",
+                    path.as_ref()
+                ),
+            });
+        }
+
+        let metadata = utility::get_path_metadata(&path)?;
+
+        // Unzip the file if it is not directory. If it is, the contents can
+        // be accessed directly which makes using a zip file unnecessary.
+        let archive: Shared<dyn Archive> = match metadata.is_file() {
+            true => Shared::new(ZipFileArchive::new(&path, settings.filename_decoding)?),
+            false => Shared::new(DirArchive::new(&path, settings.follow_symlinks)?),
+        };
+
+        Epub::build(archive, settings)
+    }
+
+    /// Creates a new epub object using a reader instance and
+    /// custom [EpubSettings].
+    ///
+    /// # Errors
+    /// See [Ebook::read_from] for possible errors.
+    pub fn read_from_with<
+        #[cfg(feature = "multi-thread")] R: Seek + Read + Send + Sync + 'static,
+        #[cfg(not(feature = "multi-thread"))] R: Seek + Read + 'static,
+    >(
+        reader: R,
+        settings: EpubSettings,
+    ) -> EbookResult<Self> {
+        let archive = ZipArchive::new(reader, settings.filename_decoding)?;
+        Epub::build(Shared::new(archive), settings)
     }
 
-    /// Retrieve the file contents.
+    /// Creates a new epub object from the contents of a zip file
+    /// held in memory.
     ///
-    /// The given path is normalized and appended to the root file directory
-    /// if it does not contain it. However, retrieving content within `META-INF`
-    /// is an exception. Please note that the root file directory varies
-    /// between ebooks.
+    /// Unlike [read_from](Ebook::read_from), the contents are kept as is,
+    /// allowing [read_resource_ref](Self::read_resource_ref) to borrow
+    /// files that are stored without compression.
     ///
-    /// # Examples:
+    /// # Errors
+    /// See [Ebook::read_from] for possible errors.
+    ///
+    /// # Examples
     /// Basic usage:
     /// ```
-    /// # use rbook::Ebook;
-    /// # let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
-    /// // Without providing the root file directory
-    /// let content1 = epub.read_file("package.opf").unwrap();
-    /// // Providing the root file directory
-    /// let content2 = epub.read_file("OPS/package.opf").unwrap();
-    ///
-    /// assert_eq!(content1, content2);
-    /// // Retrieving container.xml
-    /// let content3 = epub.read_file("META-INF/container.xml").unwrap();
-    /// // Providing a path that needs to be normalized
-    /// let content4 = epub.read_file("../META-INF//./container.xml").unwrap();
+    /// let bytes = std::fs::read("tests/ebooks/moby-dick.epub").unwrap();
+    /// let epub = rbook::Epub::from_bytes(bytes).unwrap();
     ///
-    /// assert_eq!(content3, content4);
+    /// assert_eq!("Moby-Dick", epub.metadata().title().unwrap().value());
     /// ```
-    pub fn read_file<P: AsRef<Path>>(&self, path: P) -> EbookResult<String> {
-        let path = self.parse_path(&path);
-        self.archive.read_file(&path).map_err(EbookError::Archive)
+    pub fn from_bytes(bytes: Vec<u8>) -> EbookResult<Self> {
+        Epub::from_bytes_with(bytes, EpubSettings::default())
     }
 
-    /// Retrieve the file contents in bytes.
+    /// Creates a new epub object from the contents of a zip file
+    /// held in memory and custom [EpubSettings].
     ///
-    /// The given path is normalized and appended to the root file directory
-    /// if it does not contain it. However, retrieving content within `META-INF`
-    /// is an exception. Please note that the root file directory varies
-    /// between ebooks.
+    /// # Errors
+    /// See [Ebook::read_from] for possible errors.
+    pub fn from_bytes_with(bytes: Vec<u8>, settings: EpubSettings) -> EbookResult<Self> {
+        let archive = ZipBytesArchive::new(bytes, settings.filename_decoding)?;
+        Epub::build(Shared::new(archive), settings)
+    }
+
+    /// Creates a new epub object from a directory or file that originates
+    /// from an untrusted source, such as a user upload, using
+    /// [EpubSettings::untrusted].
     ///
-    /// # Examples:
+    /// Malformed epubs result in an [EbookError] rather than a panic,
+    /// regardless of how an epub is opened. The untrusted settings
+    /// additionally limit the size of files and the files accessible
+    /// within epub directories.
+    ///
+    /// # Errors
+    /// See [Ebook::new] for possible errors.
+    ///
+    /// # Examples
     /// Basic usage:
     /// ```
-    /// # use rbook::Ebook;
-    /// # let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
-    /// // Without providing the root file directory
-    /// let content1 = epub.read_bytes_file("images/9780316000000.jpg").unwrap();
-    /// // Providing the root file directory
-    /// let content2 = epub.read_bytes_file("OPS/images/9780316000000.jpg").unwrap();
+    /// let epub = rbook::Epub::open_untrusted("tests/ebooks/moby-dick.epub").unwrap();
     ///
-    /// assert_eq!(content1, content2)
+    /// assert_eq!("Moby-Dick", epub.metadata().title().unwrap().value());
     /// ```
-    pub fn read_bytes_file<P: AsRef<Path>>(&self, path: P) -> EbookResult<Vec<u8>> {
-        let path = self.parse_path(&path);
-        self.archive
-            .read_bytes_file(&path)
-            .map_err(EbookError::Archive)
+    pub fn open_untrusted<P: AsRef<Path>>(path: P) -> EbookResult<Self> {
+        Self::open_with(path, EpubSettings::untrusted())
     }
 
-    // Transform a given path into a valid path if necessary
-    // to access the proper contents of the ebook
-    fn parse_path<'a, P: AsRef<Path>>(&self, path: &'a P) -> Cow<'a, Path> {
-        let root_file_dir = self.root_file_directory();
-        let path = path.as_ref();
+    // Wrap an archive to decrypt the files listed in `encryption.xml`,
+    // which is optional as most epubs are not encrypted
+    fn decrypting_archive(
+        archive: Shared<dyn Archive>,
+        decryptor: &decryption::Decryptor,
+    ) -> EbookResult<Shared<dyn Archive>> {
+        let encrypted = match archive.read_bytes_file(Path::new(constants::ENCRYPTION)) {
+            Ok(data) => decryption::parse_encryption(&data)
+                .map_err(|error| error.in_file(constants::ENCRYPTION))?,
+            Err(_) => return Ok(archive),
+        };
 
-        // If the path is the container or contains the root file dir, return the
-        // original. If not, concat the user supplied path to the root file dir.
-        match path.starts_with(constants::META_INF) || path.starts_with(root_file_dir) {
-            true => Cow::Borrowed(path),
-            false => Cow::Owned(root_file_dir.join(path)),
-        }
+        Ok(Shared::new(DecryptingArchive::new(
+            archive,
+            Shared::clone(&decryptor.0),
+            encrypted,
+        )))
     }
 
-    fn build(archive: Box<dyn Archive>) -> EbookResult<Self> {
+    fn build(archive: Shared<dyn Archive>, settings: EpubSettings) -> EbookResult<Self> {
+        let archive: Shared<dyn Archive> = match settings.max_file_size {
+            Some(max_file_size) => Shared::new(LimitedArchive::new(archive, max_file_size)),
+            None => archive,
+        };
+        let archive = match &settings.decryptor {
+            Some(decryptor) => Self::decrypting_archive(archive, decryptor)?,
+            None => archive,
+        };
+
         // Parse "META-INF/container.xml"
         let content_meta_inf = archive
             .read_bytes_file(Path::new(constants::CONTAINER))
             .map_err(EbookError::Archive)?;
-        let root_file = parse_container(&content_meta_inf)?;
+        let root_file = parse_container(&content_meta_inf)
+            .map_err(|error| error.in_file(constants::CONTAINER))?;
 
         // Get epub root file directory
         let root_file_dir = utility::get_parent_path(&root_file);
@@ -247,25 +1403,28 @@ impl Epub {
         let content_pkg_opf = archive
             .read_bytes_file(&root_file)
             .map_err(EbookError::Archive)?;
-        let (metadata, manifest, spine, guide) = parse_package(&content_pkg_opf)?;
-
-        // Get toc.xhtml/ncx href value
-        let toc_href = get_toc_href(&manifest)?;
-
-        // Parse "toc.xhtml/ncx"
-        let content_toc = archive
-            .read_file(&root_file_dir.join(toc_href))
-            .map_err(EbookError::Archive)?;
-        let toc = parse_toc(&content_toc)?;
+        let (metadata, mut manifest, spine, guide, collections, foreign_elements) =
+            parse_package(&content_pkg_opf, &settings)
+                .map_err(|error| error.in_file(&root_file))?;
+        manifest.set_href_case_insensitive(settings.href_case_insensitive);
+
+        let toc = match settings.lazy_toc {
+            true => OnceCell::new(),
+            false => OnceCell::from(load_toc(&*archive, root_file_dir, &manifest, &settings)?),
+        };
 
         Ok(Self {
             archive,
+            settings,
             root_file,
-            metadata,
-            manifest,
-            spine,
-            guide,
-            toc,
+            metadata: Shared::new(metadata),
+            manifest: Shared::new(manifest),
+            spine: Shared::new(spine),
+            guide: Shared::new(guide),
+            toc: Shared::new(toc),
+            empty_toc: OnceCell::new(),
+            collections: Shared::new(collections),
+            foreign_elements: Shared::new(foreign_elements),
         })
     }
 }
@@ -278,7 +1437,9 @@ impl Debug for Epub {
             .field("manifest", &self.manifest)
             .field("spine", &self.spine)
             .field("landmarks", &self.guide)
-            .field("toc", &self.toc)
+            // Avoid parsing a lazily loaded toc when formatting
+            .field("toc", &self.toc.get())
+            .field("collections", &self.collections)
             .finish()
     }
 }
@@ -287,14 +1448,7 @@ impl Ebook for Epub {
     type Format = Self;
 
     fn new<P: AsRef<Path>>(path: P) -> EbookResult<Self> {
-        let metadata = utility::get_path_metadata(&path)?;
-
-        // Unzip the file if it is not directory. If it is, the contents can
-        // be accessed directly which makes using a zip file unnecessary.
-        Epub::build(match metadata.is_file() {
-            true => Box::new(ZipArchive::new(BufReader::new(utility::get_file(&path)?))?),
-            false => Box::new(DirArchive::new(&path)?),
-        })
+        Epub::open_with(path, EpubSettings::default())
     }
 
     fn read_from<
@@ -303,7 +1457,7 @@ impl Ebook for Epub {
     >(
         reader: R,
     ) -> EbookResult<Self> {
-        Epub::build(Box::new(ZipArchive::new(reader)?))
+        Epub::read_from_with(reader, EpubSettings::default())
     }
 }
 
@@ -313,6 +1467,10 @@ impl Readable for Epub {
         self.spine.elements().len()
     }
 
+    fn weights(&self) -> SpineWeights {
+        self.spine_weights()
+    }
+
     fn navigate_str(&self, path: &str) -> Option<ReaderResult<usize>> {
         // Avoid freeing reference to elements while still in use
         let manifest_elements = self.manifest.elements();
@@ -340,46 +1498,115 @@ impl Readable for Epub {
         Some(index)
     }
 
-    fn navigate(&self, index: usize) -> Option<ReaderResult<Content>> {
-        fn get_content<'a>(ebook: &'a Epub, spine_element: &Element) -> ReaderResult<Content<'a>> {
-            let manifest_element = ebook.manifest.by_id(spine_element.name()).ok_or_else(|| {
-                ReaderError::InvalidReference {
-                    cause: "Invalid manifest reference".to_string(),
-                    description: "Please ensure all spine elements \
-                    reference a valid manifest element."
-                        .to_string(),
-                }
-            })?;
+    fn navigate(&self, index: usize) -> Option<ReaderResult<Content<'_>>> {
+        let spine_elements = self.spine.elements();
+        let spine_element = spine_elements.get(index)?;
+        Some(self.get_content(spine_element, None))
+    }
+
+    #[cfg(feature = "multi-thread")]
+    fn load_page(&self, index: usize) -> Option<PageLoader> {
+        let spine_elements = self.spine.elements();
+        let manifest_element = self.manifest.by_id(spine_elements.get(index)?.name())?;
+        let path = self.parse_path(&manifest_element.value()).into_owned();
+        let archive = Shared::clone(&self.archive);
+        let settings = self.settings.clone();
+
+        Some(Box::new(move || {
+            read_archive(&*archive, &settings, &path, |archive, path| {
+                archive.read_bytes_file(path)
+            })
+        }))
+    }
+
+    fn navigate_loaded(&self, index: usize, data: Vec<u8>) -> Option<ReaderResult<Content<'_>>> {
+        let spine_elements = self.spine.elements();
+        let spine_element = spine_elements.get(index)?;
+        Some(self.get_content(spine_element, Some(data)))
+    }
+}
+
+#[cfg(feature = "reader")]
+impl Epub {
+    // Check if a reading system would display a spine entry
+    fn is_displayed(
+        &self,
+        profile: &ReadingSystemProfile,
+        spine_element: &Element,
+        manifest_element: &Element,
+    ) -> bool {
+        let has_property = |property| {
+            xmlutil::equals_attribute_by_value(spine_element, constants::PROPERTIES, property)
+        };
+        // Entries may override the layout of the entire ebook
+        let is_pre_paginated = has_property(constants::PRE_PAGINATED_PROPERTY)
+            || (!has_property(constants::REFLOWABLE_PROPERTY)
+                && self
+                    .metadata
+                    .get(constants::LAYOUT)
+                    .first()
+                    .is_some_and(|layout| layout.value().trim() == constants::PRE_PAGINATED));
+        let is_non_linear = spine_element.get_attribute(constants::LINEAR) == Some(constants::NO);
+
+        !(profile.skips_non_linear() && is_non_linear)
+            && (profile.supports_fixed_layout() || !is_pre_paginated)
+            && self.manifest.resolve(manifest_element, profile).is_some()
+    }
+
+    // Retrieve the content of a spine element. If the data of the
+    // content is not provided, it is read from the archive.
+    fn get_content(
+        &self,
+        spine_element: &Element,
+        data: Option<Vec<u8>>,
+    ) -> ReaderResult<Content<'_>> {
+        let manifest_element = self.manifest.by_id(spine_element.name()).ok_or_else(|| {
+            ReaderError::InvalidReference {
+                cause: "Invalid manifest reference".to_string(),
+                description: "Please ensure all spine elements \
+                reference a valid manifest element."
+                    .to_string(),
+            }
+        })?;
 
-            let data = ebook
+        let data = match data {
+            Some(data) => data,
+            None => self
                 .read_bytes_file(manifest_element.value())
-                .map_err(ReaderError::NoContent)?;
+                .map_err(ReaderError::NoContent)?,
+        };
 
-            let mut fields = HashMap::from([
-                (
-                    ContentType::Id.as_str(),
-                    Cow::Borrowed(manifest_element.name()),
+        let mut fields = HashMap::from([
+            (
+                ContentType::Id.as_str(),
+                Cow::Borrowed(manifest_element.name()),
+            ),
+            (
+                ContentType::Path.as_str(),
+                Cow::Owned(
+                    utility::normalize_path(&self.parse_path(&manifest_element.value()))
+                        .to_string_lossy()
+                        .replace('\\', "/"),
                 ),
-                (
-                    ContentType::Path.as_str(),
-                    Cow::Owned(
-                        utility::normalize_path(&ebook.parse_path(&manifest_element.value()))
-                            .to_string_lossy()
-                            .replace('\\', "/"),
-                    ),
-                ),
-            ]);
+            ),
+        ]);
 
-            if let Some(media_type) = manifest_element.get_attribute(constants::MEDIA_TYPE) {
-                fields.insert(ContentType::MediaType.as_str(), Cow::Borrowed(media_type));
-            }
+        let mut resources = Vec::new();
 
-            Ok(Content::new(data, fields))
+        if let Some(media_type) = manifest_element.get_attribute(constants::MEDIA_TYPE) {
+            fields.insert(ContentType::MediaType.as_str(), Cow::Borrowed(media_type));
+
+            // Only markup documents reference other resources
+            if constants::MARKUP_TYPES.contains(&media_type) {
+                resources = extract_references(&data, manifest_element.value())
+                    .unwrap_or_default()
+                    .iter()
+                    .filter_map(|href| self.manifest.by_href(href))
+                    .collect();
+            }
         }
 
-        let spine_elements = self.spine.elements();
-        let spine_element = spine_elements.get(index)?;
-        Some(get_content(self, spine_element))
+        Ok(Content::new(data, fields, resources))
     }
 }
 
@@ -509,7 +1736,16 @@ fn parse_container(data: &[u8]) -> EbookResult<PathBuf> {
     }
 }
 
-fn parse_package(data: &[u8]) -> EbookResult<(Metadata, Manifest, Spine, Guide)> {
+type Package = (
+    Metadata,
+    Manifest,
+    Spine,
+    Guide,
+    Vec<Collection>,
+    Vec<Element>,
+);
+
+fn parse_package(data: &[u8], settings: &EpubSettings) -> EbookResult<Package> {
     // Keep track of latest metadata entry
     let current_meta = RefCell::new(None);
     // Track contents
@@ -518,10 +1754,17 @@ fn parse_package(data: &[u8]) -> EbookResult<(Metadata, Manifest, Spine, Guide)>
     let mut itemref_vec = Vec::new(); // Spine contents
     let mut guide_vec = Vec::new(); // Guide contents (Epub 2 Only)
     let mut package_root = None; // Package element
-    let mut spine_root = None; // Spine element
-
-    // Stores the package and spine elements
-    let parent_element_handler = element!("package, spine", |element| {
+    let mut spine_attributes = None; // Spine element attributes
+    let mut manifest_attributes = Vec::new(); // Manifest element attributes
+    let collection_stack = Rc::new(RefCell::new(Vec::new())); // Nested collections
+    let collections = Rc::new(RefCell::new(Vec::new())); // Top-level collections
+    let mut foreign_package_vec = Vec::new(); // Unknown package children
+    let mut foreign_manifest_vec = Vec::new(); // Unknown manifest children
+    let mut foreign_spine_vec = Vec::new(); // Unknown spine children
+    let section_depth = Rc::new(Cell::new(0_usize)); // Open package sections
+
+    // Stores the package, manifest, and spine elements
+    let parent_element_handler = element!("package, manifest, spine", |element| {
         let name = element.tag_name();
         let attributes = xmlutil::copy_attributes(element.attributes());
 
@@ -533,12 +1776,9 @@ fn parse_package(data: &[u8]) -> EbookResult<(Metadata, Manifest, Spine, Guide)>
                     ..Element::default()
                 });
             }
+            constants::MANIFEST => manifest_attributes = attributes,
             constants::SPINE => {
-                spine_root.replace(TempElement {
-                    name,
-                    attributes,
-                    ..TempElement::default()
-                });
+                spine_attributes.replace(attributes);
             }
             _ => (),
         };
@@ -546,44 +1786,68 @@ fn parse_package(data: &[u8]) -> EbookResult<(Metadata, Manifest, Spine, Guide)>
         Ok(())
     });
 
-    let metadata_entry_handler = element!("metadata > *", |element| {
-        let mut meta = TempElement {
+    let metadata_entry_handler = element!("package > metadata > *", |element| {
+        let meta = Rc::new(RefCell::new(create_meta_entry(element)));
+
+        current_meta.borrow_mut().replace(Rc::clone(&meta));
+        meta_vec.push(meta);
+
+        Ok(())
+    });
+
+    let collection_handler = element!("collection", |element| {
+        collection_stack.borrow_mut().push(TempCollection {
             attributes: xmlutil::copy_attributes(element.attributes()),
-            ..TempElement::default()
-        };
+            ..TempCollection::default()
+        });
 
-        // Change name to the value of the name or
-        // property attribute of a meta element
-        // Newer meta element condition
-        if let Some(property) = element.get_attribute(constants::PROPERTY) {
-            meta.name = property;
-        }
-        // Legacy OPF2.0 meta element condition
-        else if let (Some(name), Some(content)) = (
-            element.get_attribute(constants::NAME),
-            element.get_attribute(constants::CONTENT),
-        ) {
-            meta.name = name;
-            meta.value = content;
-            meta.attributes.push(Attribute::new(
-                constants::LEGACY_FEATURE.to_string(),
-                constants::LEGACY_META.to_string(),
-            ));
-        }
-        // Use tag name instead
-        else {
-            meta.name = element.tag_name();
-        }
+        // Handle end tag event
+        let collection_stack = Rc::clone(&collection_stack);
+        let collections = Rc::clone(&collections);
+        let depth = Rc::clone(&section_depth);
+        section_depth.set(section_depth.get() + 1);
+        element.on_end_tag(move |_| {
+            let mut stack = collection_stack.borrow_mut();
+            depth.set(depth.get().saturating_sub(1));
+
+            if let Some(collection) = stack.pop().map(TempCollection::convert) {
+                match stack.last_mut() {
+                    // Collection is nested
+                    Some(parent) => parent.collections.push(collection),
+                    None => collections.borrow_mut().push(collection),
+                }
+            }
+
+            Ok(())
+        })?;
+
+        Ok(())
+    });
+
+    let collection_metadata_handler = element!("collection > metadata > *", |element| {
+        if let Some(collection) = collection_stack.borrow_mut().last_mut() {
+            let meta = Rc::new(RefCell::new(create_meta_entry(element)));
 
-        // Remove namespace
-        if let Some(index) = meta.name.find(':') {
-            meta.name.drain(..=index);
+            current_meta.borrow_mut().replace(Rc::clone(&meta));
+            collection.metadata.push(meta);
         }
 
-        let meta = Shared::new(RefCell::new(meta));
+        Ok(())
+    });
 
-        current_meta.borrow_mut().replace(Shared::clone(&meta));
-        meta_vec.push(meta);
+    // The value of collection links will be the value of its href attribute
+    let collection_link_handler = element!("collection > link", |element| {
+        if let (Some(collection), Some(href)) = (
+            collection_stack.borrow_mut().last_mut(),
+            element.get_attribute(xml::HREF),
+        ) {
+            collection.links.push(Element {
+                name: element.tag_name(),
+                value: settings.href(href),
+                attributes: settings.copy_attributes(element.attributes()),
+                ..Element::default()
+            });
+        }
 
         Ok(())
     });
@@ -617,8 +1881,8 @@ fn parse_package(data: &[u8]) -> EbookResult<(Metadata, Manifest, Spine, Guide)>
                 id.to_string(),
                 Element {
                     name: id,
-                    value: href,
-                    attributes: xmlutil::copy_attributes(element.attributes()),
+                    value: settings.href(href),
+                    attributes: settings.copy_attributes(element.attributes()),
                     ..Element::default()
                 },
             );
@@ -650,8 +1914,8 @@ fn parse_package(data: &[u8]) -> EbookResult<(Metadata, Manifest, Spine, Guide)>
         ) {
             guide_vec.push(Element {
                 name: title,
-                value: href,
-                attributes: xmlutil::copy_attributes(element.attributes()),
+                value: settings.href(href),
+                attributes: settings.copy_attributes(element.attributes()),
                 ..Element::default()
             });
         };
@@ -659,37 +1923,141 @@ fn parse_package(data: &[u8]) -> EbookResult<(Metadata, Manifest, Spine, Guide)>
         Ok(())
     });
 
-    parse_xhtml_data(
-        vec![
-            parent_element_handler,
-            metadata_entry_handler,
-            manifest_handler,
-            spine_handler,
-            guide_handler,
-        ],
-        vec![metadata_text_value_handler],
-        data,
-    )?;
+    // Unknown elements, such as vendor extensions, are retained
+    // with their attributes to avoid silently dropping them.
+    // Descendant selectors are used as self-closing tags are not
+    // recognized by the parser, nesting subsequent siblings instead.
+    let foreign_element = |element: &LolElement<'_, '_>| Element {
+        name: element.tag_name(),
+        attributes: xmlutil::copy_attributes(element.attributes()),
+        ..Element::default()
+    };
+    let section_handler = element!(constants::PACKAGE_SECTIONS.join(", "), |element| {
+        let depth = Rc::clone(&section_depth);
+
+        if element
+            .on_end_tag(move |_| {
+                depth.set(depth.get().saturating_sub(1));
+                Ok(())
+            })
+            .is_ok()
+        {
+            section_depth.set(section_depth.get() + 1);
+        }
+        Ok(())
+    });
+    let foreign_package_handler = element!("package *", |element| {
+        let tag_name = element.tag_name();
+
+        if section_depth.get() == 0 && !constants::PACKAGE_CHILDREN.contains(&tag_name.as_str()) {
+            foreign_package_vec.push(foreign_element(element));
+        }
+        Ok(())
+    });
+    let foreign_manifest_handler = element!("manifest *", |element| {
+        if element.tag_name() != constants::ITEM {
+            foreign_manifest_vec.push(foreign_element(element));
+        }
+        Ok(())
+    });
+    let foreign_spine_handler = element!("spine *", |element| {
+        if element.tag_name() != constants::ITEMREF {
+            foreign_spine_vec.push(foreign_element(element));
+        }
+        Ok(())
+    });
+
+    let mut element_handlers = vec![parent_element_handler, metadata_entry_handler];
+    let content_handlers = vec![
+        manifest_handler,
+        spine_handler,
+        guide_handler,
+        collection_handler,
+        collection_metadata_handler,
+        collection_link_handler,
+        section_handler,
+        foreign_package_handler,
+        foreign_manifest_handler,
+        foreign_spine_handler,
+    ];
+
+    match settings.parse_level {
+        // Only the package and metadata handlers are required
+        ParseLevel::MetadataOnly => drop(content_handlers),
+        _ => element_handlers.extend(content_handlers),
+    }
+
+    parse_xhtml_data(element_handlers, vec![metadata_text_value_handler], data)?;
 
     // Finalize package:
     // Check if the package contains the epub version
     let package_root = is_valid_package(package_root)?;
 
-    // Finalize spine:
-    let spine_root = is_valid_spine(spine_root, itemref_vec)?;
-
     // Finalize metadata:
     // Create parent references for each element
-    let meta_vec = to_rc_meta_vec(meta_vec);
+    let (meta_vec, refinements) = to_rc_meta_vec(meta_vec);
+    attach_refinements(refinements, &mut itemref_vec, &mut item_map);
+
+    // Finalize spine:
+    let (spine_vec, spine_attributes) = match settings.parse_level {
+        ParseLevel::MetadataOnly => (Vec::new(), Vec::new()),
+        _ => (
+            itemref_vec.into_iter().map(TempElement::convert).collect(),
+            is_valid_spine(spine_attributes)?,
+        ),
+    };
+
+    let mut manifest = Manifest::new(item_map);
+    manifest.set_foreign_elements(foreign_manifest_vec);
+    manifest.set_attributes(manifest_attributes);
 
     Ok((
         Metadata::new(package_root, meta_vec),
-        Manifest::new(item_map), // Add properties
-        Spine::new(spine_root),
+        manifest,
+        Spine::new(spine_vec, foreign_spine_vec, spine_attributes),
         Guide::new(guide_vec),
+        collections.take(),
+        foreign_package_vec,
     ))
 }
 
+fn create_meta_entry(element: &LolElement<'_, '_>) -> TempElement {
+    let mut meta = TempElement {
+        attributes: xmlutil::copy_attributes(element.attributes()),
+        ..TempElement::default()
+    };
+
+    // Change name to the value of the name or
+    // property attribute of a meta element
+    // Newer meta element condition
+    if let Some(property) = element.get_attribute(constants::PROPERTY) {
+        meta.name = property;
+    }
+    // Legacy OPF2.0 meta element condition
+    else if let (Some(name), Some(content)) = (
+        element.get_attribute(constants::NAME),
+        element.get_attribute(constants::CONTENT),
+    ) {
+        meta.name = name;
+        meta.value = content;
+        meta.attributes.push(Attribute::new(
+            constants::LEGACY_FEATURE.to_string(),
+            constants::LEGACY_META.to_string(),
+        ));
+    }
+    // Use tag name instead
+    else {
+        meta.name = element.tag_name();
+    }
+
+    // Remove namespace
+    if let Some(index) = meta.name.find(':') {
+        meta.name.drain(..=index);
+    }
+
+    meta
+}
+
 fn is_valid_package(package: Option<Element>) -> EbookResult<Element> {
     package
         .filter(|pkg| pkg.contains_attribute(constants::VERSION))
@@ -702,29 +2070,24 @@ fn is_valid_package(package: Option<Element>) -> EbookResult<Element> {
         })
 }
 
-fn is_valid_spine(
-    spine: Option<TempElement>,
-    children: Vec<TempElement>,
-) -> EbookResult<Shared<Element>> {
-    spine
-        .map(|mut spine| {
-            spine.children.replace(children);
-            spine.convert_to_shared(Weak::new())
-        })
-        .ok_or(EbookError::Parse {
-            cause: "Required element is missing".to_string(),
-            description: "Please ensure the 'spine' element exists in the .opf file".to_string(),
-        })
+fn is_valid_spine(spine_attributes: Option<Vec<Attribute>>) -> EbookResult<Vec<Attribute>> {
+    spine_attributes.ok_or(EbookError::Parse {
+        cause: "Required element is missing".to_string(),
+        description: "Please ensure the 'spine' element exists in the .opf file".to_string(),
+    })
 }
 
+type MetaVec = Vec<(String, Vec<Shared<Element>>)>;
+
 // Using vec here instead of hashmap as it better maintains the
 // order of metadata from the original file. Performance loss
 // is miniscule as there are generally very little elements.
-fn to_rc_meta_vec(
-    elements: Vec<Shared<RefCell<TempElement>>>,
-) -> Vec<(String, Vec<Shared<Element>>)> {
-    let mut new_vec: Vec<(String, Vec<Shared<Element>>)> = Vec::new();
+// Refinements of elements outside the metadata, such as spine
+// `itemref` elements, are returned separately.
+fn to_rc_meta_vec(elements: Vec<Rc<RefCell<TempElement>>>) -> (MetaVec, Vec<TempElement>) {
+    let mut new_vec: MetaVec = Vec::new();
     let mut parent_vec: Vec<TempElement> = Vec::new(); // temp vec to help with construction
+    let mut refinements = Vec::new(); // Refinements without a metadata parent
 
     // Loop to form parent-child relationship between metadata
     for element_cell in elements {
@@ -740,12 +2103,14 @@ fn to_rc_meta_vec(
                 .find(|parent| {
                     parent
                         .get_attribute(xml::ID)
-                        .map_or(false, |value| value == id)
+                        .is_some_and(|value| value == id)
                 })
                 .and_then(|parent| parent.children.as_mut())
             {
                 // Add child metadata entry to parent
                 children.push(element);
+            } else {
+                refinements.push(element);
             }
         } else {
             // If the element has an id attribute, it most likely has children
@@ -774,14 +2139,44 @@ fn to_rc_meta_vec(
         }
     }
 
-    new_vec
+    (new_vec, refinements)
+}
+
+// Add refinements to the spine `itemref` or manifest `item` they refine
+fn attach_refinements(
+    refinements: Vec<TempElement>,
+    itemrefs: &mut [TempElement],
+    items: &mut HashMap<String, Element>,
+) {
+    for refinement in refinements {
+        let Some(id) = refinement
+            .get_attribute(constants::REFINES)
+            .map(|refines| refines.replace('#', ""))
+        else {
+            continue;
+        };
+
+        if let Some(itemref) = itemrefs
+            .iter_mut()
+            .find(|itemref| itemref.get_attribute(xml::ID) == Some(&id))
+        {
+            itemref
+                .children
+                .get_or_insert_with(Vec::new)
+                .push(refinement);
+        } else if let Some(item) = items.get_mut(&id) {
+            item.children
+                .get_or_insert_with(Vec::new)
+                .push(refinement.convert_to_shared(Weak::new()));
+        }
+    }
 }
 
-fn parse_toc(mut data: &str) -> EbookResult<Toc> {
+fn parse_toc(mut data: &str, settings: &EpubSettings) -> EbookResult<Toc> {
     // Keep track of latest nav element entry
-    let parent_stack = Shared::new(RefCell::new(Vec::new()));
-    let current_nav_group = Shared::new(RefCell::new(Vec::new()));
-    let nav_groups = Shared::new(RefCell::new(HashMap::new()));
+    let parent_stack = Rc::new(RefCell::new(Vec::new()));
+    let current_nav_group = Rc::new(RefCell::new(Vec::new()));
+    let nav_groups = Rc::new(RefCell::new(HashMap::new()));
 
     // TODO: Temporary work around for a dependency bug at the moment
     // Bug: If the parser encounters a script element in the head,
@@ -797,9 +2192,9 @@ fn parse_toc(mut data: &str) -> EbookResult<Toc> {
         let toc_type = element.get_attribute(constants::TOC_TYPE);
         let attributes = xmlutil::copy_attributes(element.attributes());
 
-        let parent_stack = Shared::clone(&parent_stack);
-        let current_nav_group = Shared::clone(&current_nav_group);
-        let groups = Shared::clone(&nav_groups);
+        let parent_stack = Rc::clone(&parent_stack);
+        let current_nav_group = Rc::clone(&current_nav_group);
+        let groups = Rc::clone(&nav_groups);
         element.on_end_tag(move |_| {
             let nav_group_name = match toc_type {
                 Some(nav_type) => nav_type,
@@ -837,8 +2232,8 @@ fn parse_toc(mut data: &str) -> EbookResult<Toc> {
         });
 
         // Handle end tag event
-        let parent_stack = Shared::clone(&parent_stack);
-        let toc = Shared::clone(&current_nav_group);
+        let parent_stack = Rc::clone(&parent_stack);
+        let toc = Rc::clone(&current_nav_group);
         element.on_end_tag(move |_| {
             let mut stack = parent_stack.borrow_mut();
 
@@ -863,7 +2258,7 @@ fn parse_toc(mut data: &str) -> EbookResult<Toc> {
     let nav_content_handler = element!("a, span, content", |element| {
         // Get attributes and obtain href/src
         if let Some(nav_entry) = parent_stack.borrow_mut().last_mut() {
-            for attribute in xmlutil::copy_attributes(element.attributes()) {
+            for attribute in settings.copy_attributes(element.attributes()) {
                 if attribute.name() == xml::HREF || attribute.name() == xml::SRC {
                     nav_entry.value = attribute.value().to_string()
                 }
@@ -907,6 +2302,52 @@ fn parse_toc(mut data: &str) -> EbookResult<Toc> {
     Ok(Toc::new(nav_groups))
 }
 
+// Retrieve and parse the toc if required by the parse level
+fn load_toc(
+    archive: &dyn Archive,
+    root_file_dir: &Path,
+    manifest: &Manifest,
+    settings: &EpubSettings,
+) -> EbookResult<Toc> {
+    if settings.parse_level != ParseLevel::Full {
+        return Ok(Toc::new(HashMap::new()));
+    }
+
+    // Get toc.xhtml/ncx href value
+    let toc_href = get_toc_href(manifest)?;
+
+    // Parse "toc.xhtml/ncx"
+    let toc_path = root_file_dir.join(toc_href);
+    let content_toc = read_archive(archive, settings, &toc_path, |archive, path| {
+        archive.read_file(path)
+    })?;
+    parse_toc(&content_toc, settings).map_err(|error| error.in_file(toc_path))
+}
+
+// Retrieve the contents or information of a file from the archive. If enabled,
+// a case-insensitive match is used when the file cannot be found.
+fn read_archive<'a, T, F>(
+    archive: &'a dyn Archive,
+    settings: &EpubSettings,
+    path: &Path,
+    read: F,
+) -> EbookResult<T>
+where
+    F: Fn(&'a dyn Archive, &Path) -> Result<T, ArchiveError>,
+{
+    match read(archive, path) {
+        Err(ArchiveError::InvalidPath { .. }) if settings.href_case_insensitive => archive
+            .find_ignore_case(path)
+            .ok_or_else(|| ArchiveError::InvalidPath {
+                cause: "Unable to find file ignoring case".to_string(),
+                description: format!("Please ensure the file exists: '{:?}'", path.display()),
+            })
+            .and_then(|resolved| read(archive, &resolved)),
+        result => result,
+    }
+    .map_err(EbookError::Archive)
+}
+
 fn is_valid_toc(toc: &HashMap<String, TempElement>) -> EbookResult<()> {
     if toc.contains_key(constants::TOC) {
         Ok(())
@@ -927,10 +2368,27 @@ fn to_rc_nav_groups(map: HashMap<String, TempElement>) -> HashMap<String, Shared
 }
 
 // Helper functions
-fn parse_xhtml_data(
+pub(crate) fn parse_xhtml_data(
+    element_content_handlers: Vec<(Cow<Selector>, ElementContentHandlers)>,
+    document_content_handlers: Vec<DocumentContentHandlers>,
+    data: &[u8],
+) -> EbookResult<()> {
+    parse_xhtml_data_with_output(
+        element_content_handlers,
+        document_content_handlers,
+        data,
+        |_: &[u8]| (),
+    )
+}
+
+// As the data is not modified, the output matches the data, which
+// allows handlers to retrieve their position from the output written
+// so far.
+pub(crate) fn parse_xhtml_data_with_output(
     element_content_handlers: Vec<(Cow<Selector>, ElementContentHandlers)>,
     document_content_handlers: Vec<DocumentContentHandlers>,
     data: &[u8],
+    output: impl FnMut(&[u8]),
 ) -> EbookResult<()> {
     let mut reader = HtmlRewriter::new(
         Settings {
@@ -938,7 +2396,7 @@ fn parse_xhtml_data(
             document_content_handlers,
             ..Settings::default()
         },
-        |_: &[u8]| (),
+        output,
     );
 
     // Convert data to utf-8 if necessary and start parsing
@@ -950,6 +2408,117 @@ fn parse_xhtml_data(
         })
 }
 
+// Resolve an href relative to the location of the file that references
+// it. Both the location and the result are relative to the root file
+// directory, i.e., `dict/skm.xml` + `entries.xhtml` -> `dict/entries.xhtml`
+fn resolve_href(location: &str, href: &str) -> String {
+    let joined = utility::get_parent_path(&location).join(href);
+
+    utility::normalize_path(&joined)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+// Retrieve the hrefs of resources referenced by a markup document, such
+// as images and stylesheets, resolved relative to the root file directory.
+// External references are excluded and fragments are removed.
+#[cfg(feature = "reader")]
+fn extract_references(data: &[u8], location: &str) -> EbookResult<Vec<String>> {
+    Ok(resolve_references(
+        extract_reference_values(data)?,
+        location,
+    ))
+}
+
+// Resolve referenced hrefs relative to the root file directory,
+// excluding external references and removing fragments
+fn resolve_references(values: Vec<String>, location: &str) -> Vec<String> {
+    let mut references = Vec::new();
+
+    for href in values {
+        // External references contain a scheme, i.e., `https:`
+        let is_external = href
+            .split('/')
+            .next()
+            .is_some_and(|segment| segment.contains(':'));
+        let href = href.split(['#', '?']).next().unwrap_or_default();
+
+        if !is_external && !href.is_empty() {
+            let href = resolve_href(location, href);
+
+            if !references.contains(&href) {
+                references.push(href);
+            }
+        }
+    }
+
+    references
+}
+
+// Retrieve the remote urls of resources referenced by a markup document
+fn extract_remote_references(data: &[u8]) -> EbookResult<Vec<String>> {
+    let mut references = Vec::new();
+
+    for href in extract_reference_values(data)? {
+        let lowercase = href.to_lowercase();
+        let is_remote = lowercase.starts_with("http://")
+            || lowercase.starts_with("https://")
+            || href.starts_with("//");
+
+        if is_remote && !references.contains(&href) {
+            references.push(href);
+        }
+    }
+
+    Ok(references)
+}
+
+// Retrieve the values of attributes that reference resources,
+// such as `src`, in the order they appear in a markup document
+fn extract_reference_values(data: &[u8]) -> EbookResult<Vec<String>> {
+    let values = RefCell::new(Vec::new());
+
+    let reference_handler = element!(
+        "[src], link[href], image, object[data], video[poster]",
+        |element| {
+            let mut values = values.borrow_mut();
+
+            for name in constants::REFERENCE_ATTRIBUTES {
+                if let Some(value) = element.get_attribute(name) {
+                    values.push(value.trim().to_string());
+                }
+            }
+
+            Ok(())
+        }
+    );
+
+    parse_xhtml_data(vec![reference_handler], vec![], data)?;
+
+    Ok(values.take())
+}
+
+// Retrieve the values of hyperlinks, such as `<a href>`, in the
+// order they appear in a markup document
+fn extract_hyperlink_values(data: &[u8]) -> EbookResult<Vec<String>> {
+    let values = RefCell::new(Vec::new());
+
+    let hyperlink_handler = element!("a, area", |element| {
+        let href = element
+            .get_attribute("href")
+            .or_else(|| element.get_attribute("xlink:href"));
+
+        if let Some(href) = href {
+            values.borrow_mut().push(href.trim().to_string());
+        }
+        Ok(())
+    });
+
+    parse_xhtml_data(vec![hyperlink_handler], vec![], data)?;
+
+    Ok(values.take())
+}
+
 fn get_toc_href(manifest: &Manifest) -> EbookResult<&str> {
     // Attempt to retrieve newer toc format first
     manifest
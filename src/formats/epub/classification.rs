@@ -0,0 +1,276 @@
+use crate::formats::epub::constants;
+use crate::formats::xml::Element;
+
+/// Subject classification of an ebook using a well-known authority,
+/// such as BISAC, Thema, or BIC.
+///
+/// Codes are decoded into human-readable categories using embedded
+/// lookup tables. The tables cover the top-level categories of each
+/// authority along with common fiction categories. Categories that are
+/// not covered end the decoded path early, while the raw code is
+/// always retained.
+///
+/// # Examples
+/// Decoding the subjects of an ebook:
+/// ```
+/// use rbook::Ebook;
+/// use rbook::epub::ClassificationScheme;
+///
+/// let epub = rbook::Epub::new("tests/ebooks/subjects_epub").unwrap();
+/// let classifications = epub.metadata().classifications();
+/// let bisac = classifications.first().unwrap();
+///
+/// assert_eq!(ClassificationScheme::Bisac, bisac.scheme());
+/// assert_eq!("FIC000000", bisac.code());
+/// assert_eq!("Fiction / General", bisac.path());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Classification {
+    scheme: ClassificationScheme,
+    code: String,
+    categories: Vec<&'static str>,
+}
+
+impl Classification {
+    /// Create a classification from a `subject` metadata element.
+    ///
+    /// The authority is retrieved from a refining `authority` element,
+    /// or from an `authority` or `scheme` attribute. The code is
+    /// retrieved from a refining `term` element, or the value of the
+    /// subject itself. Returns [None] if the authority is not recognized.
+    pub fn from_subject(subject: &Element) -> Option<Self> {
+        let authority = subject
+            .get_child(constants::AUTHORITY)
+            .map(Element::value)
+            .or_else(|| subject.get_attribute(constants::AUTHORITY))
+            .or_else(|| subject.get_attribute(constants::SCHEME))?;
+        let code = subject
+            .get_child(constants::TERM)
+            .map(Element::value)
+            .unwrap_or_else(|| subject.value());
+
+        Self::new(authority, code)
+    }
+
+    /// Create a classification from an authority, such as `BISAC`,
+    /// and a code. Returns [None] if the authority is not recognized.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use rbook::epub::Classification;
+    ///
+    /// let thema = Classification::new("thema", "FBA").unwrap();
+    ///
+    /// assert_eq!(
+    ///     vec!["Fiction and Related items", "Fiction: general and literary", "Modern and contemporary fiction"],
+    ///     thema.categories()
+    /// );
+    /// ```
+    pub fn new(authority: &str, code: &str) -> Option<Self> {
+        let scheme = ClassificationScheme::from_authority(authority)?;
+        let code = code.trim().to_uppercase();
+        let categories = match scheme {
+            ClassificationScheme::Bisac => decode_bisac(&code),
+            ClassificationScheme::Thema => decode_prefixes(&code, THEMA),
+            ClassificationScheme::Bic => decode_prefixes(&code, BIC),
+        };
+
+        Some(Self {
+            scheme,
+            code,
+            categories,
+        })
+    }
+
+    /// Retrieve the authority of the classification.
+    pub fn scheme(&self) -> ClassificationScheme {
+        self.scheme
+    }
+
+    /// Retrieve the raw code, such as `FIC000000`.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// Retrieve the decoded categories, from the broadest to the most
+    /// specific. Empty if the code is not covered by the lookup tables.
+    pub fn categories(&self) -> Vec<&str> {
+        self.categories.clone()
+    }
+
+    /// Retrieve the decoded categories separated by ` / `.
+    pub fn path(&self) -> String {
+        self.categories.join(" / ")
+    }
+}
+
+/// Supported subject classification authorities.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClassificationScheme {
+    /// Book Industry Standards and Communications subject headings.
+    Bisac,
+    /// Thema subject categories.
+    Thema,
+    /// Book Industry Communication standard subject categories.
+    Bic,
+}
+
+impl ClassificationScheme {
+    fn from_authority(authority: &str) -> Option<Self> {
+        match authority.trim().to_lowercase().as_str() {
+            "bisac" => Some(Self::Bisac),
+            "thema" => Some(Self::Thema),
+            "bic" => Some(Self::Bic),
+            _ => None,
+        }
+    }
+}
+
+// BISAC codes consist of a three letter heading and six digits,
+// where all zeros indicate the general category of the heading.
+fn decode_bisac(code: &str) -> Vec<&'static str> {
+    let Some(heading) = code
+        .get(..3)
+        .and_then(|prefix| BISAC.iter().find(|(key, _)| *key == prefix))
+    else {
+        return Vec::new();
+    };
+
+    match &code[3..] {
+        "000000" => vec![heading.1, "General"],
+        _ => vec![heading.1],
+    }
+}
+
+// Thema and BIC codes are hierarchical, where each additional
+// character narrows the category of the preceding characters.
+fn decode_prefixes(code: &str, table: &[(&str, &'static str)]) -> Vec<&'static str> {
+    (1..=code.len())
+        .map_while(|end| {
+            let prefix = code.get(..end)?;
+            table
+                .iter()
+                .find(|(key, _)| *key == prefix)
+                .map(|(_, name)| *name)
+        })
+        .collect()
+}
+
+const BISAC: &[(&str, &str)] = &[
+    ("ANT", "Antiques & Collectibles"),
+    ("ARC", "Architecture"),
+    ("ART", "Art"),
+    ("BIB", "Bibles"),
+    ("BIO", "Biography & Autobiography"),
+    ("BOD", "Body, Mind & Spirit"),
+    ("BUS", "Business & Economics"),
+    ("CGN", "Comics & Graphic Novels"),
+    ("CKB", "Cooking"),
+    ("COM", "Computers"),
+    ("CRA", "Crafts & Hobbies"),
+    ("DES", "Design"),
+    ("DRA", "Drama"),
+    ("EDU", "Education"),
+    ("FAM", "Family & Relationships"),
+    ("FIC", "Fiction"),
+    ("FOR", "Foreign Language Study"),
+    ("GAM", "Games & Activities"),
+    ("GAR", "Gardening"),
+    ("HEA", "Health & Fitness"),
+    ("HIS", "History"),
+    ("HOM", "House & Home"),
+    ("HUM", "Humor"),
+    ("JNF", "Juvenile Nonfiction"),
+    ("JUV", "Juvenile Fiction"),
+    ("LAN", "Language Arts & Disciplines"),
+    ("LAW", "Law"),
+    ("LCO", "Literary Collections"),
+    ("LIT", "Literary Criticism"),
+    ("MAT", "Mathematics"),
+    ("MED", "Medical"),
+    ("MUS", "Music"),
+    ("NAT", "Nature"),
+    ("NON", "Non-Classifiable"),
+    ("PER", "Performing Arts"),
+    ("PET", "Pets"),
+    ("PHI", "Philosophy"),
+    ("PHO", "Photography"),
+    ("POE", "Poetry"),
+    ("POL", "Political Science"),
+    ("PSY", "Psychology"),
+    ("REF", "Reference"),
+    ("REL", "Religion"),
+    ("SCI", "Science"),
+    ("SEL", "Self-Help"),
+    ("SOC", "Social Science"),
+    ("SPO", "Sports & Recreation"),
+    ("STU", "Study Aids"),
+    ("TEC", "Technology & Engineering"),
+    ("TRA", "Transportation"),
+    ("TRU", "True Crime"),
+    ("TRV", "Travel"),
+    ("YAF", "Young Adult Fiction"),
+    ("YAN", "Young Adult Nonfiction"),
+];
+
+const THEMA: &[(&str, &str)] = &[
+    ("A", "The Arts"),
+    ("C", "Language and Linguistics"),
+    ("D", "Biography, Literature and Literary studies"),
+    ("F", "Fiction and Related items"),
+    ("FB", "Fiction: general and literary"),
+    ("FBA", "Modern and contemporary fiction"),
+    ("FF", "Crime and mystery fiction"),
+    ("FL", "Science fiction"),
+    ("FM", "Fantasy"),
+    ("FR", "Romance"),
+    ("FV", "Historical fiction"),
+    ("G", "Reference, Information and Interdisciplinary subjects"),
+    ("J", "Society and Social Sciences"),
+    ("K", "Economics, Finance, Business and Management"),
+    ("L", "Law"),
+    ("M", "Medicine and Nursing"),
+    ("N", "History and Archaeology"),
+    ("P", "Mathematics and Science"),
+    ("Q", "Philosophy and Religion"),
+    ("R", "Earth Sciences, Geography, Environment, Planning"),
+    ("S", "Sports and Active outdoor recreation"),
+    (
+        "T",
+        "Technology, Engineering, Agriculture, Industrial processes",
+    ),
+    ("U", "Computing and Information Technology"),
+    ("V", "Health, Relationships and Personal development"),
+    ("W", "Lifestyle, Hobbies and Leisure"),
+    ("X", "Graphic novels, Comic books, Cartoons"),
+    ("Y", "Children's, Teenage and Educational"),
+];
+
+const BIC: &[(&str, &str)] = &[
+    ("A", "The arts"),
+    ("B", "Biography & true stories"),
+    ("C", "Language"),
+    ("D", "Literature & literary studies"),
+    ("E", "English language teaching (ELT)"),
+    ("F", "Fiction & related items"),
+    ("FA", "Modern & contemporary fiction (post c 1945)"),
+    ("FC", "Classic fiction (pre c 1945)"),
+    ("FF", "Crime & mystery"),
+    ("FL", "Science fiction"),
+    ("FM", "Fantasy"),
+    ("FR", "Romance"),
+    ("G", "Reference, information & interdisciplinary subjects"),
+    ("H", "Humanities"),
+    ("J", "Society & social sciences"),
+    ("K", "Economics, finance, business & management"),
+    ("L", "Law"),
+    ("M", "Medicine"),
+    ("P", "Mathematics & science"),
+    ("R", "Earth sciences, geography, environment, planning"),
+    ("T", "Technology, engineering, agriculture"),
+    ("U", "Computing & information technology"),
+    ("V", "Health & personal development"),
+    ("W", "Lifestyle, sport & leisure"),
+    ("Y", "Children's, teenage & educational"),
+];
@@ -0,0 +1,110 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::formats::epub::constants;
+use crate::formats::xml::{utility, Attribute, Element, TempElement};
+
+/// A `collection` element of the package that groups related
+/// resources, such as a preview or an index.
+///
+/// For convenience the value of the `href` attribute of `link`
+/// elements is the `value` field of the element. Metadata entries
+/// of a collection follow the same conventions as [Metadata](super::Metadata).
+///
+/// # Examples
+/// Accessing collections:
+/// ```
+/// use rbook::Ebook;
+///
+/// let epub = rbook::Epub::new("tests/ebooks/collection_epub").unwrap();
+///
+/// let collection = epub.collections_by_role("preview").into_iter().next().unwrap();
+/// let title = collection.metadata().into_iter().next().unwrap();
+/// assert_eq!("Collection Example Preview", title.value());
+///
+/// let link = collection.links().into_iter().next().unwrap();
+/// assert_eq!("c1.xhtml", link.value());
+///
+/// // Collections may be nested
+/// let nested = collection.collections().first().unwrap();
+/// assert_eq!("manifest", nested.role());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Collection {
+    attributes: Vec<Attribute>,
+    metadata: Vec<Element>,
+    links: Vec<Element>,
+    collections: Vec<Collection>,
+}
+
+impl Collection {
+    /// Retrieve the role of the collection, such as `preview`.
+    pub fn role(&self) -> &str {
+        self.get_attribute(constants::ROLE).unwrap_or_default()
+    }
+
+    /// Retrieve all attributes of the collection element
+    pub fn attributes(&self) -> &[Attribute] {
+        &self.attributes
+    }
+
+    /// Retrieve the value from a certain attribute of the collection element
+    pub fn get_attribute(&self, name: &str) -> Option<&str> {
+        utility::get_attribute(&self.attributes, name)
+    }
+
+    /// Check if an attribute of the collection element exists
+    pub fn contains_attribute(&self, name: &str) -> bool {
+        utility::contains_attribute(&self.attributes, name)
+    }
+
+    /// Retrieve all metadata elements of the collection
+    pub fn metadata(&self) -> Vec<&Element> {
+        self.metadata.iter().collect()
+    }
+
+    /// Retrieve all `link` elements of the collection
+    pub fn links(&self) -> Vec<&Element> {
+        self.links.iter().collect()
+    }
+
+    /// Retrieve all nested collections
+    pub fn collections(&self) -> &[Collection] {
+        &self.collections
+    }
+}
+
+// Temporary container for collections during construction
+#[derive(Default)]
+pub(super) struct TempCollection {
+    pub(super) attributes: Vec<Attribute>,
+    pub(super) metadata: Vec<Rc<RefCell<TempElement>>>,
+    pub(super) links: Vec<Element>,
+    pub(super) collections: Vec<Collection>,
+}
+
+impl TempCollection {
+    pub(super) fn convert(self) -> Collection {
+        let metadata = self
+            .metadata
+            .into_iter()
+            .map(|meta| {
+                let meta = meta.take();
+
+                Element {
+                    name: meta.name,
+                    value: meta.value,
+                    attributes: meta.attributes,
+                    ..Element::default()
+                }
+            })
+            .collect();
+
+        Collection {
+            attributes: self.attributes,
+            metadata,
+            links: self.links,
+            collections: self.collections,
+        }
+    }
+}
@@ -1,10 +1,45 @@
 // Paths
 pub(crate) const META_INF: &str = "META-INF"; // Used to identify the root `.opf` file
 pub(crate) const CONTAINER: &str = "META-INF/container.xml"; // Used to identify container
+pub(crate) const ENCRYPTION: &str = "META-INF/encryption.xml"; // Lists encrypted resources
+pub(crate) const APPLE_DISPLAY_OPTIONS: &str = "META-INF/com.apple.ibooks.display-options.xml";
 
 // Elements
 pub(crate) const PACKAGE: &str = "package";
 pub(crate) const SPINE: &str = "spine";
+pub(crate) const ITEM: &str = "item";
+pub(crate) const ITEMREF: &str = "itemref";
+// Attributes of the package, manifest, and spine elements modeled by rbook
+pub(crate) const PACKAGE_ATTRIBUTES: [&str; 6] = [
+    "version",
+    "unique-identifier",
+    "prefix",
+    "xml:lang",
+    "dir",
+    "id",
+];
+pub(crate) const MANIFEST_ATTRIBUTES: [&str; 1] = ["id"];
+pub(crate) const SPINE_ATTRIBUTES: [&str; 3] = ["id", "toc", "page-progression-direction"];
+pub(crate) const MANIFEST: &str = "manifest";
+
+// Known children of the package element
+pub(crate) const PACKAGE_CHILDREN: [&str; 7] = [
+    "metadata",
+    "manifest",
+    "spine",
+    "guide",
+    "bindings",
+    "collection",
+    "tours",
+];
+// Known children of the package element, excluding collections
+pub(crate) const PACKAGE_SECTIONS: [&str; 6] = [
+    "metadata", "manifest", "spine", "guide", "bindings", "tours",
+];
+
+// Spine attributes
+pub(crate) const PAGE_PROGRESSION_DIRECTION: &str = "page-progression-direction";
+pub(crate) const RTL: &str = "rtl";
 
 // Metadata elements
 pub(crate) const TITLE: &str = "title"; // Also used for Guide title attribute
@@ -19,6 +54,30 @@ pub(crate) const PUBLISHER: &str = "publisher";
 pub(crate) const SUBJECT: &str = "subject";
 pub(crate) const TYPE: &str = "type"; // Also used for Guide type attribute
 pub(crate) const COVER: &str = "cover";
+pub(crate) const DICTIONARY: &str = "dictionary"; // Value of the `type` element
+pub(crate) const RATING: &str = "rating";
+pub(crate) const AUDIENCE: &str = "audience";
+pub(crate) const TITLE_TYPE: &str = "title-type"; // Refines title metadata
+pub(crate) const DISPLAY_SEQ: &str = "display-seq"; // Refines metadata
+pub(crate) const FILE_AS: &str = "file-as"; // Refines metadata
+pub(crate) const ALTERNATE_SCRIPT: &str = "alternate-script"; // Refines metadata
+pub(crate) const MEDIA_PREFIX: &str = "media"; // Media overlay vocabulary
+pub(crate) const DURATION: &str = "duration"; // media:duration
+pub(crate) const NARRATOR: &str = "narrator"; // media:narrator
+pub(crate) const ACTIVE_CLASS: &str = "active-class"; // media:active-class
+pub(crate) const PLAYBACK_ACTIVE_CLASS: &str = "playback-active-class"; // media:playback-active-class
+#[cfg(feature = "classification")]
+pub(crate) const AUTHORITY: &str = "authority"; // Refines subject metadata
+#[cfg(feature = "classification")]
+pub(crate) const TERM: &str = "term"; // Refines subject metadata
+#[cfg(any(feature = "classification", feature = "relators"))]
+pub(crate) const SCHEME: &str = "scheme";
+
+// Common attributes
+pub(crate) const LANG: &str = "lang"; // xml:lang
+pub(crate) const XML_LANG: &str = "xml:lang";
+pub(crate) const DIR: &str = "dir";
+pub(crate) const VALUE: &str = "value";
 
 // Container attributes
 pub(crate) const FULL_PATH: &str = "full-path";
@@ -26,15 +85,22 @@ pub(crate) const FULL_PATH: &str = "full-path";
 // Package attributes
 pub(crate) const VERSION: &str = "version";
 pub(crate) const UNIQUE_ID: &str = "unique-identifier";
+pub(crate) const PREFIX: &str = "prefix";
 
 // Metadata attributes
 pub(crate) const PROPERTY: &str = "property";
+pub(crate) const MEDIA_OVERLAY: &str = "media-overlay"; // Manifest attribute
 pub(crate) const NAME: &str = "name";
 pub(crate) const CONTENT: &str = "content";
 pub(crate) const REFINES: &str = "refines";
 
+// Collection attributes
+pub(crate) const ROLE: &str = "role";
+
 // Spine attributes
 pub(crate) const IDREF: &str = "idref";
+pub(crate) const LINEAR: &str = "linear";
+pub(crate) const NO: &str = "no"; // Value of the `linear` attribute
 
 // Toc attributes
 pub(crate) const TOC_TYPE: &str = "epub:type";
@@ -43,17 +109,71 @@ pub(crate) const LANDMARKS: &str = "landmarks";
 pub(crate) const PAGE_LIST2: &str = "pageList"; // epub2
 pub(crate) const PAGE_LIST3: &str = "page-list"; // epub3
 pub(crate) const PLAY_ORDER: &str = "playOrder"; // epub2 only
+pub(crate) const HIDDEN: &str = "hidden";
 
 // Properties
 pub(crate) const PROPERTIES: &str = "properties";
+pub(crate) const FALLBACK: &str = "fallback";
 pub(crate) const COVER_PROPERTY: &str = "cover-image";
 pub(crate) const NAV_PROPERTY: &str = "nav";
+pub(crate) const SEARCH_KEY_MAP_PROPERTY: &str = "search-key-map";
+pub(crate) const REMOTE_RESOURCES_PROPERTY: &str = "remote-resources";
+pub(crate) const SCRIPTED_PROPERTY: &str = "scripted";
+pub(crate) const PAGE_SPREAD_LEFT: &str = "page-spread-left";
+pub(crate) const PAGE_SPREAD_RIGHT: &str = "page-spread-right";
+pub(crate) const PAGE_SPREAD_CENTER: &str = "page-spread-center";
+pub(crate) const RENDITION_PREFIX: &str = "rendition:";
+#[cfg(feature = "reader")]
+pub(crate) const LAYOUT: &str = "layout"; // `rendition:layout` metadata
+#[cfg(feature = "reader")]
+pub(crate) const PRE_PAGINATED: &str = "pre-paginated"; // Value of `rendition:layout`
+#[cfg(feature = "reader")]
+pub(crate) const PRE_PAGINATED_PROPERTY: &str = "rendition:layout-pre-paginated";
+#[cfg(feature = "reader")]
+pub(crate) const REFLOWABLE_PROPERTY: &str = "rendition:layout-reflowable";
+
+// Vocabularies of properties without a prefix
+pub(crate) const DC_VOCABULARY: &str = "http://purl.org/dc/elements/1.1/"; // `dc:*` elements
+pub(crate) const DC_PREFIX: &str = "dc";
+pub(crate) const META_VOCABULARY: &str = "http://idpf.org/epub/vocab/package/meta/#"; // `meta` properties
+
+// Reserved prefixes that do not need to be declared
+// using the package `prefix` attribute
+pub(crate) const RESERVED_PREFIXES: [(&str, &str); 10] = [
+    ("a11y", "http://www.idpf.org/epub/vocab/package/a11y/#"),
+    ("dcterms", "http://purl.org/dc/terms/"),
+    ("marc", "http://id.loc.gov/vocabulary/"),
+    ("media", "http://www.idpf.org/epub/vocab/overlays/#"),
+    ("msv", "http://www.idpf.org/epub/vocab/structure/magazine/#"),
+    (
+        "onix",
+        "http://www.editeur.org/ONIX/book/codelists/current.html#",
+    ),
+    (
+        "prism",
+        "http://www.prismstandard.org/specifications/3.0/PRISM_CV_Spec_3.0.htm#",
+    ),
+    ("rendition", "http://www.idpf.org/vocab/rendition/#"),
+    ("schema", "http://schema.org/"),
+    ("xsd", "http://www.w3.org/2001/XMLSchema#"),
+];
+
+// Attributes of markup elements that reference resources
+pub(crate) const REFERENCE_ATTRIBUTES: [&str; 5] = ["src", "href", "xlink:href", "data", "poster"];
 
 // Media types
 pub(crate) const MEDIA_TYPE: &str = "media-type";
+pub(crate) const XHTML_TYPE: &str = "application/xhtml+xml";
+pub(crate) const CSS_TYPE: &str = "text/css";
+pub(crate) const MARKUP_TYPES: [&str; 3] = ["application/xhtml+xml", "text/html", "image/svg+xml"];
 pub(crate) const PACKAGE_TYPE: &str = "application/oebps-package+xml";
 pub(crate) const NCX_TYPE: &str = "application/x-dtbncx+xml";
 
+// Vendors
+pub(crate) const CALIBRE: &str = "calibre";
+pub(crate) const IBOOKS: &str = "ibooks";
+pub(crate) const ALL_PLATFORMS: &str = "*"; // Apple display options platform
+
 // rbook specific
 // Used to indicate and differentiate between non-legacy and legacy
 // features if not possible otherwise.
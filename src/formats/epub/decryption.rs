@@ -0,0 +1,92 @@
+use lol_html::element;
+use std::cell::RefCell;
+use std::fmt::{Debug, Formatter};
+use std::path::PathBuf;
+
+use crate::archive::ArchiveError;
+use crate::formats::epub::parse_xhtml_data;
+use crate::formats::EbookResult;
+use crate::utility::{self, Shared};
+
+/// Decrypts the resources of an epub that are listed as encrypted in
+/// `META-INF/encryption.xml`, such as for a DRM scheme the integrator
+/// is licensed to handle. Registered using
+/// [EpubSettings::decryptor](super::EpubSettings::decryptor).
+///
+/// Only resources listed in `encryption.xml` are provided to the
+/// decryptor. All other resources are read as is.
+#[cfg(feature = "multi-thread")]
+pub trait ContentDecryptor: Send + Sync {
+    /// Decrypt the data of a resource, where `path` is the location of
+    /// the resource within the archive, such as `OEBPS/chapter_001.xhtml`,
+    /// and `algorithm` is the `Algorithm` of its `EncryptionMethod`.
+    fn decrypt(&self, path: &str, algorithm: &str, data: Vec<u8>) -> Result<Vec<u8>, ArchiveError>;
+}
+/// Decrypts the resources of an epub that are listed as encrypted in
+/// `META-INF/encryption.xml`, such as for a DRM scheme the integrator
+/// is licensed to handle. Registered using
+/// [EpubSettings::decryptor](super::EpubSettings::decryptor).
+///
+/// Only resources listed in `encryption.xml` are provided to the
+/// decryptor. All other resources are read as is.
+#[cfg(not(feature = "multi-thread"))]
+pub trait ContentDecryptor {
+    /// Decrypt the data of a resource, where `path` is the location of
+    /// the resource within the archive, such as `OEBPS/chapter_001.xhtml`,
+    /// and `algorithm` is the `Algorithm` of its `EncryptionMethod`.
+    fn decrypt(&self, path: &str, algorithm: &str, data: Vec<u8>) -> Result<Vec<u8>, ArchiveError>;
+}
+
+// Registered decryptor, which is opaque when debugging settings
+#[derive(Clone)]
+pub(super) struct Decryptor(pub(super) Shared<dyn ContentDecryptor>);
+
+impl Debug for Decryptor {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt.write_str("ContentDecryptor")
+    }
+}
+
+// Retrieve the paths of the encrypted resources listed in
+// `encryption.xml` along with their encryption algorithm
+pub(super) fn parse_encryption(data: &[u8]) -> EbookResult<Vec<(PathBuf, String)>> {
+    let algorithm = RefCell::new(None);
+    let mut encrypted = Vec::new();
+
+    // Descendant selectors are used as self-closing tags are not
+    // recognized by the parser, nesting subsequent siblings instead
+    let encrypted_data_handler = element!("EncryptedData", |_| {
+        algorithm.borrow_mut().take();
+        Ok(())
+    });
+    let encryption_method_handler = element!("EncryptedData EncryptionMethod", |element| {
+        let mut algorithm = algorithm.borrow_mut();
+
+        // Nested methods, such as those of keys, are ignored
+        if algorithm.is_none() {
+            *algorithm = element.get_attribute("Algorithm");
+        }
+        Ok(())
+    });
+    let cipher_reference_handler = element!("CipherReference", |element| {
+        if let (Some(uri), Some(algorithm)) =
+            (element.get_attribute("URI"), algorithm.borrow().clone())
+        {
+            let path = utility::normalize_path(&uri.trim_start_matches('/')).into_owned();
+            encrypted.push((path, algorithm));
+        }
+        Ok(())
+    });
+
+    parse_xhtml_data(
+        vec![
+            encrypted_data_handler,
+            encryption_method_handler,
+            cipher_reference_handler,
+        ],
+        vec![],
+        data,
+    )?;
+
+    Ok(encrypted)
+}
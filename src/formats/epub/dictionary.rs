@@ -0,0 +1,174 @@
+use lol_html::element;
+use std::cell::RefCell;
+
+use crate::formats::epub::{self, constants};
+use crate::formats::xml::{self, utility as xmlutil};
+use crate::formats::EbookResult;
+
+/// Search key map of an epub dictionary that associates search
+/// terms, along with their inflected forms, with dictionary entries.
+///
+/// Search key maps are provided by manifest items that have the
+/// `search-key-map` property. The hrefs of each
+/// [SearchKeyGroup] are resolved relative to the root file
+/// directory, meaning they can be passed to methods such as
+/// [read_file(...)](super::Epub::read_file) directly after removing
+/// the fragment.
+///
+/// # Examples
+/// Looking up a search term:
+/// ```
+/// use rbook::Ebook;
+///
+/// let epub = rbook::Epub::new("tests/ebooks/dictionary_epub").unwrap();
+/// assert!(epub.metadata().is_dictionary());
+///
+/// let search_key_maps = epub.search_key_maps().unwrap();
+/// let search_key_map = search_key_maps.first().unwrap();
+///
+/// // Inflected forms are also matched
+/// let hrefs = search_key_map.lookup("Reading");
+/// assert_eq!(vec!["dict/entries.xhtml#read"], hrefs);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SearchKeyMap {
+    language: Option<String>,
+    groups: Vec<SearchKeyGroup>,
+}
+
+impl SearchKeyMap {
+    /// Retrieve the language of the search keys, if specified.
+    pub fn language(&self) -> Option<&str> {
+        self.language.as_deref()
+    }
+
+    /// Retrieve all `search-key-group` elements.
+    pub fn groups(&self) -> &[SearchKeyGroup] {
+        &self.groups
+    }
+
+    /// Retrieve the hrefs of all dictionary entries that match the
+    /// given search term. The comparison is case-insensitive and
+    /// includes inflected forms of each search key.
+    pub fn lookup(&self, term: &str) -> Vec<&str> {
+        let term = term.trim().to_lowercase();
+
+        self.groups
+            .iter()
+            .filter(|group| group.matches.iter().any(|key| key.matches(&term)))
+            .map(|group| group.href())
+            .collect()
+    }
+}
+
+/// Group of search keys that lead to the same dictionary entry.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SearchKeyGroup {
+    href: String,
+    matches: Vec<SearchKeyMatch>,
+}
+
+impl SearchKeyGroup {
+    /// Retrieve the href of the associated dictionary entry.
+    pub fn href(&self) -> &str {
+        &self.href
+    }
+
+    /// Retrieve all `match` elements of the group.
+    pub fn matches(&self) -> &[SearchKeyMatch] {
+        &self.matches
+    }
+}
+
+/// A search key along with its inflected forms.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SearchKeyMatch {
+    value: String,
+    forms: Vec<String>,
+}
+
+impl SearchKeyMatch {
+    /// Retrieve the value of the search key.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Retrieve the inflected forms of the search key, such as plurals.
+    pub fn forms(&self) -> &[String] {
+        &self.forms
+    }
+
+    // The given term is expected to be lowercase
+    fn matches(&self, term: &str) -> bool {
+        self.value.to_lowercase() == term
+            || self.forms.iter().any(|form| form.to_lowercase() == term)
+    }
+}
+
+// Parse a search key map. The `location` of the map is used to
+// resolve the hrefs of the search key groups.
+pub(super) fn parse_search_key_map(data: &[u8], location: &str) -> EbookResult<SearchKeyMap> {
+    let language = RefCell::new(None);
+    let groups = RefCell::new(Vec::new());
+
+    let map_handler = element!("search-key-map", |element| {
+        language.replace(
+            xmlutil::copy_attributes(element.attributes())
+                .into_iter()
+                .find(|attribute| matches!(attribute.name(), constants::XML_LANG | constants::LANG))
+                .map(|attribute| attribute.value().to_string()),
+        );
+
+        Ok(())
+    });
+
+    let group_handler = element!("search-key-group", |element| {
+        if let Some(href) = element.get_attribute(xml::HREF) {
+            groups.borrow_mut().push(SearchKeyGroup {
+                href: epub::resolve_href(location, &href),
+                matches: Vec::new(),
+            });
+        }
+
+        Ok(())
+    });
+
+    let match_handler = element!("match", |element| {
+        if let (Some(value), Some(group)) = (
+            element.get_attribute(constants::VALUE),
+            groups.borrow_mut().last_mut(),
+        ) {
+            group.matches.push(SearchKeyMatch {
+                value,
+                forms: Vec::new(),
+            });
+        }
+
+        Ok(())
+    });
+
+    let value_handler = element!("value", |element| {
+        if let (Some(value), Some(key)) = (
+            element.get_attribute(constants::VALUE),
+            groups
+                .borrow_mut()
+                .last_mut()
+                .and_then(|group| group.matches.last_mut()),
+        ) {
+            key.forms.push(value);
+        }
+
+        Ok(())
+    });
+
+    epub::parse_xhtml_data(
+        vec![map_handler, group_handler, match_handler, value_handler],
+        vec![],
+        data,
+    )?;
+
+    Ok(SearchKeyMap {
+        language: language.take(),
+        groups: groups.take(),
+    })
+}
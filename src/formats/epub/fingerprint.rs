@@ -0,0 +1,31 @@
+// 128-bit FNV-1a hash, used to create fingerprints that remain stable
+// across platforms and compiler versions, unlike the std hashers.
+const OFFSET_BASIS: u128 = 0x6c62272e07bb014262b821756295c58d;
+const PRIME: u128 = 0x0000000001000000000000000000013B;
+
+pub(super) struct Fingerprint(u128);
+
+impl Fingerprint {
+    pub(super) fn new() -> Self {
+        Self(OFFSET_BASIS)
+    }
+
+    // Write a length-prefixed field, so the boundaries of
+    // consecutive fields cannot be confused with each other
+    pub(super) fn write(&mut self, field: &[u8]) {
+        self.write_raw(&(field.len() as u64).to_le_bytes());
+        self.write_raw(field);
+    }
+
+    fn write_raw(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u128;
+            self.0 = self.0.wrapping_mul(PRIME);
+        }
+    }
+
+    // Retrieve the digest as 32 lowercase hexadecimal characters
+    pub(super) fn finish(&self) -> String {
+        format!("{:032x}", self.0)
+    }
+}
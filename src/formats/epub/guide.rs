@@ -52,6 +52,15 @@ impl Guide {
     }
 }
 
+impl<'a> IntoIterator for &'a Guide {
+    type Item = &'a Element;
+    type IntoIter = std::vec::IntoIter<&'a Element>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.elements().into_iter()
+    }
+}
+
 impl Find for Guide {
     fn __find_fallback(&self, _name: &str, _is_wildcard: bool) -> Vec<&Element> {
         self.elements()
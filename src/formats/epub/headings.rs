@@ -0,0 +1,156 @@
+use lol_html::{doc_text, element};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::formats::epub::parse_xhtml_data;
+use crate::formats::xml::TempElement;
+use crate::formats::EbookResult;
+use crate::utility;
+
+/// Options to customize the behavior of
+/// [Epub::generate_toc_from_headings](super::Epub::generate_toc_from_headings).
+#[derive(Clone, Debug)]
+pub struct HeadingOptions {
+    min_level: u8,
+    max_level: u8,
+    include_non_linear: bool,
+}
+
+impl HeadingOptions {
+    /// Create options with default values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The highest heading level to include, such as `1` for `h1`.
+    /// Default is `1`.
+    pub fn min_level(mut self, min_level: u8) -> Self {
+        self.min_level = min_level;
+        self
+    }
+
+    /// The lowest heading level to include, such as `3` for `h3`.
+    /// Default is `3`.
+    pub fn max_level(mut self, max_level: u8) -> Self {
+        self.max_level = max_level;
+        self
+    }
+
+    /// Scan spine entries with `linear="no"`. Default is `false`.
+    pub fn include_non_linear(mut self, include_non_linear: bool) -> Self {
+        self.include_non_linear = include_non_linear;
+        self
+    }
+
+    pub(super) fn includes_non_linear(&self) -> bool {
+        self.include_non_linear
+    }
+
+    fn contains(&self, level: u8) -> bool {
+        (self.min_level..=self.max_level).contains(&level)
+    }
+}
+
+impl Default for HeadingOptions {
+    fn default() -> Self {
+        Self {
+            min_level: 1,
+            max_level: 3,
+            include_non_linear: false,
+        }
+    }
+}
+
+// Heading of a content document
+pub(super) struct Heading {
+    level: u8,
+    id: Option<String>,
+    text: String,
+}
+
+// Retrieve the headings of a content document within the given levels
+pub(super) fn find_headings(data: &str, options: &HeadingOptions) -> EbookResult<Vec<Heading>> {
+    let current = Rc::new(RefCell::new(None));
+    let headings = Rc::new(RefCell::new(Vec::new()));
+
+    // Skip the head, matching the parsing of navigation documents
+    let data = data.find("<body").map_or(data, |index| &data[index..]);
+
+    let heading_handler = element!("h1, h2, h3, h4, h5, h6", |element| {
+        let level = element.tag_name()[1..].parse().unwrap_or_default();
+
+        // Nested headings are part of the text of the enclosing heading
+        if !options.contains(level) || current.borrow().is_some() {
+            return Ok(());
+        }
+
+        current.replace(Some(Heading {
+            level,
+            id: element.get_attribute("id"),
+            text: String::new(),
+        }));
+
+        let current = Rc::clone(&current);
+        let headings = Rc::clone(&headings);
+        element.on_end_tag(move |_| {
+            if let Some(heading) = current.take() {
+                headings.borrow_mut().push(heading);
+            }
+            Ok(())
+        })?;
+
+        Ok(())
+    });
+    let text_handler = doc_text!(|text| {
+        if let Some(heading) = current.borrow_mut().as_mut() {
+            heading.text.push_str(text.as_str());
+        }
+        Ok(())
+    });
+
+    parse_xhtml_data(vec![heading_handler], vec![text_handler], data.as_bytes())?;
+
+    let headings = headings.take();
+    Ok(headings)
+}
+
+// Nest headings by level into toc elements, where the name is the
+// text of the heading and the value is the href of its document
+// followed by the id of the heading as the fragment.
+pub(super) fn to_toc_elements(headings: Vec<(String, Heading)>) -> Vec<TempElement> {
+    let entries: Vec<_> = headings
+        .into_iter()
+        .filter_map(|(href, heading)| {
+            let text = utility::decode_entities(&heading.text);
+            let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+            let href = match heading.id {
+                Some(id) => format!("{href}#{id}"),
+                None => href,
+            };
+
+            (!text.is_empty()).then_some((heading.level, text, href))
+        })
+        .collect();
+
+    nest(&mut entries.into_iter().peekable(), 0)
+}
+
+fn nest<I>(entries: &mut std::iter::Peekable<I>, parent_level: u8) -> Vec<TempElement>
+where
+    I: Iterator<Item = (u8, String, String)>,
+{
+    let mut elements = Vec::new();
+
+    while let Some((level, name, value)) = entries.next_if(|(level, _, _)| *level > parent_level) {
+        let children = nest(entries, level);
+
+        elements.push(TempElement {
+            name,
+            value,
+            children: (!children.is_empty()).then_some(children),
+            ..TempElement::default()
+        });
+    }
+
+    elements
+}
@@ -0,0 +1,286 @@
+#[cfg(feature = "image-meta")]
+use lol_html::element;
+#[cfg(feature = "image-meta")]
+use std::cell::RefCell;
+
+#[cfg(feature = "image-meta")]
+use crate::formats::epub;
+use crate::formats::xml::Element;
+
+/// Information about an image of the manifest, retrieved using
+/// [Epub::images_with_info](super::Epub::images_with_info).
+///
+/// Sizes are available for all images. With the `image-meta` feature
+/// enabled, the format and dimensions are also retrieved by reading the
+/// header of each image, without decoding the image itself.
+///
+/// # Examples
+/// Finding oversized images:
+/// ```
+/// # use rbook::Ebook;
+/// # let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+/// let oversized: Vec<_> = epub
+///     .images_with_info()
+///     .into_iter()
+///     .filter(|image| image.size().is_some_and(|size| size > 300_000))
+///     .collect();
+///
+/// assert_eq!(1, oversized.len());
+/// assert_eq!("images/9780316000000.jpg", oversized[0].element().value());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImageInfo<'a> {
+    element: &'a Element,
+    size: Option<u64>,
+    compressed_size: Option<u64>,
+    #[cfg(feature = "image-meta")]
+    format: Option<ImageFormat>,
+    #[cfg(feature = "image-meta")]
+    dimensions: Option<(u32, u32)>,
+}
+
+impl<'a> ImageInfo<'a> {
+    pub(super) fn new(
+        element: &'a Element,
+        size: Option<u64>,
+        compressed_size: Option<u64>,
+    ) -> Self {
+        Self {
+            element,
+            size,
+            compressed_size,
+            #[cfg(feature = "image-meta")]
+            format: None,
+            #[cfg(feature = "image-meta")]
+            dimensions: None,
+        }
+    }
+
+    /// Retrieve the manifest element of the image.
+    pub fn element(&self) -> &'a Element {
+        self.element
+    }
+
+    /// Retrieve the uncompressed size of the image in bytes.
+    /// Returns [None] if the image file does not exist.
+    pub fn size(&self) -> Option<u64> {
+        self.size
+    }
+
+    /// Retrieve the size of the image in bytes as stored within the
+    /// epub, which is equal to [size](Self::size) if the image is not
+    /// compressed. Returns [None] if the image file does not exist.
+    pub fn compressed_size(&self) -> Option<u64> {
+        self.compressed_size
+    }
+
+    /// Retrieve the format of the image, determined from its header
+    /// rather than its declared media type. Returns [None] if the format
+    /// is not recognized or the image cannot be read.
+    #[cfg(feature = "image-meta")]
+    pub fn format(&self) -> Option<ImageFormat> {
+        self.format
+    }
+
+    /// Retrieve the `(width, height)` of the image in pixels.
+    /// Returns [None] if the dimensions cannot be determined, such as an
+    /// SVG image without a fixed size.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// use rbook::epub::ImageFormat;
+    ///
+    /// # let epub = rbook::Epub::new("tests/ebooks/childrens-literature.epub").unwrap();
+    /// let images = epub.images_with_info();
+    ///
+    /// assert_eq!(Some(ImageFormat::Png), images[0].format());
+    /// assert_eq!(Some((500, 714)), images[0].dimensions());
+    /// ```
+    #[cfg(feature = "image-meta")]
+    pub fn dimensions(&self) -> Option<(u32, u32)> {
+        self.dimensions
+    }
+
+    #[cfg(feature = "image-meta")]
+    pub(super) fn read_header(&mut self, data: &[u8]) {
+        self.format = ImageFormat::from_header(data);
+        self.dimensions = self.format.and_then(|format| match format {
+            ImageFormat::Png => png_dimensions(data),
+            ImageFormat::Jpeg => jpeg_dimensions(data),
+            ImageFormat::Gif => gif_dimensions(data),
+            ImageFormat::Webp => webp_dimensions(data),
+            ImageFormat::Svg => svg_dimensions(data),
+        });
+    }
+}
+
+/// Supported image formats of [ImageInfo].
+#[cfg(feature = "image-meta")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ImageFormat {
+    /// Portable Network Graphics image.
+    Png,
+    /// JPEG image, including JFIF and Exif variants.
+    Jpeg,
+    /// Graphics Interchange Format image.
+    Gif,
+    /// WebP image in the lossy, lossless, or extended format.
+    Webp,
+    /// Scalable Vector Graphics image.
+    Svg,
+}
+
+#[cfg(feature = "image-meta")]
+impl ImageFormat {
+    fn from_header(data: &[u8]) -> Option<Self> {
+        if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+            Some(Self::Png)
+        } else if data.starts_with(b"\xFF\xD8\xFF") {
+            Some(Self::Jpeg)
+        } else if data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a") {
+            Some(Self::Gif)
+        } else if data.starts_with(b"RIFF") && data.get(8..12) == Some(&b"WEBP"[..]) {
+            Some(Self::Webp)
+        } else {
+            // Svg images may start with whitespace, comments, or declarations
+            let start = data.get(..data.len().min(1024))?;
+            String::from_utf8_lossy(start)
+                .contains("<svg")
+                .then_some(Self::Svg)
+        }
+    }
+
+    /// Retrieve the media type of the format, such as `image/png`.
+    pub fn media_type(&self) -> &'static str {
+        match self {
+            Self::Png => "image/png",
+            Self::Jpeg => "image/jpeg",
+            Self::Gif => "image/gif",
+            Self::Webp => "image/webp",
+            Self::Svg => "image/svg+xml",
+        }
+    }
+}
+
+// The width and height are the first fields of the `IHDR` chunk
+#[cfg(feature = "image-meta")]
+fn png_dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    Some((read_u32_be(data, 16)?, read_u32_be(data, 20)?))
+}
+
+// The dimensions are stored within the first start of frame (SOF) segment
+#[cfg(feature = "image-meta")]
+fn jpeg_dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    let mut index = 2;
+
+    while index + 4 <= data.len() {
+        if data[index] != 0xFF {
+            return None;
+        }
+
+        let marker = data[index + 1];
+        let length = u16::from_be_bytes([data[index + 2], data[index + 3]]) as usize;
+
+        // Markers C4 (DHT), C8 (JPG), and CC (DAC) are not frames
+        if (0xC0..=0xCF).contains(&marker) && ![0xC4, 0xC8, 0xCC].contains(&marker) {
+            let height = u16::from_be_bytes([*data.get(index + 5)?, *data.get(index + 6)?]);
+            let width = u16::from_be_bytes([*data.get(index + 7)?, *data.get(index + 8)?]);
+            return Some((width.into(), height.into()));
+        }
+
+        index += 2 + length;
+    }
+
+    None
+}
+
+#[cfg(feature = "image-meta")]
+fn gif_dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    let width = u16::from_le_bytes([*data.get(6)?, *data.get(7)?]);
+    let height = u16::from_le_bytes([*data.get(8)?, *data.get(9)?]);
+    Some((width.into(), height.into()))
+}
+
+// Webp images contain a lossy (VP8), lossless (VP8L), or extended (VP8X) chunk
+#[cfg(feature = "image-meta")]
+fn webp_dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    match data.get(12..16)? {
+        b"VP8 " => {
+            let width = u16::from_le_bytes([*data.get(26)?, *data.get(27)?]) & 0x3FFF;
+            let height = u16::from_le_bytes([*data.get(28)?, *data.get(29)?]) & 0x3FFF;
+            Some((width.into(), height.into()))
+        }
+        b"VP8L" => {
+            let bits = u32::from_le_bytes(data.get(21..25)?.try_into().ok()?);
+            Some(((bits & 0x3FFF) + 1, ((bits >> 14) & 0x3FFF) + 1))
+        }
+        b"VP8X" => {
+            let read_u24 = |index: usize| {
+                let bytes = data.get(index..index + 3)?;
+                Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], 0]) + 1)
+            };
+            Some((read_u24(24)?, read_u24(27)?))
+        }
+        _ => None,
+    }
+}
+
+// Retrieve the size from the `width` and `height` attributes of the root
+// element, otherwise from its `viewBox`
+#[cfg(feature = "image-meta")]
+fn svg_dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    let dimensions = RefCell::new(None);
+
+    let svg_handler = element!("svg", |element| {
+        if dimensions.borrow().is_some() {
+            return Ok(());
+        }
+
+        let length = |name: &str| {
+            element
+                .get_attribute(name)
+                .and_then(|value| parse_length(&value))
+        };
+        let view_box = element.get_attribute("viewBox").and_then(|value| {
+            let values: Vec<_> = value
+                .split(|c: char| c.is_whitespace() || c == ',')
+                .filter(|value| !value.is_empty())
+                .map(parse_length)
+                .collect();
+
+            match values.as_slice() {
+                [_, _, Some(width), Some(height)] => Some((*width, *height)),
+                _ => None,
+            }
+        });
+
+        let size = match (length("width"), length("height")) {
+            (Some(width), Some(height)) => Some((width, height)),
+            _ => view_box,
+        };
+        dimensions.replace(Some(size));
+        Ok(())
+    });
+
+    epub::parse_xhtml_data(vec![svg_handler], vec![], data).ok()?;
+    dimensions.take().flatten()
+}
+
+// Parse a length in pixels, such as `600` or `600px`
+#[cfg(feature = "image-meta")]
+fn parse_length(value: &str) -> Option<u32> {
+    let value = value.trim();
+    let number = value.strip_suffix("px").unwrap_or(value);
+    let number: f64 = number.trim().parse().ok()?;
+
+    (number.is_finite() && number >= 0.0).then(|| number.round() as u32)
+}
+
+#[cfg(feature = "image-meta")]
+fn read_u32_be(data: &[u8], index: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(
+        data.get(index..index + 4)?.try_into().ok()?,
+    ))
+}
@@ -0,0 +1,130 @@
+// Heuristic language detection of text, based on the writing system
+// of its letters and, for the latin script, the frequency of common
+// words of each language.
+
+// Minimum number of letters required to make a guess
+const MIN_LETTERS: usize = 20;
+// Minimum fraction of latin words that must be common words of a language
+const MIN_WORD_RATIO: f64 = 0.05;
+
+// Check if a character belongs to a script
+type IsScript = fn(char) -> bool;
+
+// Scripts that are mostly used by a single language
+const SCRIPTS: [(&str, IsScript); 8] = [
+    (
+        "ko",
+        |c| matches!(c, '\u{1100}'..='\u{11FF}' | '\u{AC00}'..='\u{D7AF}'),
+    ),
+    ("ru", |c| matches!(c, '\u{0400}'..='\u{04FF}')),
+    ("el", |c| matches!(c, '\u{0370}'..='\u{03FF}')),
+    ("ar", |c| matches!(c, '\u{0600}'..='\u{06FF}')),
+    ("he", |c| matches!(c, '\u{0590}'..='\u{05FF}')),
+    ("th", |c| matches!(c, '\u{0E00}'..='\u{0E7F}')),
+    ("hi", |c| matches!(c, '\u{0900}'..='\u{097F}')),
+    ("zh", is_han),
+];
+
+// Common words of languages that use the latin script
+const WORDS: [(&str, &[&str]); 7] = [
+    (
+        "en",
+        &[
+            "the", "and", "of", "to", "in", "is", "that", "it", "was", "he", "with", "for",
+        ],
+    ),
+    (
+        "fr",
+        &[
+            "le", "la", "les", "et", "des", "est", "une", "que", "dans", "il", "pas", "du",
+        ],
+    ),
+    (
+        "de",
+        &[
+            "der", "die", "und", "das", "ist", "nicht", "ein", "ich", "zu", "den", "mit", "sie",
+        ],
+    ),
+    (
+        "es",
+        &[
+            "el", "la", "los", "y", "que", "en", "las", "por", "una", "con", "del", "se",
+        ],
+    ),
+    (
+        "it",
+        &[
+            "il", "di", "che", "e", "la", "non", "per", "un", "sono", "gli", "della", "con",
+        ],
+    ),
+    (
+        "pt",
+        &[
+            "o", "de", "que", "e", "os", "não", "uma", "com", "para", "do", "da", "em",
+        ],
+    ),
+    (
+        "nl",
+        &[
+            "de", "het", "een", "en", "van", "ik", "niet", "dat", "zijn", "op", "te", "met",
+        ],
+    ),
+];
+
+// Retrieve the best-guess language tag of text, such as `en`
+pub(super) fn detect(text: &str) -> Option<&'static str> {
+    let letters: Vec<char> = text.chars().filter(|c| c.is_alphabetic()).collect();
+
+    if letters.len() < MIN_LETTERS {
+        return None;
+    }
+
+    // Japanese is written using both kana and han characters
+    let count = |is_script: IsScript| letters.iter().filter(|c| is_script(**c)).count();
+    let (kana, han) = (count(is_kana), count(is_han));
+    if kana > 0 && kana + han > letters.len() / 2 {
+        return Some("ja");
+    }
+
+    let (language, script_count) = SCRIPTS
+        .iter()
+        .map(|(language, is_script)| (*language, count(*is_script)))
+        .max_by_key(|(_, count)| *count)?;
+
+    if script_count > letters.len() / 2 {
+        return Some(language);
+    }
+
+    detect_latin(text)
+}
+
+fn detect_latin(text: &str) -> Option<&'static str> {
+    let words: Vec<String> = text
+        .split(|c: char| !c.is_alphabetic())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+
+    let (language, matches) = WORDS
+        .iter()
+        .map(|(language, common)| {
+            let matches = words
+                .iter()
+                .filter(|word| common.contains(&word.as_str()))
+                .count();
+            (*language, matches)
+        })
+        // Prefer the first language on ties
+        .rev()
+        .max_by_key(|(_, matches)| *matches)?;
+
+    (matches as f64 >= words.len() as f64 * MIN_WORD_RATIO && matches > 0).then_some(language)
+}
+
+fn is_kana(c: char) -> bool {
+    matches!(c, '\u{3040}'..='\u{30FF}' | '\u{31F0}'..='\u{31FF}')
+}
+
+fn is_han(c: char) -> bool {
+    matches!(c, '\u{4E00}'..='\u{9FFF}' | '\u{3400}'..='\u{4DBF}')
+}
@@ -0,0 +1,185 @@
+// Primary language subtags of languages written from right to left
+const RTL_LANGUAGES: [&str; 9] = ["ar", "dv", "fa", "he", "ks", "ku", "ps", "ur", "yi"];
+// Css property that sets the writing mode, also matching vendor prefixes
+const WRITING_MODE: &str = "writing-mode";
+
+/// Summary of the hints that determine the layout of an ebook,
+/// retrieved using [Epub::layout_hints](super::Epub::layout_hints).
+///
+/// Combines the `page-progression-direction` of the spine, the primary
+/// language of the metadata, and the `writing-mode` declared by the
+/// stylesheets of the manifest.
+///
+/// # Examples
+/// Configuring pagination:
+/// ```
+/// # use rbook::Ebook;
+/// # let epub = rbook::Epub::new("tests/ebooks/example_epub").unwrap();
+/// let hints = epub.layout_hints();
+///
+/// assert_eq!(Some("ltr"), hints.page_progression_direction());
+/// assert_eq!(Some("en"), hints.language());
+/// assert!(!hints.is_rtl());
+/// assert!(!hints.is_vertical());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LayoutHints {
+    page_progression_direction: Option<String>,
+    language: Option<String>,
+    writing_mode: Option<String>,
+}
+
+impl LayoutHints {
+    pub(super) fn new(
+        page_progression_direction: Option<&str>,
+        language: Option<&str>,
+        writing_mode: Option<String>,
+    ) -> Self {
+        Self {
+            page_progression_direction: page_progression_direction.map(str::to_string),
+            language: language.map(str::to_string),
+            writing_mode,
+        }
+    }
+
+    /// Retrieve the `page-progression-direction` of the spine,
+    /// such as `rtl`.
+    pub fn page_progression_direction(&self) -> Option<&str> {
+        self.page_progression_direction.as_deref()
+    }
+
+    /// Retrieve the primary language of the ebook, such as `ja`.
+    pub fn language(&self) -> Option<&str> {
+        self.language.as_deref()
+    }
+
+    /// Retrieve the first `writing-mode` declared by the stylesheets of
+    /// the manifest, such as `vertical-rl`. Vendor prefixed
+    /// declarations, such as `-epub-writing-mode`, are included.
+    pub fn writing_mode(&self) -> Option<&str> {
+        self.writing_mode.as_deref()
+    }
+
+    /// Check if pages progress from right to left.
+    ///
+    /// The `page-progression-direction` is used if specified.
+    /// Otherwise, pages progress from right to left if the text is
+    /// written vertically from right to left, or if the language is
+    /// written from right to left, such as Arabic or Hebrew.
+    pub fn is_rtl(&self) -> bool {
+        match self.page_progression_direction() {
+            Some("rtl") => true,
+            Some("ltr") => false,
+            _ => {
+                let is_rtl_language = self.language().is_some_and(|language| {
+                    let primary = language.split(['-', '_']).next().unwrap_or_default();
+                    RTL_LANGUAGES
+                        .iter()
+                        .any(|rtl| rtl.eq_ignore_ascii_case(primary))
+                });
+
+                is_rtl_language || self.writing_mode() == Some("vertical-rl")
+            }
+        }
+    }
+
+    /// Check if text is written vertically, such as `vertical-rl`.
+    pub fn is_vertical(&self) -> bool {
+        self.writing_mode()
+            .is_some_and(|mode| mode.starts_with("vertical") || mode.starts_with("tb"))
+    }
+}
+
+/// Base direction of text, specified by the `dir` attribute of the
+/// package and metadata elements.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use rbook::epub::TextDirection;
+///
+/// assert_eq!(Some(TextDirection::Rtl), TextDirection::from_dir(" RTL "));
+/// assert_eq!(None, TextDirection::from_dir("sideways"));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TextDirection {
+    /// Text is written from left to right.
+    Ltr,
+    /// Text is written from right to left, such as Arabic or Hebrew.
+    Rtl,
+    /// Direction is determined by the content, such as for text
+    /// with mixed scripts.
+    Auto,
+}
+
+impl TextDirection {
+    /// Retrieve the direction of a `dir` value, such as `rtl`.
+    /// Case and surrounding whitespace are ignored.
+    ///
+    /// Returns [None] if the value is not recognized.
+    pub fn from_dir(dir: &str) -> Option<Self> {
+        match dir.trim().to_lowercase().as_str() {
+            "ltr" => Some(Self::Ltr),
+            "rtl" => Some(Self::Rtl),
+            "auto" => Some(Self::Auto),
+            _ => None,
+        }
+    }
+
+    /// Retrieve the `dir` value of the direction, such as `rtl`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ltr => "ltr",
+            Self::Rtl => "rtl",
+            Self::Auto => "auto",
+        }
+    }
+}
+
+// Retrieve the value of the first `writing-mode` declaration of a stylesheet
+pub(super) fn find_writing_mode(css: &str) -> Option<String> {
+    let css = strip_comments(css);
+
+    css.match_indices(WRITING_MODE).find_map(|(index, _)| {
+        // Exclude other properties that end with `writing-mode`
+        let start = css[..index]
+            .rfind(|c: char| !(c.is_alphanumeric() || c == '-' || c == '_'))
+            .map_or(0, |preceding| preceding + 1);
+        if !is_vendor_prefix(&css[start..index]) {
+            return None;
+        }
+
+        let value = css[index + WRITING_MODE.len()..]
+            .trim_start()
+            .strip_prefix(':')?;
+        let end = value.find([';', '}']).unwrap_or(value.len());
+        let value = value[..end].trim().trim_end_matches("!important").trim();
+
+        (!value.is_empty()).then(|| value.to_ascii_lowercase())
+    })
+}
+
+// Check if a property prefix is empty or a vendor prefix, such as `-epub-`
+fn is_vendor_prefix(prefix: &str) -> bool {
+    prefix.is_empty()
+        || prefix
+            .strip_prefix('-')
+            .and_then(|prefix| prefix.strip_suffix('-'))
+            .is_some_and(|vendor| !vendor.is_empty() && vendor.chars().all(char::is_alphanumeric))
+}
+
+fn strip_comments(css: &str) -> String {
+    let mut stripped = String::with_capacity(css.len());
+    let mut rest = css;
+
+    while let Some(start) = rest.find("/*") {
+        stripped.push_str(&rest[..start]);
+        rest = match rest[start + 2..].find("*/") {
+            Some(end) => &rest[start + 2 + end + 2..],
+            None => "",
+        };
+    }
+
+    stripped.push_str(rest);
+    stripped
+}
@@ -0,0 +1,132 @@
+use crate::formats::epub::{constants, Metadata, TitleKind};
+use crate::formats::xml::{utility as xmlutil, Element};
+
+/// Access the metadata of an ebook in a specific language, such as for
+/// bilingual editions, retrieved using [Metadata::localized].
+///
+/// The language of an entry is its `xml:lang` attribute. Entries without
+/// one are in the language of the package, which is the `xml:lang`
+/// attribute of the `package` element, or otherwise the primary
+/// `dc:language`. If an entry is not in the requested language, its
+/// `alternate-script` refinement in that language is used instead.
+///
+/// Languages match if they are equal, ignoring case, or if one is a
+/// more specific variant of the other, such as `en` and `en-US`.
+///
+/// # Examples
+/// Retrieving the title in different languages:
+/// ```
+/// # use rbook::Ebook;
+/// # let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+/// let metadata = epub.metadata();
+///
+/// assert_eq!(Some("Moby-Dick"), metadata.localized("en").title());
+/// // Falls back to the title in the language of the package
+/// assert_eq!(Some("Moby-Dick"), metadata.localized("ja").title());
+/// assert!(!metadata.localized("ja").is_available(metadata.title().unwrap()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct LocalizedMetadata<'a> {
+    metadata: &'a Metadata,
+    language: String,
+}
+
+impl<'a> LocalizedMetadata<'a> {
+    pub(super) fn new(metadata: &'a Metadata, language: &str) -> Self {
+        Self {
+            metadata,
+            language: language.trim().to_string(),
+        }
+    }
+
+    /// Retrieve the requested language, such as `fr`.
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    /// Retrieve the main title in the requested language.
+    ///
+    /// Falls back to the value of [Metadata::title] if no title is
+    /// available in the requested language.
+    pub fn title(&self) -> Option<&'a str> {
+        let mut titles = self.metadata.titles_by_kind(TitleKind::Main);
+        titles.extend(self.metadata.get(constants::TITLE));
+
+        titles
+            .into_iter()
+            .find_map(|title| self.value(title))
+            .or_else(|| self.metadata.title().map(Element::value))
+    }
+
+    /// Retrieve the description in the requested language.
+    ///
+    /// Falls back to the value of [Metadata::description] if no
+    /// description is available in the requested language.
+    pub fn description(&self) -> Option<&'a str> {
+        self.metadata
+            .get(constants::DESCRIPTION)
+            .into_iter()
+            .find_map(|description| self.value(description))
+            .or_else(|| self.metadata.description().map(Element::value))
+    }
+
+    /// Retrieve the names of the creators in the requested language, in
+    /// the same order as [Metadata::creators]. Creators whose name is not
+    /// available in the requested language retain their original name.
+    pub fn creators(&self) -> Vec<&'a str> {
+        self.metadata
+            .creators()
+            .into_iter()
+            .map(|creator| self.value(creator).unwrap_or(creator.value()))
+            .collect()
+    }
+
+    /// Retrieve the value of a metadata element in the requested language,
+    /// which is either the value of the element or of its
+    /// `alternate-script` refinement.
+    ///
+    /// Returns [None] if the element is not available in the requested
+    /// language.
+    pub fn value(&self, element: &'a Element) -> Option<&'a str> {
+        if self.matches(self.element_language(element)) {
+            return Some(element.value());
+        }
+
+        element
+            .children()
+            .into_iter()
+            .filter(|child| child.name() == constants::ALTERNATE_SCRIPT)
+            .find(|child| self.matches(child.get_attribute(constants::LANG)))
+            .map(Element::value)
+    }
+
+    /// Check if a metadata element is available in the requested language.
+    pub fn is_available(&self, element: &'a Element) -> bool {
+        self.value(element).is_some()
+    }
+
+    // Retrieve the language of an element, which is inherited
+    // from the package if not specified
+    fn element_language(&self, element: &'a Element) -> Option<&'a str> {
+        element.get_attribute(constants::LANG).or_else(|| {
+            xmlutil::get_attribute(self.metadata.package_attributes(), constants::LANG)
+                .or_else(|| self.metadata.language().map(Element::value))
+        })
+    }
+
+    fn matches(&self, language: Option<&str>) -> bool {
+        let Some(language) = language.map(|language| language.trim().to_lowercase()) else {
+            return false;
+        };
+        let requested = self.language.to_lowercase();
+        let is_variant = |general: &str, specific: &str| {
+            specific
+                .strip_prefix(general)
+                .is_some_and(|rest| rest.starts_with('-'))
+        };
+
+        language == requested
+            || is_variant(&requested, &language)
+            || is_variant(&language, &requested)
+    }
+}
@@ -1,7 +1,8 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use crate::formats::epub::constants;
-use crate::formats::xml::{self, Element};
+use crate::formats::epub::{constants, ReadingSystemProfile};
+use crate::formats::xml::{self, utility as xmlutil, Attribute, Element};
+use crate::utility;
 use crate::xml::Find;
 
 /// Access all resources for the ebook, such as images, files, etc.
@@ -27,18 +28,95 @@ use crate::xml::Find;
 /// assert_eq!("chapter_009.xhtml", href);
 /// ```
 #[derive(Debug)]
-pub struct Manifest(HashMap<String, Element>);
+pub struct Manifest {
+    // Elements sorted by id
+    elements: Vec<Element>,
+    // Indexes into `elements` to avoid searching upon each lookup
+    ids: HashMap<String, usize>,
+    hrefs: HashMap<String, usize>,
+    // Normalized lowercase hrefs, if hrefs are compared ignoring case
+    folded_hrefs: Option<HashMap<String, usize>>,
+    foreign_elements: Vec<Element>,
+    attributes: Vec<Attribute>,
+}
 
 impl Manifest {
     pub(crate) fn new(element_map: HashMap<String, Element>) -> Self {
-        Self(element_map)
+        let mut elements: Vec<_> = element_map.into_values().collect();
+        elements.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+
+        let ids = elements
+            .iter()
+            .enumerate()
+            .map(|(index, element)| (element.name.clone(), index))
+            .collect();
+        let mut hrefs = HashMap::with_capacity(elements.len());
+
+        // The first element in order takes precedence for duplicate hrefs
+        for (index, element) in elements.iter().enumerate() {
+            hrefs.entry(element.value.clone()).or_insert(index);
+        }
+
+        Self {
+            elements,
+            ids,
+            hrefs,
+            folded_hrefs: None,
+            foreign_elements: Vec::new(),
+            attributes: Vec::new(),
+        }
+    }
+
+    pub(crate) fn set_href_case_insensitive(&mut self, href_case_insensitive: bool) {
+        self.folded_hrefs = href_case_insensitive.then(|| {
+            let mut folded_hrefs = HashMap::with_capacity(self.elements.len());
+
+            for (index, element) in self.elements.iter().enumerate() {
+                folded_hrefs
+                    .entry(normalize_href(&element.value))
+                    .or_insert(index);
+            }
+            folded_hrefs
+        });
+    }
+
+    pub(crate) fn set_foreign_elements(&mut self, foreign_elements: Vec<Element>) {
+        self.foreign_elements = foreign_elements;
+    }
+
+    pub(crate) fn set_attributes(&mut self, attributes: Vec<Attribute>) {
+        self.attributes = attributes;
     }
 
     /// Retrieve all manifest `item` elements.
     pub fn elements(&self) -> Vec<&Element> {
-        let mut sorted_elements: Vec<_> = self.0.values().collect();
-        sorted_elements.sort_by_key(|e| &e.name);
-        sorted_elements
+        self.elements.iter().collect()
+    }
+
+    /// Retrieve all the attributes of the root manifest element
+    pub fn attributes(&self) -> &[Attribute] {
+        &self.attributes
+    }
+
+    /// Retrieve the value from a certain attribute from the root manifest element
+    pub fn get_attribute(&self, name: &str) -> Option<&str> {
+        xmlutil::get_attribute(&self.attributes, &name.to_lowercase())
+    }
+
+    /// Retrieve the attributes of the root manifest element that rbook
+    /// does not model, such as `xml:base` or custom attributes.
+    ///
+    /// The `id` attribute, along with namespace declarations, is excluded.
+    pub fn extra_attributes(&self) -> Vec<&Attribute> {
+        xmlutil::extra_attributes(&self.attributes, &constants::MANIFEST_ATTRIBUTES)
+    }
+
+    /// Retrieve the children of the `manifest` element that are not
+    /// `item` elements, such as vendor extensions, in the order they
+    /// appear. See [Epub::foreign_elements](super::Epub::foreign_elements)
+    /// for more details.
+    pub fn foreign_elements(&self) -> Vec<&Element> {
+        self.foreign_elements.iter().collect()
     }
 
     /// Retrieve all elements that reference an image media type file.
@@ -73,29 +151,72 @@ impl Manifest {
     /// }
     /// ```
     pub fn images(&self) -> Vec<&Element> {
-        self.0
-            .values()
+        self.elements
+            .iter()
             .filter(|element| {
                 element
                     .get_attribute(constants::MEDIA_TYPE)
-                    .map_or(false, |attribute| attribute.starts_with("image"))
+                    .is_some_and(|attribute| attribute.starts_with("image"))
             })
             .collect()
     }
 
     /// Retrieve a certain element by the value of its `id` from the manifest
     pub fn by_id(&self, id: &str) -> Option<&Element> {
-        self.0.get(id)
+        self.ids.get(id).map(|index| &self.elements[*index])
     }
 
     /// Retrieve a certain element by the value of its `href` from the manifest
+    ///
+    /// If the epub was opened with
+    /// [href_case_insensitive](super::EpubSettings::href_case_insensitive)
+    /// enabled and no exact match exists, hrefs are compared ignoring case.
     pub fn by_href(&self, href: &str) -> Option<&Element> {
-        xml::utility::find_attribute_by_value(&self.elements(), xml::HREF, href)
+        let index = match (self.hrefs.get(href), &self.folded_hrefs) {
+            (Some(index), _) => index,
+            (None, Some(folded_hrefs)) => folded_hrefs.get(&normalize_href(href))?,
+            (None, None) => return None,
+        };
+
+        Some(&self.elements[*index])
     }
 
     /// Check if an element with a certain `id` exists in the manifest
     pub fn contains_id(&self, id: &str) -> bool {
-        self.0.contains_key(id)
+        self.ids.contains_key(id)
+    }
+
+    /// Check if an element with a certain `href` exists in the manifest,
+    /// following the same rules as [by_href](Self::by_href).
+    pub fn contains_href(&self, href: &str) -> bool {
+        self.by_href(href).is_some()
+    }
+
+    /// Retrieve the number of manifest `item` elements.
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Check if the manifest contains no `item` elements.
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Retrieve the manifest `item` element at a certain index, following
+    /// the order of [elements](Self::elements).
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// # let epub = rbook::Epub::new("tests/ebooks/example_epub").unwrap();
+    /// let manifest = epub.manifest();
+    ///
+    /// assert_eq!(manifest.elements()[0], manifest.get(0).unwrap());
+    /// assert!(manifest.get(manifest.len()).is_none());
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&Element> {
+        self.elements.get(index)
     }
 
     /// Retrieve a certain element by the value of its
@@ -123,6 +244,150 @@ impl Manifest {
     pub fn all_by_property(&self, property: &str) -> Vec<&Element> {
         xml::utility::find_attributes_by_value(&self.elements(), constants::PROPERTIES, property)
     }
+
+    /// Retrieve the fallback chain of an element, starting with the
+    /// element itself and followed by each element referenced by the
+    /// `fallback` attribute.
+    ///
+    /// The chain ends upon reaching an element without a fallback, an
+    /// `id` that does not exist, or an element already in the chain.
+    /// See [FallbackChain::is_cyclic] to detect malformed chains.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// # let epub = rbook::Epub::new("tests/ebooks/fallback_epub").unwrap();
+    /// let chart = epub.manifest().by_id("chart").unwrap();
+    /// let ids: Vec<_> = epub
+    ///     .manifest()
+    ///     .fallback_chain(chart)
+    ///     .map(|element| element.name())
+    ///     .collect();
+    ///
+    /// assert_eq!(vec!["chart", "chart-image", "chart-table"], ids);
+    /// ```
+    pub fn fallback_chain<'a>(&'a self, element: &'a Element) -> FallbackChain<'a> {
+        FallbackChain {
+            manifest: self,
+            next: Some(element),
+            visited: HashSet::new(),
+            cyclic: false,
+        }
+    }
+
+    /// Retrieve the first element of the
+    /// [fallback chain](Self::fallback_chain) of an element
+    /// with a media type that is supported by the given closure.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// # let epub = rbook::Epub::new("tests/ebooks/fallback_epub").unwrap();
+    /// let chart = epub.manifest().by_id("chart").unwrap();
+    /// let supported = epub
+    ///     .manifest()
+    ///     .resolve_fallback(chart, |media_type| media_type.starts_with("image/png"))
+    ///     .unwrap();
+    ///
+    /// assert_eq!("chart.png", supported.value());
+    /// ```
+    pub fn resolve_fallback<'a, F>(
+        &'a self,
+        element: &'a Element,
+        is_supported: F,
+    ) -> Option<&'a Element>
+    where
+        F: Fn(&str) -> bool,
+    {
+        self.fallback_chain(element).find(|element| {
+            element
+                .get_attribute(constants::MEDIA_TYPE)
+                .is_some_and(&is_supported)
+        })
+    }
+
+    /// Retrieve the first element of the
+    /// [fallback chain](Self::fallback_chain) of an element that a
+    /// reading system supports, considering both media types and
+    /// scripting. See [ReadingSystemProfile] for more details.
+    pub fn resolve<'a>(
+        &'a self,
+        element: &'a Element,
+        profile: &ReadingSystemProfile,
+    ) -> Option<&'a Element> {
+        self.fallback_chain(element)
+            .find(|element| profile.supports(element))
+    }
+}
+
+/// Iterator over the fallback chain of a manifest element.
+/// See [Manifest::fallback_chain] for more details.
+#[derive(Clone, Debug)]
+pub struct FallbackChain<'a> {
+    manifest: &'a Manifest,
+    next: Option<&'a Element>,
+    visited: HashSet<&'a str>,
+    cyclic: bool,
+}
+
+impl FallbackChain<'_> {
+    /// Check if the chain ended due to a fallback that references an
+    /// element already in the chain, which is invalid.
+    /// Only accurate after the iterator is exhausted.
+    ///
+    /// # Examples
+    /// Detecting a cycle:
+    /// ```
+    /// # use rbook::Ebook;
+    /// # let epub = rbook::Epub::new("tests/ebooks/fallback_epub").unwrap();
+    /// let element = epub.manifest().by_id("loop-a").unwrap();
+    /// let mut chain = epub.manifest().fallback_chain(element);
+    ///
+    /// assert_eq!(2, chain.by_ref().count());
+    /// assert!(chain.is_cyclic());
+    /// ```
+    pub fn is_cyclic(&self) -> bool {
+        self.cyclic
+    }
+}
+
+impl<'a> Iterator for FallbackChain<'a> {
+    type Item = &'a Element;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let element = self.next.take()?;
+
+        if !self.visited.insert(element.name()) {
+            self.cyclic = true;
+            return None;
+        }
+
+        self.next = element
+            .get_attribute(constants::FALLBACK)
+            .and_then(|id| self.manifest.by_id(id));
+
+        Some(element)
+    }
+}
+
+// Normalize an href for case-insensitive comparison
+fn normalize_href(href: &str) -> String {
+    let href = href.replace('\\', "/");
+
+    utility::normalize_path(&href.trim_start_matches("./"))
+        .to_string_lossy()
+        .to_lowercase()
+}
+
+impl<'a> IntoIterator for &'a Manifest {
+    type Item = &'a Element;
+    type IntoIter = std::vec::IntoIter<&'a Element>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.elements().into_iter()
+    }
 }
 
 impl Find for Manifest {
@@ -0,0 +1,238 @@
+use std::fmt::{Display, Formatter};
+use std::time;
+
+use crate::formats::epub::constants;
+use crate::formats::xml::Element;
+
+/// Access the media overlay entries of the `media` vocabulary,
+/// such as `media:duration` and `media:narrator`, used by read-aloud
+/// and audiobook players.
+///
+/// Entries of the ebook, such as its total duration, are retrieved
+/// using [Metadata::media](super::Metadata::media). Entries of a spine
+/// entry are retrieved using [Epub::spine_media](super::Epub::spine_media),
+/// which combines the refinements of the `itemref` with those of its
+/// media overlay.
+///
+/// # Examples
+/// Retrieving the narrator of a spine entry:
+/// ```
+/// # use rbook::Ebook;
+/// # let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+/// let media = epub.spine_media(&epub.spine()[0]);
+///
+/// // Moby Dick does not contain media overlays
+/// assert_eq!(None, media.duration());
+/// assert!(media.narrators().is_empty());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MediaMetadata<'a> {
+    elements: Vec<&'a Element>,
+}
+
+impl<'a> MediaMetadata<'a> {
+    pub(super) fn new(elements: Vec<&'a Element>) -> Self {
+        Self { elements }
+    }
+
+    /// Retrieve the `media:duration` entry, such as `0:32:29`.
+    /// Returns [None] if the entry is missing or is not a valid
+    /// clock value.
+    ///
+    /// For the entries of the ebook, this is the total duration of all
+    /// media overlays.
+    pub fn duration(&self) -> Option<Duration> {
+        self.values(constants::DURATION)
+            .into_iter()
+            .find_map(Duration::parse)
+    }
+
+    /// Retrieve the `media:narrator` entries in the order they appear.
+    pub fn narrators(&self) -> Vec<&'a str> {
+        self.values(constants::NARRATOR)
+    }
+
+    /// Retrieve the `media:active-class` entry, the css class applied to
+    /// the element currently being read aloud, such as `-epub-media-overlay-active`.
+    pub fn active_class(&self) -> Option<&'a str> {
+        self.values(constants::ACTIVE_CLASS).into_iter().next()
+    }
+
+    /// Retrieve the `media:playback-active-class` entry, the css class
+    /// applied to the document while media overlay playback is active.
+    pub fn playback_active_class(&self) -> Option<&'a str> {
+        self.values(constants::PLAYBACK_ACTIVE_CLASS)
+            .into_iter()
+            .next()
+    }
+
+    /// Retrieve all `media` vocabulary entries, such as refinements
+    /// that rbook does not model.
+    pub fn elements(&self) -> Vec<&'a Element> {
+        self.elements
+            .iter()
+            .filter(|element| is_media_property(element))
+            .copied()
+            .collect()
+    }
+
+    fn values(&self, name: &str) -> Vec<&'a str> {
+        self.elements()
+            .into_iter()
+            .filter(|element| element.name() == name)
+            .map(|element| element.value().trim())
+            .collect()
+    }
+}
+
+fn is_media_property(element: &Element) -> bool {
+    element
+        .get_attribute(constants::PROPERTY)
+        .and_then(|property| property.split_once(':'))
+        .is_some_and(|(prefix, _)| prefix == constants::MEDIA_PREFIX)
+}
+
+/// Duration of a media overlay, parsed from a SMIL clock value,
+/// such as `0:32:29`, `05:01.5`, or `12.5s`.
+///
+/// Durations are formatted as full clock values, such as `1:02:03.5`,
+/// with the fraction of seconds omitted if it is zero.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use rbook::epub::Duration;
+///
+/// let duration = Duration::parse("1:02:03.5").unwrap();
+///
+/// assert_eq!(3723.5, duration.as_secs_f64());
+/// assert_eq!(Some(duration), Duration::parse("01:02:03.500"));
+/// assert_eq!(Some(duration), Duration::parse("3723.5s"));
+/// assert_eq!(None, Duration::parse("1:60:00"));
+///
+/// // Formatting back into a clock value
+/// assert_eq!("1:02:03.5", duration.to_string());
+/// assert_eq!("0:00:12", Duration::parse("12s").unwrap().to_string());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Duration(time::Duration);
+
+impl Duration {
+    /// Parse a clock value, which is either:
+    /// - A full clock value, such as `1:02:03.5`.
+    /// - A partial clock value, such as `02:03.5`.
+    /// - A timecount value with an optional metric of `h`, `min`, `s`,
+    ///   or `ms`, such as `3.5min` or `500ms`. Values without a metric
+    ///   are in seconds.
+    ///
+    /// Returns [None] if the value is not a valid clock value.
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        let parts: Vec<_> = value.split(':').collect();
+        let (hours, minutes, seconds) = match parts[..] {
+            [hours, minutes, seconds] => (parse_digits(hours)?, minutes, seconds),
+            [minutes, seconds] => (0, minutes, seconds),
+            _ => return parse_timecount(value).map(Self),
+        };
+        let minutes = parse_digits(minutes).filter(|minutes| *minutes < 60)?;
+        let (seconds, nanos) = parse_seconds(seconds).filter(|(seconds, _)| *seconds < 60)?;
+
+        let seconds = hours
+            .checked_mul(3600)?
+            .checked_add(minutes * 60 + seconds)?;
+        Some(Self(time::Duration::new(seconds, nanos)))
+    }
+
+    /// Retrieve the duration in seconds.
+    pub fn as_secs_f64(&self) -> f64 {
+        self.0.as_secs_f64()
+    }
+
+    /// Retrieve the duration as a [std::time::Duration].
+    pub fn as_std(&self) -> time::Duration {
+        self.0
+    }
+}
+
+impl Display for Duration {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        let seconds = self.0.as_secs();
+        write!(
+            fmt,
+            "{}:{:02}:{:02}",
+            seconds / 3600,
+            seconds % 3600 / 60,
+            seconds % 60
+        )?;
+
+        match self.0.subsec_nanos() {
+            0 => Ok(()),
+            nanos => {
+                let fraction = format!("{nanos:09}");
+                write!(fmt, ".{}", fraction.trim_end_matches('0'))
+            }
+        }
+    }
+}
+
+impl From<time::Duration> for Duration {
+    fn from(duration: time::Duration) -> Self {
+        Self(duration)
+    }
+}
+
+impl From<Duration> for time::Duration {
+    fn from(duration: Duration) -> Self {
+        duration.0
+    }
+}
+
+// Parse a non-empty sequence of ascii digits
+fn parse_digits(value: &str) -> Option<u64> {
+    match !value.is_empty() && value.bytes().all(|byte| byte.is_ascii_digit()) {
+        true => value.parse().ok(),
+        false => None,
+    }
+}
+
+// Parse a timecount value with an optional metric, such as `3.5min`
+fn parse_timecount(value: &str) -> Option<time::Duration> {
+    const NANOS_PER_SEC: u128 = 1_000_000_000;
+    let metrics = [
+        ("ms", NANOS_PER_SEC / 1000),
+        ("min", NANOS_PER_SEC * 60),
+        ("h", NANOS_PER_SEC * 3600),
+        ("s", NANOS_PER_SEC),
+    ];
+
+    let (number, nanos_per_unit) = metrics
+        .into_iter()
+        .find_map(|(metric, nanos)| value.strip_suffix(metric).map(|number| (number, nanos)))
+        .unwrap_or((value, NANOS_PER_SEC));
+    let (whole, fraction) = parse_seconds(number)?;
+
+    let nanos =
+        u128::from(whole) * nanos_per_unit + u128::from(fraction) * nanos_per_unit / NANOS_PER_SEC;
+    let seconds = u64::try_from(nanos / NANOS_PER_SEC).ok()?;
+
+    Some(time::Duration::new(seconds, (nanos % NANOS_PER_SEC) as u32))
+}
+
+// Parse seconds with an optional fraction, such as `03.5`,
+// into whole seconds and nanoseconds
+fn parse_seconds(value: &str) -> Option<(u64, u32)> {
+    let (seconds, fraction) = value.split_once('.').unwrap_or((value, ""));
+    let seconds = parse_digits(seconds)?;
+
+    if fraction.is_empty() {
+        return (!value.ends_with('.')).then_some((seconds, 0));
+    } else if !fraction.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+
+    // Digits beyond nanosecond precision are truncated
+    let digits = &fraction[..fraction.len().min(9)];
+    let nanos = format!("{digits:0<9}").parse().ok()?;
+
+    Some((seconds, nanos))
+}
@@ -1,7 +1,10 @@
 use std::borrow::Borrow;
 
-use crate::formats::epub::constants;
-use crate::formats::xml::{self, Element, Find};
+use crate::formats::epub::{
+    constants, sorting, title, LocalizedMetadata, MediaMetadata, TextDirection, TitleKind,
+    TitleStyle, VendorMetadata,
+};
+use crate::formats::xml::{self, utility as xmlutil, Attribute, Element, Find};
 use crate::utility::{self, Shared};
 
 /// Retrieve associated metadata information about the epub.
@@ -83,6 +86,63 @@ impl Metadata {
             .expect("Package should have an epub 'version' attribute")
     }
 
+    /// Retrieve the base text direction of the ebook, specified by the
+    /// `dir` attribute of the root package element.
+    ///
+    /// Returns [None] if the attribute is absent or not recognized.
+    pub fn text_direction(&self) -> Option<TextDirection> {
+        self.package
+            .get_attribute(constants::DIR)
+            .and_then(TextDirection::from_dir)
+    }
+
+    /// Retrieve the text direction of a metadata element, specified by
+    /// its `dir` attribute or inherited from the root package element.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// # let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    /// let metadata = epub.metadata();
+    /// let title = metadata.title().unwrap();
+    ///
+    /// // Neither the title nor the package specify a direction
+    /// assert_eq!(None, metadata.text_direction());
+    /// assert_eq!(None, metadata.text_direction_of(title));
+    /// ```
+    pub fn text_direction_of(&self, element: &Element) -> Option<TextDirection> {
+        element
+            .get_attribute(constants::DIR)
+            .and_then(TextDirection::from_dir)
+            .or_else(|| self.text_direction())
+    }
+
+    /// Retrieve all the attributes of the root package element
+    pub fn package_attributes(&self) -> &[Attribute] {
+        self.package.attributes()
+    }
+
+    /// Retrieve the attributes of the root package element that rbook
+    /// does not model, such as `xml:base` or custom attributes.
+    ///
+    /// The `version`, `unique-identifier`, `prefix`, `xml:lang`, `dir`,
+    /// and `id` attributes, along with namespace declarations,
+    /// are excluded.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// # let epub = rbook::Epub::new("tests/ebooks/example_epub").unwrap();
+    /// assert!(epub.metadata().extra_package_attributes().is_empty());
+    /// assert!(epub.manifest().extra_attributes().is_empty());
+    /// assert!(epub.spine().extra_attributes().is_empty());
+    /// ```
+    pub fn extra_package_attributes(&self) -> Vec<&Attribute> {
+        xmlutil::extra_attributes(self.package.attributes(), &constants::PACKAGE_ATTRIBUTES)
+    }
+
     // Convenient DCMES Required Metadata methods
     // Although rare, some epubs may not contain the metadata.
     // Having them as optional broadens support.
@@ -94,6 +154,61 @@ impl Metadata {
         self.get_element(constants::TITLE)
     }
 
+    /// Retrieve all titles of a certain kind, such as subtitles,
+    /// ordered by their `display-seq` if specified.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// use rbook::epub::TitleKind;
+    ///
+    /// # let epub = rbook::Epub::new("tests/ebooks/example_epub").unwrap();
+    /// let main = epub.metadata().titles_by_kind(TitleKind::Main);
+    ///
+    /// assert_eq!("Directory Example", main[0].value());
+    /// assert!(epub.metadata().titles_by_kind(TitleKind::Subtitle).is_empty());
+    /// ```
+    pub fn titles_by_kind(&self, kind: TitleKind) -> Vec<&Element> {
+        self.sorted_titles()
+            .into_iter()
+            .filter(|title| TitleKind::from_title(title) == kind)
+            .collect()
+    }
+
+    /// Assemble a display title from the main title, subtitles,
+    /// edition, and collection titles according to a [TitleStyle].
+    ///
+    /// Titles are ordered by their `display-seq` if specified.
+    /// If there is no main title, the first title is used instead.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// use rbook::epub::TitleStyle;
+    ///
+    /// # let epub = rbook::Epub::new("tests/ebooks/example_epub").unwrap();
+    /// let title = epub.metadata().display_title(TitleStyle::Full).unwrap();
+    ///
+    /// assert_eq!("Directory Example", title);
+    /// ```
+    pub fn display_title(&self, style: TitleStyle) -> Option<String> {
+        title::display_title(&self.sorted_titles(), style)
+    }
+
+    // Titles ordered by their display sequence, if specified
+    fn sorted_titles(&self) -> Vec<&Element> {
+        let mut titles = self.get_elements(constants::TITLE);
+        titles.sort_by_key(|title| {
+            title
+                .get_child(constants::DISPLAY_SEQ)
+                .and_then(|sequence| sequence.value().trim().parse::<usize>().ok())
+                .unwrap_or(usize::MAX)
+        });
+        titles
+    }
+
     /// Language the ebook supports.
     ///
     /// If the ebook contains multiple languages, using the method
@@ -166,6 +281,89 @@ impl Metadata {
         self.get_elements(constants::CREATOR)
     }
 
+    /// Creators of the ebook ordered by their [sort key](Self::sort_key),
+    /// such as for listing authors alphabetically by their last name.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// # let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    /// let creators = epub.metadata().sorted_creators();
+    ///
+    /// assert_eq!("Herman Melville", creators[0].value());
+    /// ```
+    pub fn sorted_creators(&self) -> Vec<&Element> {
+        let mut creators: Vec<_> = self
+            .creators()
+            .into_iter()
+            .map(|creator| (self.sort_key(creator), creator))
+            .collect();
+
+        creators.sort_by(|(a, _), (b, _)| a.cmp(b));
+        creators.into_iter().map(|(_, creator)| creator).collect()
+    }
+
+    /// Retrieve the form of a metadata element used for sorting,
+    /// such as `Melville, Herman`.
+    ///
+    /// The `file-as` refinement, or the epub 2 `opf:file-as` attribute,
+    /// is preferred. For the primary title, the `calibre:title_sort`
+    /// entry is considered next. Otherwise, the form is derived from
+    /// the value:
+    /// - Creators and contributors: The last word is moved to the front,
+    ///   i.e. `Herman Melville` becomes `Melville, Herman`.
+    ///   Names that contain a comma or no spaces are kept as is.
+    /// - Titles: A leading English article is moved to the end,
+    ///   i.e. `The Whale` becomes `Whale, The`.
+    /// - Other elements: The trimmed value.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// # let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    /// let metadata = epub.metadata();
+    /// let creator = metadata.creators()[0];
+    ///
+    /// assert_eq!("MELVILLE, HERMAN", metadata.sort_name(creator));
+    /// ```
+    pub fn sort_name(&self, element: &Element) -> String {
+        let file_as = element
+            .get_child(constants::FILE_AS)
+            .map(Element::value)
+            .or_else(|| element.get_attribute(constants::FILE_AS))
+            .map(str::trim)
+            .filter(|file_as| !file_as.is_empty());
+
+        if let Some(file_as) = file_as {
+            return file_as.to_string();
+        }
+
+        let name = element.name();
+        let name = name.rsplit(':').next().unwrap_or(name);
+        match name {
+            constants::CREATOR | constants::CONTRIBUTOR => sorting::name(element.value()),
+            constants::TITLE => self
+                .title()
+                .filter(|title| std::ptr::eq(*title, element))
+                .and_then(|_| self.vendor().calibre_title_sort())
+                .map(|title_sort| title_sort.trim().to_string())
+                .unwrap_or_else(|| sorting::title(element.value())),
+            _ => element.value().trim().to_string(),
+        }
+    }
+
+    /// Retrieve the key used to sort a metadata element, which is its
+    /// [sort name](Self::sort_name) ignoring case and differences
+    /// in whitespace.
+    ///
+    /// Keys are compared by unicode code point, which may not match
+    /// the alphabetical order of languages with accented characters.
+    pub fn sort_key(&self, element: &Element) -> String {
+        sorting::key(&self.sort_name(element))
+    }
+
     /// The date of publication date for an ebook
     pub fn date(&self) -> Option<&Element> {
         self.get_element(constants::DATE)
@@ -196,6 +394,17 @@ impl Metadata {
         self.get_elements(constants::TYPE)
     }
 
+    /// Check if the ebook is an epub dictionary, indicated by a
+    /// `type` metadata element with the value of `dictionary`.
+    ///
+    /// Search key maps of a dictionary can be retrieved using
+    /// [search_key_maps()](super::Epub::search_key_maps).
+    pub fn is_dictionary(&self) -> bool {
+        self.r#type()
+            .iter()
+            .any(|element| element.value() == constants::DICTIONARY)
+    }
+
     /// Retrieve the name and id values of the cover meta
     /// element. The retrieved id from this function can
     /// also be used to retrieve the image path by using
@@ -205,6 +414,257 @@ impl Metadata {
         self.get_element(constants::COVER)
     }
 
+    /// Retrieve the prefix mappings declared using the `prefix`
+    /// attribute of the package element, in the form of
+    /// `(prefix, IRI)` pairs.
+    ///
+    /// Reserved prefixes, such as `dcterms` and `marc`, are not
+    /// included unless explicitly declared. To resolve any prefix,
+    /// [prefix_iri(...)](Self::prefix_iri) can be used instead.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    /// let prefixes = epub.metadata().prefixes();
+    ///
+    /// assert_eq!(vec![("cc", "http://creativecommons.org/ns#")], prefixes);
+    /// ```
+    pub fn prefixes(&self) -> Vec<(&str, &str)> {
+        let mut prefixes = Vec::new();
+        let mut tokens = self
+            .package
+            .get_attribute(constants::PREFIX)
+            .unwrap_or_default()
+            .split_whitespace();
+
+        // Format: `prefix1: IRI1 prefix2: IRI2 ...`
+        while let Some(token) = tokens.next() {
+            if let (Some(prefix), Some(iri)) = (token.strip_suffix(':'), tokens.next()) {
+                prefixes.push((prefix, iri));
+            }
+        }
+
+        prefixes
+    }
+
+    /// Retrieve the IRI associated with a prefix. Prefixes declared
+    /// by the package take priority over reserved prefixes.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// # let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    /// let metadata = epub.metadata();
+    ///
+    /// // Declared prefix
+    /// assert_eq!(Some("http://creativecommons.org/ns#"), metadata.prefix_iri("cc"));
+    /// // Reserved prefix
+    /// assert_eq!(Some("http://purl.org/dc/terms/"), metadata.prefix_iri("dcterms"));
+    /// // Unknown prefix
+    /// assert_eq!(None, metadata.prefix_iri("acme"));
+    /// ```
+    pub fn prefix_iri(&self, prefix: &str) -> Option<&str> {
+        self.prefixes()
+            .into_iter()
+            .chain(constants::RESERVED_PREFIXES)
+            .find(|(name, _)| *name == prefix)
+            .map(|(_, iri)| iri)
+    }
+
+    /// Expand a prefixed property, such as `dcterms:modified`, into its
+    /// full IRI.
+    ///
+    /// Returns [None] if the property is not prefixed or if the prefix
+    /// is neither declared nor reserved.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// # let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    /// let metadata = epub.metadata();
+    ///
+    /// assert_eq!(
+    ///     "http://creativecommons.org/ns#attributionURL",
+    ///     metadata.expand_property("cc:attributionURL").unwrap()
+    /// );
+    /// assert_eq!(
+    ///     "http://purl.org/dc/terms/modified",
+    ///     metadata.expand_property("dcterms:modified").unwrap()
+    /// );
+    /// ```
+    pub fn expand_property(&self, property: &str) -> Option<String> {
+        let (prefix, reference) = utility::split_where(property.trim(), ':')?;
+        self.prefix_iri(prefix)
+            .map(|iri| iri.to_string() + reference)
+    }
+
+    /// Retrieve the full IRI of the property of a metadata element,
+    /// such as `http://purl.org/dc/terms/modified` for
+    /// `<meta property="dcterms:modified">`.
+    ///
+    /// `dc:*` elements, such as `dc:title`, belong to the Dublin Core
+    /// elements vocabulary, and `meta` properties without a prefix
+    /// belong to the default vocabulary of the package. Legacy `meta`
+    /// elements use their `name` attribute.
+    ///
+    /// Returns [None] if the property uses a prefix that is neither
+    /// declared nor reserved.
+    pub fn property_iri(&self, element: &Element) -> Option<String> {
+        match raw_property(element) {
+            Some(property) => self.expand_query(property),
+            None => Some(constants::DC_VOCABULARY.to_string() + element.name()),
+        }
+    }
+
+    /// Check if the property of a metadata element matches the given
+    /// property, such as `dcterms:modified`, by comparing their
+    /// [full IRIs](Self::property_iri) rather than their text.
+    ///
+    /// Differently named prefixes that are declared with the same IRI
+    /// match, as do properties with or without the prefix of a default
+    /// vocabulary. The given property may also be a full IRI. If either
+    /// property cannot be expanded, their text is compared instead.
+    ///
+    /// # Examples
+    /// Matching a property declared with a nonstandard prefix:
+    /// ```
+    /// # use rbook::Ebook;
+    /// # let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    /// let metadata = epub.metadata();
+    /// let modified = metadata.modified().unwrap();
+    /// let title = metadata.title().unwrap();
+    ///
+    /// assert!(metadata.matches_property(modified, "dcterms:modified"));
+    /// assert!(metadata.matches_property(modified, "http://purl.org/dc/terms/modified"));
+    /// assert!(metadata.matches_property(title, "dc:title"));
+    /// assert!(!metadata.matches_property(title, "dcterms:title"));
+    /// ```
+    pub fn matches_property(&self, element: &Element, property: &str) -> bool {
+        let expected = match property.contains("://") {
+            true => Some(property.trim().to_string()),
+            false => self.expand_query(property),
+        };
+
+        match (self.property_iri(element), expected) {
+            (Some(iri), Some(expected)) => iri == expected,
+            _ => raw_property(element).is_some_and(|actual| actual.trim() == property.trim()),
+        }
+    }
+
+    // Expand a property into its full IRI, including properties
+    // of the `dc` and default vocabularies
+    fn expand_query(&self, property: &str) -> Option<String> {
+        let property = property.trim();
+
+        match utility::split_where(property, ':') {
+            Some((constants::DC_PREFIX, reference))
+                if self.prefix_iri(constants::DC_PREFIX).is_none() =>
+            {
+                Some(constants::DC_VOCABULARY.to_string() + reference)
+            }
+            Some(_) => self.expand_property(property),
+            None => Some(constants::META_VOCABULARY.to_string() + property),
+        }
+    }
+
+    /// Retrieve the rating of the ebook on a scale from `0` to `5`.
+    ///
+    /// The `calibre:rating` entry takes priority, which is stored on a
+    /// scale from `0` to `10` and converted accordingly. Otherwise, any
+    /// other `rating` entry is used, where values greater than `5` are
+    /// assumed to be on a scale from `0` to `10`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// let epub = rbook::Epub::new("tests/ebooks/vendor_epub").unwrap();
+    ///
+    /// // `calibre:rating` of 8 out of 10
+    /// assert_eq!(Some(4.0), epub.metadata().rating());
+    /// ```
+    pub fn rating(&self) -> Option<f64> {
+        let rating = match self.vendor().calibre_rating() {
+            Some(rating) => rating / 2.0,
+            None => {
+                let rating: f64 = self
+                    .get_elements(constants::RATING)
+                    .into_iter()
+                    .find_map(|element| element.value().trim().parse().ok())?;
+
+                match rating > 5.0 {
+                    true => rating / 2.0,
+                    false => rating,
+                }
+            }
+        };
+
+        Some(rating.clamp(0.0, 5.0))
+    }
+
+    /// Retrieve the intended audiences of the ebook from the
+    /// `dcterms:audience` entries, such as `Educators`.
+    pub fn audience(&self) -> Vec<&Element> {
+        self.get_elements(constants::AUDIENCE)
+    }
+
+    /// Retrieve the classifications of all `subject` elements with a
+    /// recognized authority. See [Classification](super::Classification)
+    /// for more details.
+    #[cfg(feature = "classification")]
+    pub fn classifications(&self) -> Vec<super::Classification> {
+        self.subject()
+            .into_iter()
+            .filter_map(super::Classification::from_subject)
+            .collect()
+    }
+
+    /// Retrieve the metadata of the ebook in a specific language, such
+    /// as `fr`, resolved using `xml:lang` attributes and
+    /// `alternate-script` refinements. See [LocalizedMetadata] for
+    /// more details.
+    pub fn localized(&self, language: &str) -> LocalizedMetadata<'_> {
+        LocalizedMetadata::new(self, language)
+    }
+
+    /// Retrieve the media overlay entries of the ebook, such as the
+    /// total `media:duration` of all media overlays and the
+    /// `media:active-class`. Entries that refine other elements are
+    /// excluded. See [MediaMetadata] for more details.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    /// let media = epub.metadata().media();
+    ///
+    /// // Moby Dick does not contain media overlays
+    /// assert_eq!(None, media.duration());
+    /// assert_eq!(None, media.active_class());
+    /// ```
+    pub fn media(&self) -> MediaMetadata<'_> {
+        MediaMetadata::new(self.elements())
+    }
+
+    /// Retrieve proprietary entries of well-known vendors, such as
+    /// `calibre:series`. See [VendorMetadata] for more details.
+    pub fn vendor(&self) -> VendorMetadata<'_> {
+        VendorMetadata::new(self)
+    }
+
+    /// Retrieve the entries of a custom vocabulary as a typed value.
+    /// Returns [None] if the entries cannot be parsed.
+    /// See [Vocabulary](super::Vocabulary) for more details.
+    pub fn custom<V: super::Vocabulary>(&self) -> Option<V> {
+        let elements = self.vendor().entries(V::PREFIX);
+        V::parse(&super::VocabularyEntries::new(self, V::PREFIX, elements))
+    }
+
     /// Retrieve metadata fields not explicitly provided by the API.
     ///
     /// Prefixes/namespaces for metadata entries are ignored.
@@ -241,6 +701,16 @@ impl Metadata {
     }
 }
 
+// Retrieve the `property` of a `meta` element, or the `name` of a legacy `meta` element
+fn raw_property(element: &Element) -> Option<&str> {
+    element.get_attribute(constants::PROPERTY).or_else(|| {
+        element
+            .contains_attribute(constants::LEGACY_FEATURE)
+            .then(|| element.get_attribute(constants::NAME))
+            .flatten()
+    })
+}
+
 impl Find for Metadata {
     fn __find_fallback(&self, field: &str, is_wildcard: bool) -> Vec<&Element> {
         match is_wildcard {
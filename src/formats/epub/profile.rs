@@ -0,0 +1,169 @@
+use crate::formats::epub::constants;
+use crate::formats::xml::{utility as xmlutil, Element};
+
+// Core media types that all epub 3 reading systems support
+const CORE_MEDIA_TYPES: [&str; 23] = [
+    "application/xhtml+xml",
+    "application/x-dtbncx+xml",
+    "application/smil+xml",
+    "application/pls+xml",
+    "application/javascript",
+    "application/ecmascript",
+    "text/javascript",
+    "text/css",
+    "image/gif",
+    "image/jpeg",
+    "image/png",
+    "image/svg+xml",
+    "image/webp",
+    "audio/mpeg",
+    "audio/mp4",
+    "audio/ogg",
+    "font/ttf",
+    "font/otf",
+    "font/woff",
+    "font/woff2",
+    "application/font-sfnt",
+    "application/font-woff",
+    "application/vnd.ms-opentype",
+];
+
+/// Capabilities of a target reading system, such as an e-reader,
+/// used to simulate how it resolves the resources of an epub.
+///
+/// Profiles are provided to [Manifest::resolve](super::Manifest::resolve)
+/// to select the fallback of a resource that the reading system
+/// supports, and to
+/// [EpubReaderSettings::profile](super::EpubReaderSettings::profile)
+/// to traverse the spine entries the reading system would display.
+///
+/// # Examples
+/// Resolving a resource for a device without svg support:
+/// ```
+/// use rbook::Ebook;
+/// use rbook::epub::ReadingSystemProfile;
+///
+/// let epub = rbook::Epub::new("tests/ebooks/fallback_epub").unwrap();
+/// let profile = ReadingSystemProfile::new().unsupported_media_type("image/svg+xml");
+///
+/// let chart = epub.manifest().by_id("chart").unwrap();
+/// let resolved = epub.manifest().resolve(chart, &profile).unwrap();
+///
+/// assert_eq!("chart-image", resolved.name());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReadingSystemProfile {
+    media_types: Vec<String>,
+    scripting: bool,
+    fixed_layout: bool,
+    skip_non_linear: bool,
+}
+
+impl ReadingSystemProfile {
+    /// Create a profile of a reading system that supports the core
+    /// media types of epub 3, scripting, and fixed layouts, while
+    /// displaying non-linear content.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace the supported media types, such as `image/png`.
+    pub fn media_types<I, S>(mut self, media_types: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.media_types = media_types.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Add a supported media type, such as `video/mp4`.
+    pub fn supported_media_type(mut self, media_type: &str) -> Self {
+        if !self.supports_media_type(media_type) {
+            self.media_types.push(media_type.to_string());
+        }
+        self
+    }
+
+    /// Remove a supported media type, such as `image/svg+xml`.
+    pub fn unsupported_media_type(mut self, media_type: &str) -> Self {
+        self.media_types
+            .retain(|supported| !media_type_eq(supported, media_type));
+        self
+    }
+
+    /// Support resources with the `scripted` property. Default is `true`.
+    pub fn scripting(mut self, scripting: bool) -> Self {
+        self.scripting = scripting;
+        self
+    }
+
+    /// Support pre-paginated (fixed layout) spine entries.
+    /// Default is `true`.
+    pub fn fixed_layout(mut self, fixed_layout: bool) -> Self {
+        self.fixed_layout = fixed_layout;
+        self
+    }
+
+    /// Skip spine entries marked as non-linear (`linear="no"`),
+    /// as some reading systems do. Default is `false`.
+    pub fn skip_non_linear(mut self, skip_non_linear: bool) -> Self {
+        self.skip_non_linear = skip_non_linear;
+        self
+    }
+
+    /// Check if a media type is supported. Parameters, such as
+    /// `; charset=utf-8`, and case are ignored.
+    pub fn supports_media_type(&self, media_type: &str) -> bool {
+        self.media_types
+            .iter()
+            .any(|supported| media_type_eq(supported, media_type))
+    }
+
+    /// Check if a manifest element is supported, considering its
+    /// media type and whether it is `scripted`.
+    pub fn supports(&self, element: &Element) -> bool {
+        let is_scripted = || {
+            xmlutil::equals_attribute_by_value(
+                element,
+                constants::PROPERTIES,
+                constants::SCRIPTED_PROPERTY,
+            )
+        };
+
+        element
+            .get_attribute(constants::MEDIA_TYPE)
+            .is_some_and(|media_type| self.supports_media_type(media_type))
+            && (self.scripting || !is_scripted())
+    }
+
+    #[cfg(feature = "reader")]
+    pub(super) fn supports_fixed_layout(&self) -> bool {
+        self.fixed_layout
+    }
+
+    #[cfg(feature = "reader")]
+    pub(super) fn skips_non_linear(&self) -> bool {
+        self.skip_non_linear
+    }
+}
+
+impl Default for ReadingSystemProfile {
+    fn default() -> Self {
+        Self {
+            media_types: CORE_MEDIA_TYPES.iter().map(ToString::to_string).collect(),
+            scripting: true,
+            fixed_layout: true,
+            skip_non_linear: false,
+        }
+    }
+}
+
+// Compare media types ignoring case and parameters
+fn media_type_eq(a: &str, b: &str) -> bool {
+    essence(a).eq_ignore_ascii_case(essence(b))
+}
+
+fn essence(media_type: &str) -> &str {
+    media_type.split(';').next().unwrap_or_default().trim()
+}
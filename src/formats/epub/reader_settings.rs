@@ -0,0 +1,195 @@
+use std::fmt::{Debug, Formatter};
+
+use crate::formats::epub::{constants, ReadingSystemProfile};
+use crate::formats::xml::{utility as xmlutil, Element};
+use crate::reader::ErrorBehavior;
+
+type ContentFilter = Box<dyn Fn(&Element, &Element) -> bool>;
+
+/// Settings to customize the [Reader](crate::Reader) of an epub,
+/// which are provided to [Epub::reader_with](super::Epub::reader_with).
+///
+/// Filters determine which spine entries the reader traverses before
+/// any content is retrieved. Entries that are filtered out are skipped
+/// entirely, meaning reader indexes only count the remaining entries.
+///
+/// # Examples
+/// Skipping scripted content:
+/// ```
+/// use rbook::Ebook;
+/// use rbook::epub::EpubReaderSettings;
+///
+/// let epub = rbook::Epub::new("tests/ebooks/fallback_epub").unwrap();
+///
+/// let settings = EpubReaderSettings::new().skip_property("scripted");
+/// let reader = epub.reader_with(settings);
+///
+/// assert_eq!(4, epub.reader().page_count());
+/// assert_eq!(3, reader.page_count());
+/// ```
+#[derive(Default)]
+pub struct EpubReaderSettings {
+    xhtml_only: bool,
+    skipped_properties: Vec<String>,
+    content_filter: Option<ContentFilter>,
+    pub(super) profile: Option<ReadingSystemProfile>,
+    pub(super) on_error: ErrorBehavior,
+    #[cfg(feature = "multi-thread")]
+    pub(super) prefetch: usize,
+}
+
+impl EpubReaderSettings {
+    /// Create settings with default values, equivalent to the
+    /// settings used by [Epub::reader](super::Epub::reader).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only traverse spine entries with the
+    /// `application/xhtml+xml` media type, such as skipping
+    /// SVG-only pages. Default is `false`.
+    pub fn xhtml_only(mut self, xhtml_only: bool) -> Self {
+        self.xhtml_only = xhtml_only;
+        self
+    }
+
+    /// Skip spine entries where either the `itemref` element or the
+    /// referenced manifest element contains the given property, such
+    /// as `scripted`. May be called multiple times to skip several
+    /// properties.
+    pub fn skip_property(mut self, property: &str) -> Self {
+        self.skipped_properties.push(property.to_string());
+        self
+    }
+
+    /// Only traverse spine entries that satisfy a predicate, which is
+    /// given the spine `itemref` element and the referenced manifest
+    /// element. Applies in addition to the other filters.
+    ///
+    /// # Examples
+    /// Skipping non-linear content:
+    /// ```
+    /// # use rbook::Ebook;
+    /// # use rbook::epub::EpubReaderSettings;
+    /// # let epub = rbook::Epub::new("tests/ebooks/example_epub").unwrap();
+    /// let settings = EpubReaderSettings::new()
+    ///     .content_filter(|spine_element, _| spine_element.get_attribute("linear") != Some("no"));
+    /// let reader = epub.reader_with(settings);
+    ///
+    /// assert_eq!(3, reader.page_count());
+    /// ```
+    pub fn content_filter<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(&Element, &Element) -> bool + 'static,
+    {
+        self.content_filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Only traverse spine entries that a reading system would display,
+    /// simulating its capabilities. Entries are skipped if no element of
+    /// their [fallback chain](super::Manifest::fallback_chain) is
+    /// supported, if they are pre-paginated and fixed layouts are not
+    /// supported, or if they are non-linear and the profile skips
+    /// non-linear content.
+    ///
+    /// The content of an entry is that of its manifest element, which
+    /// may be a fallback that the profile does not support. Use
+    /// [Manifest::resolve](super::Manifest::resolve) to retrieve the
+    /// supported element.
+    ///
+    /// # Examples
+    /// Simulating a reading system without scripting:
+    /// ```
+    /// # use rbook::Ebook;
+    /// use rbook::epub::{EpubReaderSettings, ReadingSystemProfile};
+    ///
+    /// # let epub = rbook::Epub::new("tests/ebooks/fallback_epub").unwrap();
+    /// let profile = ReadingSystemProfile::new().scripting(false).skip_non_linear(true);
+    /// let reader = epub.reader_with(EpubReaderSettings::new().profile(profile));
+    ///
+    /// // The cover is non-linear and `c2` is scripted
+    /// assert_eq!(2, reader.page_count());
+    /// ```
+    pub fn profile(mut self, profile: ReadingSystemProfile) -> Self {
+        self.profile = Some(profile);
+        self
+    }
+
+    /// Set the behavior of the reader when the content of a spine entry
+    /// cannot be retrieved, such as a missing file.
+    /// Default is [ErrorBehavior::Stop].
+    pub fn on_error(mut self, on_error: ErrorBehavior) -> Self {
+        self.on_error = on_error;
+        self
+    }
+
+    /// Set the number of pages after the current index to load on a
+    /// background thread, reducing the latency of turning pages when a
+    /// page is retrieved shortly after. Default is `0`, which disables
+    /// prefetching.
+    ///
+    /// Prefetched pages are updated whenever the reader's current index
+    /// changes. Pages that fall outside the prefetch range are discarded.
+    /// Pages that fail to load in the background are loaded again upon
+    /// retrieval.
+    ///
+    /// # Examples
+    /// Prefetching the next two pages:
+    /// ```
+    /// # use rbook::Ebook;
+    /// use rbook::epub::EpubReaderSettings;
+    ///
+    /// # let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    /// let mut reader = epub.reader_with(EpubReaderSettings::new().prefetch(2));
+    ///
+    /// // The content of pages 1 and 2 is loaded in the background
+    /// let content = reader.next_page().unwrap().unwrap();
+    /// assert_eq!(reader.fetch_page(1).unwrap().unwrap(), content);
+    /// ```
+    #[cfg(feature = "multi-thread")]
+    pub fn prefetch(mut self, count: usize) -> Self {
+        self.prefetch = count;
+        self
+    }
+
+    // Check if the reader traverses a spine entry
+    pub(super) fn includes(&self, spine_element: &Element, manifest_element: &Element) -> bool {
+        let is_xhtml =
+            || manifest_element.get_attribute(constants::MEDIA_TYPE) == Some(constants::XHTML_TYPE);
+        let is_skipped = || {
+            self.skipped_properties.iter().any(|property| {
+                xmlutil::equals_attribute_by_value(spine_element, constants::PROPERTIES, property)
+                    || xmlutil::equals_attribute_by_value(
+                        manifest_element,
+                        constants::PROPERTIES,
+                        property,
+                    )
+            })
+        };
+
+        (!self.xhtml_only || is_xhtml())
+            && !is_skipped()
+            && match &self.content_filter {
+                Some(filter) => filter(spine_element, manifest_element),
+                None => true,
+            }
+    }
+}
+
+impl Debug for EpubReaderSettings {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut debug = fmt.debug_struct("EpubReaderSettings");
+        debug
+            .field("xhtml_only", &self.xhtml_only)
+            .field("skipped_properties", &self.skipped_properties)
+            .field("content_filter", &self.content_filter.is_some())
+            .field("profile", &self.profile)
+            .field("on_error", &self.on_error);
+
+        #[cfg(feature = "multi-thread")]
+        debug.field("prefetch", &self.prefetch);
+
+        debug.finish()
+    }
+}
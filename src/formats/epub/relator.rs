@@ -0,0 +1,247 @@
+use crate::formats::epub::constants;
+use crate::formats::xml::Element;
+
+/// Role of a creator or contributor, identified by a MARC relator code,
+/// such as `aut` for author.
+///
+/// Codes are decoded into English display names, such as `Author`,
+/// using an embedded lookup table. The table covers the relator codes
+/// commonly found in ebooks. Codes that are not covered, or roles of
+/// other schemes, retain their raw code.
+///
+/// # Examples
+/// Displaying the roles of a creator:
+/// ```
+/// use rbook::Ebook;
+/// use rbook::epub::Relator;
+///
+/// let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+/// let creator = epub.metadata().creators()[0];
+/// let roles = Relator::from_contributor(creator);
+///
+/// assert_eq!("aut", roles[0].code());
+/// assert_eq!(Some("Author"), roles[0].name());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Relator {
+    code: String,
+    name: Option<&'static str>,
+}
+
+impl Relator {
+    /// Retrieve the roles of a `creator` or `contributor` metadata
+    /// element, in the order they appear.
+    ///
+    /// Roles are retrieved from refining `role` elements with either no
+    /// scheme or the `marc:relators` scheme. For epub 2, the `opf:role`
+    /// attribute is used if there are no refining `role` elements.
+    pub fn from_contributor(contributor: &Element) -> Vec<Self> {
+        let roles: Vec<_> = contributor
+            .children()
+            .into_iter()
+            .filter(|child| child.name() == constants::ROLE)
+            .filter(|role| match role.get_attribute(constants::SCHEME) {
+                Some(scheme) => scheme.eq_ignore_ascii_case(MARC_RELATORS),
+                None => true,
+            })
+            .map(|role| Self::new(role.value()))
+            .collect();
+
+        if roles.is_empty() {
+            contributor
+                .get_attribute(constants::ROLE)
+                .map(Self::new)
+                .into_iter()
+                .collect()
+        } else {
+            roles
+        }
+    }
+
+    /// Create a role from a MARC relator code, such as `ill`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// use rbook::epub::Relator;
+    ///
+    /// assert_eq!(Some("Translator"), Relator::new("TRL").name());
+    /// assert_eq!(None, Relator::new("xyz").name());
+    /// ```
+    pub fn new(code: &str) -> Self {
+        let code = code.trim().to_lowercase();
+        let name = RELATORS
+            .iter()
+            .find(|(key, _)| *key == code)
+            .map(|(_, name)| *name);
+
+        Self { code, name }
+    }
+
+    /// Retrieve the lowercase relator code, such as `aut`.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// Retrieve the English display name of the role, such as `Author`.
+    /// Returns [None] if the code is not covered by the lookup table.
+    pub fn name(&self) -> Option<&'static str> {
+        self.name
+    }
+
+    /// Retrieve the English display name of the role, or the raw code
+    /// if the code is not covered by the lookup table.
+    pub fn display_name(&self) -> &str {
+        self.name.unwrap_or(&self.code)
+    }
+
+    /// Retrieve a localized display name of the role.
+    ///
+    /// The `localize` hook receives the relator code and returns the
+    /// name in the target language. If the hook returns [None], the
+    /// [display name](Self::display_name) is used instead.
+    ///
+    /// # Examples
+    /// Localizing into French:
+    /// ```
+    /// use rbook::epub::Relator;
+    ///
+    /// let french = |code: &str| match code {
+    ///     "aut" => Some("Auteur".to_string()),
+    ///     _ => None,
+    /// };
+    ///
+    /// assert_eq!("Auteur", Relator::new("aut").localized_name(french));
+    /// assert_eq!("Illustrator", Relator::new("ill").localized_name(french));
+    /// ```
+    pub fn localized_name<F>(&self, localize: F) -> String
+    where
+        F: FnOnce(&str) -> Option<String>,
+    {
+        localize(&self.code).unwrap_or_else(|| self.display_name().to_string())
+    }
+}
+
+const MARC_RELATORS: &str = "marc:relators";
+
+const RELATORS: &[(&str, &str)] = &[
+    ("abr", "Abridger"),
+    ("acp", "Art copyist"),
+    ("act", "Actor"),
+    ("adi", "Art director"),
+    ("adp", "Adapter"),
+    ("aft", "Author of afterword, colophon, etc."),
+    ("anl", "Analyst"),
+    ("anm", "Animator"),
+    ("ann", "Annotator"),
+    ("ant", "Bibliographic antecedent"),
+    ("app", "Applicant"),
+    ("aqt", "Author in quotations or text abstracts"),
+    ("arc", "Architect"),
+    ("ard", "Artistic director"),
+    ("arr", "Arranger"),
+    ("art", "Artist"),
+    ("asg", "Assignee"),
+    ("asn", "Associated name"),
+    ("att", "Attributed name"),
+    ("auc", "Auctioneer"),
+    ("aud", "Author of dialog"),
+    ("aui", "Author of introduction, etc."),
+    ("aus", "Screenwriter"),
+    ("aut", "Author"),
+    ("bdd", "Binding designer"),
+    ("bjd", "Bookjacket designer"),
+    ("bkd", "Book designer"),
+    ("bkp", "Book producer"),
+    ("blw", "Blurb writer"),
+    ("bnd", "Binder"),
+    ("bpd", "Bookplate designer"),
+    ("bsl", "Bookseller"),
+    ("cll", "Calligrapher"),
+    ("clr", "Colorist"),
+    ("cmm", "Commentator"),
+    ("cmp", "Composer"),
+    ("cmt", "Compositor"),
+    ("cnd", "Conductor"),
+    ("cng", "Cinematographer"),
+    ("com", "Compiler"),
+    ("cov", "Cover designer"),
+    ("cph", "Copyright holder"),
+    ("cre", "Creator"),
+    ("ctb", "Contributor"),
+    ("ctg", "Cartographer"),
+    ("cur", "Curator"),
+    ("cwt", "Commentator for written text"),
+    ("dgs", "Degree supervisor"),
+    ("dnc", "Dancer"),
+    ("dnr", "Donor"),
+    ("drt", "Director"),
+    ("dsr", "Designer"),
+    ("dst", "Distributor"),
+    ("dte", "Dedicatee"),
+    ("dto", "Dedicator"),
+    ("dub", "Dubious author"),
+    ("edc", "Editor of compilation"),
+    ("edt", "Editor"),
+    ("egr", "Engraver"),
+    ("fmo", "Former owner"),
+    ("fnd", "Funder"),
+    ("his", "Host institution"),
+    ("hst", "Host"),
+    ("ill", "Illustrator"),
+    ("ilu", "Illuminator"),
+    ("ins", "Inscriber"),
+    ("isb", "Issuing body"),
+    ("itr", "Instrumentalist"),
+    ("ive", "Interviewee"),
+    ("ivr", "Interviewer"),
+    ("lbt", "Librettist"),
+    ("lse", "Licensee"),
+    ("lso", "Licensor"),
+    ("ltg", "Lithographer"),
+    ("lyr", "Lyricist"),
+    ("mfr", "Manufacturer"),
+    ("mrk", "Markup editor"),
+    ("mus", "Musician"),
+    ("nrt", "Narrator"),
+    ("oth", "Other"),
+    ("own", "Owner"),
+    ("pbd", "Publishing director"),
+    ("pbl", "Publisher"),
+    ("pfr", "Proofreader"),
+    ("pht", "Photographer"),
+    ("ppt", "Puppeteer"),
+    ("prd", "Production personnel"),
+    ("prf", "Performer"),
+    ("prg", "Programmer"),
+    ("pro", "Producer"),
+    ("prt", "Printer"),
+    ("pta", "Patent applicant"),
+    ("red", "Redaktor"),
+    ("res", "Researcher"),
+    ("rev", "Reviewer"),
+    ("rsp", "Respondent"),
+    ("sad", "Scientific advisor"),
+    ("scl", "Sculptor"),
+    ("scr", "Scribe"),
+    ("sng", "Singer"),
+    ("spk", "Speaker"),
+    ("spn", "Sponsor"),
+    ("std", "Set designer"),
+    ("stl", "Storyteller"),
+    ("tcd", "Technical director"),
+    ("ths", "Thesis advisor"),
+    ("trc", "Transcriber"),
+    ("trl", "Translator"),
+    ("tyd", "Type designer"),
+    ("tyg", "Typographer"),
+    ("vdg", "Videographer"),
+    ("voc", "Vocalist"),
+    ("wac", "Writer of added commentary"),
+    ("wal", "Writer of added lyrics"),
+    ("wam", "Writer of accompanying material"),
+    ("wat", "Writer of added text"),
+    ("win", "Writer of introduction"),
+    ("wpr", "Writer of preface"),
+    ("wst", "Writer of supplementary textual content"),
+];
@@ -0,0 +1,56 @@
+use crate::formats::epub::constants;
+use crate::formats::xml::{utility as xmlutil, Element};
+
+/// Key to retrieve a resource of an epub, such as a chapter, image,
+/// or stylesheet, using any of the identifiers provided by the package.
+///
+/// See [Epub::read_resource](super::Epub::read_resource) for usage.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Resource<'a> {
+    /// The `id` of a manifest element, i.e., `chapter_001`.
+    Id(&'a str),
+    /// The href of a resource, which follows the same path rules
+    /// as [Epub::read_file](super::Epub::read_file).
+    Href(&'a str),
+    /// The index of a spine element, which references a manifest element.
+    SpineIndex(usize),
+}
+
+/// Remote resources referenced by a content document of an epub,
+/// such as images or audio hosted on a web server.
+///
+/// Documents that reference remote resources must declare the
+/// `remote-resources` manifest property. See
+/// [Epub::remote_references](super::Epub::remote_references) for usage.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RemoteReferences<'a> {
+    element: &'a Element,
+    urls: Vec<String>,
+}
+
+impl<'a> RemoteReferences<'a> {
+    pub(super) fn new(element: &'a Element, urls: Vec<String>) -> Self {
+        Self { element, urls }
+    }
+
+    /// Retrieve the manifest element of the content document.
+    pub fn element(&self) -> &'a Element {
+        self.element
+    }
+
+    /// Retrieve the urls of the referenced remote resources,
+    /// in the order they first appear.
+    pub fn urls(&self) -> Vec<&str> {
+        self.urls.iter().map(String::as_str).collect()
+    }
+
+    /// Check if the content document declares the
+    /// `remote-resources` manifest property.
+    pub fn is_declared(&self) -> bool {
+        xmlutil::equals_attribute_by_value(
+            self.element,
+            constants::PROPERTIES,
+            constants::REMOTE_RESOURCES_PROPERTY,
+        )
+    }
+}
@@ -0,0 +1,321 @@
+use lol_html::html_content::Attribute as LolAttribute;
+
+use crate::formats::epub::decryption::Decryptor;
+use crate::formats::epub::ContentDecryptor;
+use crate::formats::xml::{self, Attribute};
+use crate::utility::{self, Shared};
+
+// Maximum size of files within epubs from untrusted sources
+const UNTRUSTED_MAX_FILE_SIZE: u64 = 64 * 1024 * 1024;
+
+/// Settings to customize how an [Epub](super::Epub) is opened and
+/// accessed. Settings are provided to
+/// [Epub::open_with](super::Epub::open_with) or
+/// [Epub::read_from_with](super::Epub::read_from_with).
+///
+/// # Examples
+/// Opening an epub with custom settings:
+/// ```
+/// use rbook::epub::EpubSettings;
+///
+/// let settings = EpubSettings::new().href_case_insensitive(true);
+/// let epub = rbook::Epub::open_with("tests/ebooks/moby-dick.epub", settings).unwrap();
+///
+/// // The case of the href does not match the archive
+/// let content = epub.read_file("CHAPTER_001.XHTML").unwrap();
+/// assert!(content.contains("Loomings"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct EpubSettings {
+    pub(super) href_case_insensitive: bool,
+    pub(super) normalize_backslashes: bool,
+    pub(super) follow_symlinks: bool,
+    pub(super) parse_level: ParseLevel,
+    pub(super) lazy_toc: bool,
+    pub(super) filename_decoding: FilenameDecoding,
+    pub(super) max_file_size: Option<u64>,
+    pub(super) decryptor: Option<Decryptor>,
+}
+
+impl EpubSettings {
+    /// Create settings with default values, equivalent to the
+    /// settings used by [Ebook::new](crate::Ebook::new).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create settings suited to epubs from untrusted sources, such as
+    /// user uploads, used by [Epub::open_untrusted](super::Epub::open_untrusted).
+    ///
+    /// Compared to the default settings:
+    /// - Symbolic links are not followed.
+    /// - Files larger than 64 MiB are rejected.
+    /// - Hrefs are matched ignoring case and backslashes are normalized,
+    ///   tolerating common malformations.
+    pub fn untrusted() -> Self {
+        Self::default()
+            .follow_symlinks(false)
+            .max_file_size(UNTRUSTED_MAX_FILE_SIZE)
+            .href_case_insensitive(true)
+            .normalize_backslashes(true)
+    }
+
+    /// Follow symbolic links within an unzipped epub directory.
+    /// Default is `true`.
+    ///
+    /// When disabled, retrieving a file through a symbolic link returns
+    /// an [UnsafePath](crate::result::ArchiveError::UnsafePath) error,
+    /// which is recommended when processing untrusted directories.
+    /// Paths that traverse outside the directory using `../` are always
+    /// rejected. This setting has no effect on zipped epubs.
+    pub fn follow_symlinks(mut self, follow_symlinks: bool) -> Self {
+        self.follow_symlinks = follow_symlinks;
+        self
+    }
+
+    /// Tolerate hrefs that differ in case from the files they reference,
+    /// i.e., `Images/Cover.JPG` and `images/cover.jpg`. Default is `false`.
+    ///
+    /// When enabled, exact matches are attempted first. Otherwise,
+    /// retrieving files and
+    /// [Manifest::by_href](super::Manifest::by_href) fallback to a
+    /// case-insensitive comparison instead of returning not-found.
+    pub fn href_case_insensitive(mut self, href_case_insensitive: bool) -> Self {
+        self.href_case_insensitive = href_case_insensitive;
+        self
+    }
+
+    /// Convert backslashes to forward slashes in the `href` and `src`
+    /// attributes of the package and table of contents, i.e.,
+    /// `images\cover.jpg` to `images/cover.jpg`. Default is `false`.
+    ///
+    /// Some generators emit Windows-style paths, which are not valid
+    /// epub hrefs and fail to resolve to files within the archive.
+    pub fn normalize_backslashes(mut self, normalize_backslashes: bool) -> Self {
+        self.normalize_backslashes = normalize_backslashes;
+        self
+    }
+
+    /// Set how much of an epub is parsed when it is opened.
+    /// Default is [ParseLevel::Full].
+    ///
+    /// # Examples
+    /// Opening an epub to only access its metadata:
+    /// ```
+    /// use rbook::epub::{EpubSettings, ParseLevel};
+    ///
+    /// let settings = EpubSettings::new().parse_level(ParseLevel::MetadataOnly);
+    /// let epub = rbook::Epub::open_with("tests/ebooks/moby-dick.epub", settings).unwrap();
+    ///
+    /// assert_eq!("Moby-Dick", epub.metadata().title().unwrap().value());
+    /// assert!(epub.manifest().elements().is_empty());
+    /// assert!(epub.toc().elements().is_empty());
+    /// ```
+    pub fn parse_level(mut self, parse_level: ParseLevel) -> Self {
+        self.parse_level = parse_level;
+        self
+    }
+
+    /// Defer parsing the table of contents until
+    /// [Epub::toc](super::Epub::toc) is first called. Default is `false`.
+    ///
+    /// Useful when the table of contents is not always required, such as
+    /// when only retrieving metadata or cover images. However, errors
+    /// from parsing the table of contents are no longer reported when
+    /// opening an epub.
+    ///
+    /// # Examples
+    /// Parsing the table of contents on demand:
+    /// ```
+    /// use rbook::epub::EpubSettings;
+    ///
+    /// let settings = EpubSettings::new().lazy_toc(true);
+    /// let epub = rbook::Epub::open_with("tests/ebooks/moby-dick.epub", settings).unwrap();
+    ///
+    /// // The table of contents is parsed here
+    /// assert_eq!(141, epub.toc().elements().len());
+    /// ```
+    pub fn lazy_toc(mut self, lazy_toc: bool) -> Self {
+        self.lazy_toc = lazy_toc;
+        self
+    }
+
+    /// Set how the names of files within a zipped epub are decoded.
+    /// Default is [FilenameDecoding::Auto].
+    ///
+    /// Files with names that were not stored as valid utf-8 are available
+    /// from [Epub::decoded_names](super::Epub::decoded_names).
+    /// This setting has no effect on unzipped epub directories.
+    pub fn filename_decoding(mut self, filename_decoding: FilenameDecoding) -> Self {
+        self.filename_decoding = filename_decoding;
+        self
+    }
+
+    /// Reject reading files larger than the given number of bytes,
+    /// including the package and table of contents. Default is no limit.
+    ///
+    /// Files that exceed the limit return a
+    /// [CannotRead](crate::result::ArchiveError::CannotRead) error before
+    /// they are decompressed, protecting against decompression bombs.
+    ///
+    /// # Examples
+    /// Limiting the size of files:
+    /// ```
+    /// use rbook::epub::EpubSettings;
+    ///
+    /// let settings = EpubSettings::new().max_file_size(100_000);
+    /// let epub = rbook::Epub::open_with("tests/ebooks/moby-dick.epub", settings).unwrap();
+    ///
+    /// assert!(epub.read_file("chapter_001.xhtml").is_ok());
+    /// assert!(epub.read_bytes_file("images/9780316000000.jpg").is_err());
+    /// ```
+    pub fn max_file_size(mut self, max_file_size: u64) -> Self {
+        self.max_file_size = Some(max_file_size);
+        self
+    }
+
+    /// Register a [ContentDecryptor] to decrypt the resources listed
+    /// as encrypted in `META-INF/encryption.xml`. By default, no
+    /// decryptor is registered and resources are read as is.
+    ///
+    /// # Examples
+    /// Decrypting resources encrypted by a single-byte xor:
+    /// ```
+    /// use rbook::epub::{ContentDecryptor, EpubSettings};
+    /// use rbook::result::ArchiveError;
+    ///
+    /// struct XorDecryptor(u8);
+    ///
+    /// impl ContentDecryptor for XorDecryptor {
+    ///     fn decrypt(
+    ///         &self,
+    ///         path: &str,
+    ///         algorithm: &str,
+    ///         data: Vec<u8>,
+    ///     ) -> Result<Vec<u8>, ArchiveError> {
+    ///         match algorithm {
+    ///             "urn:example:xor" => Ok(data.into_iter().map(|byte| byte ^ self.0).collect()),
+    ///             _ => Err(ArchiveError::CannotRead {
+    ///                 cause: "Unsupported encryption algorithm".to_string(),
+    ///                 description: format!("Cannot decrypt '{path}' using '{algorithm}'"),
+    ///             }),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let settings = EpubSettings::new().decryptor(XorDecryptor(0x2a));
+    /// let epub = rbook::Epub::open_with("tests/ebooks/moby-dick.epub", settings).unwrap();
+    /// ```
+    pub fn decryptor<D: ContentDecryptor + 'static>(mut self, decryptor: D) -> Self {
+        self.decryptor = Some(Decryptor(Shared::new(decryptor)));
+        self
+    }
+
+    // Apply href normalization if enabled
+    pub(super) fn href(&self, href: String) -> String {
+        if self.normalize_backslashes && href.contains('\\') {
+            href.replace('\\', "/")
+        } else {
+            href
+        }
+    }
+
+    // Copy attributes and apply href normalization to `href` and `src` attributes
+    pub(super) fn copy_attributes(&self, attributes: &[LolAttribute]) -> Vec<Attribute> {
+        attributes
+            .iter()
+            .map(|attribute| {
+                let name = attribute.name();
+                let value = match name.as_str() {
+                    xml::HREF | xml::SRC => self.href(attribute.value()),
+                    _ => attribute.value(),
+                };
+
+                Attribute::new(name, value)
+            })
+            .collect()
+    }
+}
+
+impl Default for EpubSettings {
+    fn default() -> Self {
+        Self {
+            href_case_insensitive: false,
+            normalize_backslashes: false,
+            follow_symlinks: true,
+            parse_level: ParseLevel::Full,
+            lazy_toc: false,
+            filename_decoding: FilenameDecoding::Auto,
+            max_file_size: None,
+            decryptor: None,
+        }
+    }
+}
+
+/// The amount of an epub that is parsed when it is opened.
+///
+/// Parts of an epub that are not parsed are empty, i.e., the
+/// [Manifest](super::Manifest) has no elements. Files can still be
+/// retrieved using [Epub::read_file](super::Epub::read_file).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ParseLevel {
+    /// Parse the container and package metadata only. Useful to quickly
+    /// retrieve the title, creators, etc. of many ebooks.
+    MetadataOnly,
+    /// Parse the entire package, such as the manifest and spine,
+    /// but not the table of contents.
+    Structure,
+    /// Parse the package and table of contents.
+    #[default]
+    Full,
+}
+
+/// Strategy to decode the names of files within a zip archive.
+///
+/// Zip archives store names as either utf-8 or code page 437,
+/// indicated by a flag of each file. However, archives in the wild
+/// often store utf-8 names without the flag, or names in other
+/// encodings entirely, which then fail to match the hrefs of the package.
+///
+/// # Examples
+/// Opening an epub with a file stored as code page 437:
+/// ```
+/// use rbook::epub::{EpubSettings, FilenameDecoding};
+///
+/// let settings = EpubSettings::new().filename_decoding(FilenameDecoding::Auto);
+/// let epub = rbook::Epub::open_with("tests/ebooks/encoding.epub", settings).unwrap();
+///
+/// assert_eq!("Notes", epub.read_file("café.txt").unwrap());
+///
+/// // Original names are retained
+/// let decoded = epub.decoded_names().iter().find(|name| name.name() == "OEBPS/café.txt");
+/// assert_eq!(b"OEBPS/caf\x82.txt", decoded.unwrap().raw());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FilenameDecoding {
+    /// Decode names as utf-8 if valid, otherwise as code page 437,
+    /// regardless of the flag of each file.
+    #[default]
+    Auto,
+    /// Decode names as indicated by the flag of each file.
+    Declared,
+    /// Decode names as utf-8, replacing invalid sequences with `�`.
+    /// Names that only differ by their invalid sequences may collide,
+    /// in which case the first file is retrievable.
+    Utf8Lossy,
+}
+
+impl FilenameDecoding {
+    // Decode the raw name of a zip file. The declared name
+    // is the name as indicated by the flag of the file.
+    pub(crate) fn decode(self, raw: &[u8], declared: &str) -> String {
+        match self {
+            Self::Auto => match std::str::from_utf8(raw) {
+                Ok(name) => name.to_string(),
+                Err(_) => utility::from_cp437(raw),
+            },
+            Self::Declared => declared.to_string(),
+            Self::Utf8Lossy => String::from_utf8_lossy(raw).into_owned(),
+        }
+    }
+}
@@ -0,0 +1,60 @@
+// Heuristics to derive the sort form of names and titles
+// when a `file-as` refinement is not provided.
+
+// Suffixes that follow the given names, such as `Jr.`
+const NAME_SUFFIXES: [&str; 7] = ["jr", "jr.", "sr", "sr.", "ii", "iii", "iv"];
+// Leading articles of english titles
+const ARTICLES: [&str; 3] = ["the", "a", "an"];
+
+// Retrieve the sort form of a personal name, i.e. `Melville, Herman`.
+// Names that are already inverted or consist of a single word, such as
+// names written without spaces in CJK scripts, are kept as is.
+pub(super) fn name(name: &str) -> String {
+    let name = name.trim();
+    let mut words: Vec<&str> = name.split_whitespace().collect();
+
+    if name.contains(',') || words.len() < 2 {
+        return words.join(" ");
+    }
+
+    let suffix = words
+        .last()
+        .filter(|word| NAME_SUFFIXES.contains(&word.to_lowercase().as_str()))
+        .copied();
+    if suffix.is_some() {
+        words.pop();
+    }
+
+    let Some(last) = words.pop() else {
+        return name.to_string();
+    };
+    let mut sort_form = format!("{last}, {}", words.join(" "));
+
+    if let Some(suffix) = suffix {
+        sort_form = format!("{sort_form}, {suffix}");
+    }
+    sort_form
+}
+
+// Retrieve the sort form of a title, moving a leading article
+// to the end, i.e. `Whale, The`.
+pub(super) fn title(title: &str) -> String {
+    let title = title.trim();
+
+    match title.split_once(char::is_whitespace) {
+        Some((article, rest)) if ARTICLES.contains(&article.to_lowercase().as_str()) => {
+            format!("{}, {article}", rest.trim_start())
+        }
+        _ => title.to_string(),
+    }
+}
+
+// Retrieve a key to compare sort forms, ignoring case and
+// differences in whitespace.
+pub(super) fn key(sort_form: &str) -> String {
+    sort_form
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
@@ -1,7 +1,8 @@
-use std::borrow::Borrow;
+use std::ops::{Index, RangeBounds};
+use std::slice::SliceIndex;
 
-use crate::formats::xml::{Attribute, Element};
-use crate::utility::Shared;
+use crate::formats::epub::constants;
+use crate::formats::xml::{utility as xmlutil, Attribute, Element};
 use crate::xml::Find;
 
 /// Access the order of resources for the ebook.
@@ -26,35 +27,353 @@ use crate::xml::Find;
 /// assert_eq!("xchapter_026", idref);
 /// ```
 #[derive(Debug)]
-pub struct Spine(Shared<Element>);
+pub struct Spine {
+    elements: Vec<Element>,
+    foreign_elements: Vec<Element>,
+    attributes: Vec<Attribute>,
+}
 
 impl Spine {
-    pub(crate) fn new(spine_element: Shared<Element>) -> Self {
-        Self(spine_element)
+    pub(crate) fn new(
+        elements: Vec<Element>,
+        foreign_elements: Vec<Element>,
+        attributes: Vec<Attribute>,
+    ) -> Self {
+        Self {
+            elements,
+            foreign_elements,
+            attributes,
+        }
     }
 
-    /// Retrieve all spine `itemref` elements
+    /// Retrieve all spine `itemref` elements.
+    ///
+    /// Metadata entries that refine an `itemref`, such as
+    /// `media:duration`, are the [children](Element::children) of its
+    /// element.
     pub fn elements(&self) -> Vec<&Element> {
-        self.0
-            .children
-            .as_ref()
-            .map(|elements| elements.iter().map(Shared::borrow).collect())
-            .unwrap_or_default()
+        self.elements.iter().collect()
+    }
+
+    /// Retrieve the children of the `spine` element that are not
+    /// `itemref` elements, such as vendor extensions, in the order they
+    /// appear. See [Epub::foreign_elements](super::Epub::foreign_elements)
+    /// for more details.
+    pub fn foreign_elements(&self) -> Vec<&Element> {
+        self.foreign_elements.iter().collect()
+    }
+
+    /// Retrieve the number of spine `itemref` elements.
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Check if the spine contains no `itemref` elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Retrieve the spine `itemref` element at a certain index.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// # let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    /// let spine = epub.spine();
+    ///
+    /// assert_eq!("xchapter_026", spine.get(31).unwrap().name());
+    /// assert!(spine.get(spine.len()).is_none());
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&Element> {
+        self.elements.get(index)
+    }
+
+    /// Retrieve the spine `itemref` elements within a range of indexes,
+    /// such as a window of pages. Returns [None] if the range is out of
+    /// bounds, similar to [slice::get].
+    ///
+    /// Spine elements may also be indexed directly, such as `spine[2]`
+    /// or `&spine[2..5]`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// # let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    /// let spine = epub.spine();
+    /// let window = spine.get_range(6..9).unwrap();
+    ///
+    /// assert_eq!(3, window.len());
+    /// assert_eq!("xchapter_001", window[0].name());
+    /// assert_eq!(&spine[8], &window[2]);
+    /// assert_eq!(window, &spine[6..9]);
+    /// assert!(spine.get_range(spine.len()..spine.len() + 1).is_none());
+    /// ```
+    pub fn get_range<R: RangeBounds<usize>>(&self, range: R) -> Option<&[Element]> {
+        self.elements
+            .get((range.start_bound().cloned(), range.end_bound().cloned()))
+    }
+
+    /// Check if a spine `itemref` element with a certain `idref` exists.
+    pub fn contains_idref(&self, idref: &str) -> bool {
+        self.elements()
+            .iter()
+            .any(|element| element.name() == idref)
     }
 
     /// Retrieve all the attributes of the root spine element
     pub fn attributes(&self) -> &[Attribute] {
-        self.0.attributes()
+        &self.attributes
     }
 
     /// Retrieve the value from a certain attribute from the root spine element
     pub fn get_attribute(&self, name: &str) -> Option<&str> {
-        self.0.get_attribute(name)
+        xmlutil::get_attribute(&self.attributes, &name.to_lowercase())
     }
 
     /// Check if an attribute in the root spine element exists
     pub fn contains_attribute(&self, name: &str) -> bool {
-        self.0.contains_attribute(name)
+        xmlutil::contains_attribute(&self.attributes, &name.to_lowercase())
+    }
+
+    /// Retrieve the attributes of the root spine element that rbook
+    /// does not model, such as `xml:base` or custom attributes.
+    ///
+    /// The `id`, `toc`, and `page-progression-direction` attributes,
+    /// along with namespace declarations, are excluded.
+    pub fn extra_attributes(&self) -> Vec<&Attribute> {
+        xmlutil::extra_attributes(&self.attributes, &constants::SPINE_ATTRIBUTES)
+    }
+
+    /// Pair the linear spine entries into the spreads a fixed layout
+    /// reading system displays, following their `page-spread-*`
+    /// properties and the `page-progression-direction`.
+    ///
+    /// Entries without a page-spread property fill the next side of the
+    /// current spread, starting on the left for left-to-right ebooks and
+    /// on the right for right-to-left ebooks. An entry on the starting
+    /// side begins a new spread, while a centered entry occupies a spread
+    /// alone. Non-linear entries are excluded.
+    ///
+    /// # Examples
+    /// Pairing pages:
+    /// ```
+    /// # use rbook::Ebook;
+    /// # let epub = rbook::Epub::new("tests/ebooks/example_epub").unwrap();
+    /// let spreads = epub.spine().spreads();
+    ///
+    /// // `toc` and `c1` share a spread, `c2` is left alone
+    /// assert_eq!(2, spreads.len());
+    /// assert_eq!("toc", spreads[0].left().unwrap().name());
+    /// assert_eq!("c1", spreads[0].right().unwrap().name());
+    /// assert_eq!("c2", spreads[1].left().unwrap().name());
+    /// assert_eq!(None, spreads[1].right());
+    /// ```
+    pub fn spreads(&self) -> Vec<Spread<'_>> {
+        let is_rtl =
+            self.get_attribute(constants::PAGE_PROGRESSION_DIRECTION) == Some(constants::RTL);
+        let (first, second) = match is_rtl {
+            true => (Side::Right, Side::Left),
+            false => (Side::Left, Side::Right),
+        };
+
+        let mut spreads = Vec::new();
+        let mut current = Spread::new(is_rtl);
+
+        for element in self.elements() {
+            if element.get_attribute(constants::LINEAR) == Some(constants::NO) {
+                continue;
+            }
+
+            let side = page_spread(element).unwrap_or(match current.is_empty() {
+                true => first,
+                false => second,
+            });
+
+            match side {
+                Side::Center => {
+                    spreads.extend(current.take());
+                    let mut spread = Spread::new(is_rtl);
+                    spread.set(Side::Center, element);
+                    spreads.push(spread);
+                }
+                side if side == first => {
+                    spreads.extend(current.take());
+                    current.set(side, element);
+                }
+                side => {
+                    current.set(side, element);
+                    spreads.extend(current.take());
+                }
+            }
+        }
+
+        spreads.extend(current.take());
+        spreads
+    }
+}
+
+// Retrieve the side of a spine entry from its `page-spread-*` property
+fn page_spread(element: &Element) -> Option<Side> {
+    element
+        .get_attribute(constants::PROPERTIES)?
+        .split_whitespace()
+        .map(|property| {
+            property
+                .strip_prefix(constants::RENDITION_PREFIX)
+                .unwrap_or(property)
+        })
+        .find_map(|property| match property {
+            constants::PAGE_SPREAD_LEFT => Some(Side::Left),
+            constants::PAGE_SPREAD_RIGHT => Some(Side::Right),
+            constants::PAGE_SPREAD_CENTER => Some(Side::Center),
+            _ => None,
+        })
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Left,
+    Right,
+    Center,
+}
+
+/// Spine entries displayed together by a fixed layout reading system,
+/// retrieved using [Spine::spreads].
+///
+/// A spread contains either a left and/or right page, or a single
+/// centered page.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Spread<'a> {
+    left: Option<&'a Element>,
+    right: Option<&'a Element>,
+    center: Option<&'a Element>,
+    // Whether the right side precedes the left side in spine order
+    rtl: bool,
+}
+
+impl<'a> Spread<'a> {
+    fn new(rtl: bool) -> Self {
+        Self {
+            left: None,
+            right: None,
+            center: None,
+            rtl,
+        }
+    }
+
+    /// Retrieve the spine entry on the left side.
+    pub fn left(&self) -> Option<&'a Element> {
+        self.left
+    }
+
+    /// Retrieve the spine entry on the right side.
+    pub fn right(&self) -> Option<&'a Element> {
+        self.right
+    }
+
+    /// Retrieve the spine entry that occupies the entire spread.
+    pub fn center(&self) -> Option<&'a Element> {
+        self.center
+    }
+
+    /// Retrieve the spine entries of the spread in spine order.
+    pub fn elements(&self) -> Vec<&'a Element> {
+        let mut elements: Vec<_> = [self.left, self.right, self.center]
+            .into_iter()
+            .flatten()
+            .collect();
+        // The starting side of a spread always precedes the other
+        if self.rtl {
+            elements.reverse();
+        }
+        elements
+    }
+
+    fn is_empty(&self) -> bool {
+        self.left.is_none() && self.right.is_none() && self.center.is_none()
+    }
+
+    fn set(&mut self, side: Side, element: &'a Element) {
+        match side {
+            Side::Left => self.left = Some(element),
+            Side::Right => self.right = Some(element),
+            Side::Center => self.center = Some(element),
+        }
+    }
+
+    // Retrieve the spread if it has entries, resetting it
+    fn take(&mut self) -> Option<Self> {
+        (!self.is_empty()).then(|| std::mem::replace(self, Self::new(self.rtl)))
+    }
+}
+
+/// Relative weights of spine entries, used to calculate the reading
+/// progress of an ebook.
+///
+/// Each entry is weighted by the uncompressed size of its resource in
+/// bytes. Entries with `linear="no"` have a weight of `0`, as they are
+/// not part of the default reading order.
+///
+/// The weights are retrieved using [Epub::spine_weights](super::Epub::spine_weights).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpineWeights(Vec<u64>);
+
+impl SpineWeights {
+    pub(crate) fn new(weights: Vec<u64>) -> Self {
+        Self(weights)
+    }
+
+    /// Retrieve the weight of every spine entry, in spine order
+    pub fn weights(&self) -> &[u64] {
+        &self.0
+    }
+
+    /// Retrieve the sum of all weights
+    pub fn total(&self) -> u64 {
+        self.0.iter().sum()
+    }
+
+    /// Calculate the reading progress, ranging from `0.0` to `1.0`, of
+    /// the given spine index. The `fraction` is how far into the entry
+    /// the reader is, and is clamped between `0.0` and `1.0`.
+    ///
+    /// Returns `None` if the index is out of bounds or if no entry has
+    /// any weight. Progress does not advance within a non-linear entry.
+    pub fn progress(&self, spine_index: usize, fraction: f64) -> Option<f64> {
+        let weight = *self.0.get(spine_index)?;
+        let total = self.total();
+
+        if total == 0 {
+            return None;
+        }
+
+        let fraction = if fraction.is_nan() {
+            0.0
+        } else {
+            fraction.clamp(0.0, 1.0)
+        };
+        let preceding: u64 = self.0[..spine_index].iter().sum();
+
+        Some((preceding as f64 + weight as f64 * fraction) / total as f64)
+    }
+}
+
+impl<I: SliceIndex<[Element]>> Index<I> for Spine {
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &Self::Output {
+        &self.elements[index]
+    }
+}
+
+impl<'a> IntoIterator for &'a Spine {
+    type Item = &'a Element;
+    type IntoIter = std::vec::IntoIter<&'a Element>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.elements().into_iter()
     }
 }
 
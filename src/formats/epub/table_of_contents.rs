@@ -56,7 +56,7 @@ use crate::xml::Find;
 /// assert_eq!("204 THE KING OF THE GOLDEN RIVER OR THE BLACK BROTHERS", element.name());
 /// assert_eq!("s04.xhtml#pgepubid00602", element.value());
 /// ```
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Toc(HashMap<String, Shared<Element>>);
 
 impl Toc {
@@ -82,15 +82,53 @@ impl Toc {
         let elements = self.get_elements_flat(constants::TOC);
 
         // Order navPoint elements
-        if elements.first().map_or(false, |element| {
-            element.contains_attribute(constants::PLAY_ORDER)
-        }) {
+        if elements
+            .first()
+            .is_some_and(|element| element.contains_attribute(constants::PLAY_ORDER))
+        {
             sort_nav_points(elements)
         } else {
             elements
         }
     }
 
+    /// Retrieve the number of toc elements in flattened form.
+    pub fn len(&self) -> usize {
+        self.get_elements_flat(constants::TOC).len()
+    }
+
+    /// Check if the toc contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Check if a toc element references a certain `href`.
+    /// The fragment of an element, such as `#section`, is ignored
+    /// if the given `href` does not contain one.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// # let epub = rbook::Epub::new("tests/ebooks/childrens-literature.epub").unwrap();
+    /// let toc = epub.toc();
+    ///
+    /// assert!(toc.contains_href("s04.xhtml#pgepubid00602"));
+    /// assert!(toc.contains_href("s04.xhtml"));
+    /// assert!(!toc.contains_href("s05.xhtml"));
+    /// ```
+    pub fn contains_href(&self, href: &str) -> bool {
+        let has_fragment = href.contains('#');
+
+        self.get_elements_flat(constants::TOC)
+            .iter()
+            .map(|element| element.value())
+            .any(|value| match has_fragment {
+                true => value == href,
+                false => value.split('#').next() == Some(href),
+            })
+    }
+
     /// Retrieve landmark toc elements.
     pub fn landmarks(&self) -> Vec<&Element> {
         self.get_elements_flat(constants::LANDMARKS)
@@ -101,6 +139,100 @@ impl Toc {
         self.get_elements_flat(constants::PAGE_LIST3)
     }
 
+    /// Retrieve the elements of any `nav` element by its `epub:type`
+    /// in nested form, including types without a dedicated method such
+    /// as `loi` (list of illustrations) or `lot` (list of tables).
+    ///
+    /// For epub2, the `navMap` and `pageList` elements are available
+    /// as `toc` and `page-list` respectively.
+    ///
+    /// # Examples
+    /// Retrieving a list of illustrations:
+    /// ```
+    /// # use rbook::Ebook;
+    /// let epub = rbook::Epub::new("tests/ebooks/content_epub").unwrap();
+    ///
+    /// let illustrations = epub.toc().get("loi");
+    /// assert_eq!("Figure 1", illustrations[0].name());
+    ///
+    /// assert!(epub.toc().nav_types().contains(&"loi"));
+    /// ```
+    pub fn get(&self, nav_type: &str) -> Vec<&Element> {
+        self.0
+            .iter()
+            .find(|(name, _)| name.split_whitespace().any(|name| name == nav_type))
+            .map(|(_, element)| element.children())
+            .unwrap_or_default()
+    }
+
+    /// Retrieve the `epub:type` of every `nav` element, preserved
+    /// as declared, in no particular order.
+    pub fn nav_types(&self) -> Vec<&str> {
+        self.0.keys().map(String::as_str).collect()
+    }
+
+    /// Check if a toc element is hidden, meaning the element or one of
+    /// its ancestors, such as the root `nav` element, has the `hidden`
+    /// attribute.
+    ///
+    /// Hidden elements are not meant to be rendered by a reading system,
+    /// although they remain available for navigation.
+    ///
+    /// # Examples
+    /// Checking the visibility of toc elements:
+    /// ```
+    /// # use rbook::Ebook;
+    /// let epub = rbook::Epub::new("tests/ebooks/content_epub").unwrap();
+    /// let toc = epub.toc();
+    ///
+    /// // The `page-list` nav element is hidden
+    /// let page = toc.page_list().into_iter().next().unwrap();
+    /// assert!(toc.is_hidden(page));
+    ///
+    /// let chapter = toc.elements().into_iter().next().unwrap();
+    /// assert!(!toc.is_hidden(chapter));
+    /// ```
+    pub fn is_hidden(&self, element: &Element) -> bool {
+        if element.contains_attribute(constants::HIDDEN) {
+            return true;
+        }
+
+        let mut parent = element.parent();
+
+        while let Some(element) = parent {
+            if element.contains_attribute(constants::HIDDEN) {
+                return true;
+            }
+
+            parent = element.parent();
+        }
+
+        false
+    }
+
+    /// Retrieve the `epub:type` values of a toc element, such as
+    /// `bodymatter` or `glossary`. Values declared on both the `li`
+    /// element and its `a`/`span` label are included.
+    ///
+    /// # Examples
+    /// Retrieving the types of a landmark:
+    /// ```
+    /// # use rbook::Ebook;
+    /// let epub = rbook::Epub::new("tests/ebooks/example_epub").unwrap();
+    /// let toc = epub.toc();
+    ///
+    /// let landmark = toc.landmarks().into_iter().nth(1).unwrap();
+    /// assert_eq!(vec!["bodymatter"], toc.types(landmark));
+    /// ```
+    pub fn types<'a>(&self, element: &'a Element) -> Vec<&'a str> {
+        element
+            .attributes()
+            .iter()
+            .filter(|attribute| attribute.name() == constants::TOC_TYPE)
+            .flat_map(|attribute| attribute.value().split_whitespace())
+            .collect()
+    }
+
     // Gets the children elements from toc, page-list, landmarks, etc. elements.
     fn get_elements(&self, name: &str) -> Vec<&Element> {
         self.0
@@ -114,6 +246,16 @@ impl Toc {
     }
 }
 
+// Toc elements are iterated in flattened form
+impl<'a> IntoIterator for &'a Toc {
+    type Item = &'a Element;
+    type IntoIter = std::vec::IntoIter<&'a Element>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.elements_flat().into_iter()
+    }
+}
+
 impl Find for Toc {
     fn __find_fallback(&self, _name: &str, _is_wild: bool) -> Vec<&Element> {
         self.0
@@ -137,7 +279,7 @@ fn sort_nav_points(nav_points: Vec<&Element>) -> Vec<&Element> {
         .collect();
 
     // Sort by nav point play order
-    ordered_element.sort_by(|(order1, _), (order2, _)| order1.cmp(order2));
+    ordered_element.sort_by_key(|(order, _)| *order);
     ordered_element
         .into_iter()
         .map(|(_, nav_point)| nav_point)
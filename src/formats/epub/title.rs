@@ -0,0 +1,123 @@
+use crate::formats::epub::constants;
+use crate::formats::xml::Element;
+
+/// Kind of a `title` metadata element, retrieved from its refining
+/// `title-type` element.
+///
+/// # Examples
+/// Retrieving the kind of a title:
+/// ```
+/// use rbook::Ebook;
+/// use rbook::epub::TitleKind;
+///
+/// let epub = rbook::Epub::new("tests/ebooks/example_epub").unwrap();
+/// let title = epub.metadata().title().unwrap();
+///
+/// // Titles without a `title-type` are main titles
+/// assert_eq!(TitleKind::Main, TitleKind::from_title(title));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TitleKind {
+    /// Primary title of the ebook, such as `Moby-Dick`.
+    Main,
+    /// Secondary title, such as `or, The Whale`.
+    Subtitle,
+    /// Abbreviated title.
+    Short,
+    /// Title of the collection or series the ebook belongs to.
+    Collection,
+    /// Edition statement, such as `Second Edition`.
+    Edition,
+    /// Complete title, combining the main title and subtitles.
+    /// The nonstandard `extended` title type is also mapped to this kind.
+    Expanded,
+    /// Title type that is not recognized.
+    Other,
+}
+
+impl TitleKind {
+    /// Retrieve the kind of a `title` metadata element. Titles without
+    /// a `title-type` are considered [Main](Self::Main) titles.
+    pub fn from_title(title: &Element) -> Self {
+        title
+            .get_child(constants::TITLE_TYPE)
+            .map_or(Self::Main, |title_type| Self::from_type(title_type.value()))
+    }
+
+    /// Retrieve the kind of a `title-type` value, such as `subtitle`.
+    /// Case and surrounding whitespace are ignored.
+    pub fn from_type(title_type: &str) -> Self {
+        match title_type.trim().to_lowercase().as_str() {
+            "main" => Self::Main,
+            "subtitle" => Self::Subtitle,
+            "short" => Self::Short,
+            "collection" => Self::Collection,
+            "edition" => Self::Edition,
+            "expanded" | "extended" => Self::Expanded,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Style used to assemble a display title from the titles of an
+/// ebook, using [Metadata::display_title](super::Metadata::display_title).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TitleStyle {
+    /// The main title only, such as `Moby-Dick`.
+    Main,
+    /// The main title and subtitles separated by a colon, such as
+    /// `Moby-Dick: or, The Whale`. An expanded title is preferred
+    /// if present.
+    #[default]
+    Full,
+    /// The [full](Self::Full) title followed by the edition and the
+    /// collection, such as
+    /// `Moby-Dick: or, The Whale, Second Edition (Penguin Classics)`.
+    Catalog,
+}
+
+// Assemble the display title of titles sorted by their display sequence
+pub(super) fn display_title(titles: &[&Element], style: TitleStyle) -> Option<String> {
+    let values = |kind: TitleKind| -> Vec<&str> {
+        titles
+            .iter()
+            .filter(|title| TitleKind::from_title(title) == kind)
+            .map(|title| title.value().trim())
+            .filter(|value| !value.is_empty())
+            .collect()
+    };
+    let main = values(TitleKind::Main)
+        .first()
+        .copied()
+        .or_else(|| titles.first().map(|title| title.value().trim()))?;
+
+    if style == TitleStyle::Main {
+        return Some(main.to_string());
+    }
+
+    let mut display = match values(TitleKind::Expanded).first() {
+        Some(expanded) => expanded.to_string(),
+        None => {
+            values(TitleKind::Subtitle)
+                .into_iter()
+                .fold(main.to_string(), |title, subtitle| {
+                    // Avoid doubling punctuation, such as `Why?: An Essay`
+                    match title.ends_with(['?', '!', ':', '.']) {
+                        true => format!("{title} {subtitle}"),
+                        false => format!("{title}: {subtitle}"),
+                    }
+                })
+        }
+    };
+
+    if style == TitleStyle::Catalog {
+        for edition in values(TitleKind::Edition) {
+            display = format!("{display}, {edition}");
+        }
+        for collection in values(TitleKind::Collection) {
+            display = format!("{display} ({collection})");
+        }
+    }
+
+    Some(display)
+}
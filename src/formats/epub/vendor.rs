@@ -0,0 +1,317 @@
+use lol_html::{element, text};
+use std::cell::RefCell;
+
+use crate::formats::epub::{self, constants, Metadata};
+use crate::formats::xml::Element;
+use crate::formats::EbookResult;
+
+/// Access proprietary metadata entries of well-known vendors, such as
+/// calibre and Apple Books, which are not part of the epub specification.
+///
+/// Entries are retrieved from [Metadata] using
+/// [vendor()](Metadata::vendor). Options of Apple Books that are stored
+/// outside the package are available using
+/// [apple_display_options()](super::Epub::apple_display_options).
+///
+/// # Examples
+/// Retrieving calibre series information:
+/// ```
+/// use rbook::Ebook;
+///
+/// let epub = rbook::Epub::new("tests/ebooks/vendor_epub").unwrap();
+/// let vendor = epub.metadata().vendor();
+///
+/// assert_eq!(Some("rbook Examples"), vendor.calibre_series());
+/// assert_eq!(Some(2.0), vendor.calibre_series_index());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct VendorMetadata<'a> {
+    metadata: &'a Metadata,
+}
+
+impl<'a> VendorMetadata<'a> {
+    pub(super) fn new(metadata: &'a Metadata) -> Self {
+        Self { metadata }
+    }
+
+    /// Retrieve the name of the series the ebook belongs to
+    /// from the `calibre:series` entry.
+    pub fn calibre_series(&self) -> Option<&'a str> {
+        self.value(constants::CALIBRE, "series")
+    }
+
+    /// Retrieve the position of the ebook within its series
+    /// from the `calibre:series_index` entry.
+    pub fn calibre_series_index(&self) -> Option<f64> {
+        self.number(constants::CALIBRE, "series_index")
+    }
+
+    /// Retrieve the rating of the ebook from the `calibre:rating` entry.
+    /// calibre stores ratings from `0` to `10`, where each star is worth `2`.
+    pub fn calibre_rating(&self) -> Option<f64> {
+        self.number(constants::CALIBRE, "rating")
+    }
+
+    /// Retrieve the title used for sorting from the `calibre:title_sort` entry.
+    pub fn calibre_title_sort(&self) -> Option<&'a str> {
+        self.value(constants::CALIBRE, "title_sort")
+    }
+
+    /// Retrieve the version of Apple Books the ebook targets
+    /// from the `ibooks:version` entry.
+    pub fn ibooks_version(&self) -> Option<&'a str> {
+        self.value(constants::IBOOKS, "version")
+    }
+
+    /// Check if the embedded fonts of the ebook should be used from
+    /// the `ibooks:specified-fonts` entry.
+    pub fn ibooks_specified_fonts(&self) -> Option<bool> {
+        self.value(constants::IBOOKS, "specified-fonts")
+            .and_then(|value| value.trim().parse().ok())
+    }
+
+    /// Retrieve all entries that belong to a vendor prefix,
+    /// such as `calibre` or `ibooks`.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// # let epub = rbook::Epub::new("tests/ebooks/vendor_epub").unwrap();
+    /// let entries = epub.metadata().vendor().entries("ibooks");
+    ///
+    /// assert_eq!(2, entries.len());
+    /// ```
+    pub fn entries(&self, prefix: &str) -> Vec<&'a Element> {
+        self.metadata
+            .elements()
+            .into_iter()
+            .filter(|element| vendor_prefix(element) == Some(prefix))
+            .collect()
+    }
+
+    fn element(&self, prefix: &str, name: &str) -> Option<&'a Element> {
+        self.metadata
+            .get(name)
+            .into_iter()
+            .find(|element| vendor_prefix(element) == Some(prefix))
+    }
+
+    fn value(&self, prefix: &str, name: &str) -> Option<&'a str> {
+        self.element(prefix, name).map(Element::value)
+    }
+
+    fn number(&self, prefix: &str, name: &str) -> Option<f64> {
+        self.value(prefix, name)
+            .and_then(|value| value.trim().parse().ok())
+    }
+}
+
+// Retrieve the prefix of the original `property` or `name` attribute
+fn vendor_prefix(element: &Element) -> Option<&str> {
+    element
+        .get_attribute(constants::PROPERTY)
+        .or_else(|| element.get_attribute(constants::NAME))
+        .and_then(|name| name.split_once(':'))
+        .map(|(prefix, _)| prefix)
+}
+
+/// Display options of Apple Books from the
+/// `META-INF/com.apple.ibooks.display-options.xml` file.
+///
+/// # Examples
+/// Basic usage:
+/// ```
+/// use rbook::Ebook;
+///
+/// let epub = rbook::Epub::new("tests/ebooks/vendor_epub").unwrap();
+/// let options = epub.apple_display_options().unwrap().unwrap();
+///
+/// assert_eq!(Some(true), options.specified_fonts());
+/// assert_eq!(Some("portrait-only"), options.get("iphone", "orientation-lock"));
+/// ```
+///
+/// Generating the file:
+/// ```
+/// use rbook::epub::AppleDisplayOptions;
+///
+/// let options = AppleDisplayOptions::new()
+///     .with_specified_fonts(true)
+///     .with_option("iphone", "orientation-lock", "landscape-only");
+///
+/// assert_eq!(
+///     concat!(
+///         r#"<?xml version="1.0" encoding="UTF-8"?>"#, "\n",
+///         "<display_options>\n",
+///         r#"  <platform name="*">"#, "\n",
+///         r#"    <option name="specified-fonts">true</option>"#, "\n",
+///         "  </platform>\n",
+///         r#"  <platform name="iphone">"#, "\n",
+///         r#"    <option name="orientation-lock">landscape-only</option>"#, "\n",
+///         "  </platform>\n",
+///         "</display_options>\n",
+///     ),
+///     options.to_xml(),
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AppleDisplayOptions {
+    // (platform, name, value)
+    options: Vec<(String, String, String)>,
+}
+
+impl AppleDisplayOptions {
+    /// Create display options without any options, to be
+    /// written as the `META-INF/com.apple.ibooks.display-options.xml`
+    /// file using [to_xml](Self::to_xml).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the value of an option for a platform, such as `iphone`,
+    /// replacing its previous value. The platform `*` applies to
+    /// all devices.
+    pub fn with_option(mut self, platform: &str, name: &str, value: &str) -> Self {
+        let existing = self
+            .options
+            .iter_mut()
+            .find(|option| option.0 == platform && option.1 == name);
+
+        match existing {
+            Some(option) => option.2 = value.to_string(),
+            None => self
+                .options
+                .push((platform.to_string(), name.to_string(), value.to_string())),
+        }
+        self
+    }
+
+    /// Set whether the embedded fonts of the ebook should be used.
+    pub fn with_specified_fonts(self, specified_fonts: bool) -> Self {
+        self.with_option(
+            constants::ALL_PLATFORMS,
+            "specified-fonts",
+            &specified_fonts.to_string(),
+        )
+    }
+
+    /// Set whether the ebook is a fixed layout ebook.
+    pub fn with_fixed_layout(self, fixed_layout: bool) -> Self {
+        self.with_option(
+            constants::ALL_PLATFORMS,
+            "fixed-layout",
+            &fixed_layout.to_string(),
+        )
+    }
+
+    /// Generate the xml of the `META-INF/com.apple.ibooks.display-options.xml`
+    /// file. Options are grouped by platform in the order each platform
+    /// first appears.
+    pub fn to_xml(&self) -> String {
+        let mut platforms: Vec<&str> = Vec::new();
+        for (platform, _, _) in &self.options {
+            if !platforms.contains(&platform.as_str()) {
+                platforms.push(platform);
+            }
+        }
+
+        let mut xml =
+            String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<display_options>\n");
+
+        for platform in platforms {
+            xml.push_str(&format!("  <platform name=\"{}\">\n", escape_xml(platform)));
+
+            for (_, name, value) in self.options.iter().filter(|option| option.0 == platform) {
+                xml.push_str(&format!(
+                    "    <option name=\"{}\">{}</option>\n",
+                    escape_xml(name),
+                    escape_xml(value)
+                ));
+            }
+            xml.push_str("  </platform>\n");
+        }
+
+        xml.push_str("</display_options>\n");
+        xml
+    }
+
+    /// Retrieve all options in the form of `(platform, name, value)`.
+    /// The platform `*` applies to all devices.
+    pub fn options(&self) -> Vec<(&str, &str, &str)> {
+        self.options
+            .iter()
+            .map(|(platform, name, value)| (platform.as_str(), name.as_str(), value.as_str()))
+            .collect()
+    }
+
+    /// Retrieve the value of an option for a platform, such as `iphone`.
+    /// Options of the `*` platform are used if the platform does not
+    /// specify the option.
+    pub fn get(&self, platform: &str, name: &str) -> Option<&str> {
+        let find = |platform: &str| {
+            self.options
+                .iter()
+                .find(|option| option.0 == platform && option.1 == name)
+                .map(|option| option.2.as_str())
+        };
+
+        find(platform).or_else(|| find(constants::ALL_PLATFORMS))
+    }
+
+    /// Check if the embedded fonts of the ebook should be used.
+    pub fn specified_fonts(&self) -> Option<bool> {
+        self.flag("specified-fonts")
+    }
+
+    /// Check if the ebook is a fixed layout ebook.
+    pub fn fixed_layout(&self) -> Option<bool> {
+        self.flag("fixed-layout")
+    }
+
+    fn flag(&self, name: &str) -> Option<bool> {
+        self.get(constants::ALL_PLATFORMS, name)
+            .and_then(|value| value.trim().parse().ok())
+    }
+}
+
+pub(super) fn parse_display_options(data: &[u8]) -> EbookResult<AppleDisplayOptions> {
+    let platform = RefCell::new(String::new());
+    let options = RefCell::new(Vec::new());
+
+    let platform_handler = element!("platform", |element| {
+        platform.replace(element.get_attribute(constants::NAME).unwrap_or_default());
+        Ok(())
+    });
+
+    let option_handler = element!("option", |element| {
+        if let Some(name) = element.get_attribute(constants::NAME) {
+            let platform = platform.borrow().clone();
+            options.borrow_mut().push((platform, name, String::new()));
+        }
+        Ok(())
+    });
+
+    let option_text_handler = text!("option", |text| {
+        if let Some(option) = options.borrow_mut().last_mut() {
+            option.2.push_str(text.as_str().trim());
+        }
+        Ok(())
+    });
+
+    epub::parse_xhtml_data(
+        vec![platform_handler, option_handler, option_text_handler],
+        vec![],
+        data,
+    )?;
+
+    Ok(AppleDisplayOptions {
+        options: options.take(),
+    })
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
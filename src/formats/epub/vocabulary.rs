@@ -0,0 +1,107 @@
+use crate::formats::epub::Metadata;
+use crate::formats::xml::Element;
+
+/// Typed parser of a custom metadata vocabulary, such as the
+/// proprietary `acme:*` entries of a publisher, retrieved using
+/// [Metadata::custom].
+///
+/// Entries belong to a vocabulary if their `property` or `name`
+/// attribute starts with its [PREFIX](Self::PREFIX).
+///
+/// # Examples
+/// Parsing calibre series entries:
+/// ```
+/// use rbook::Ebook;
+/// use rbook::epub::{Vocabulary, VocabularyEntries};
+///
+/// struct Series {
+///     name: String,
+///     index: f64,
+/// }
+///
+/// impl Vocabulary for Series {
+///     const PREFIX: &'static str = "calibre";
+///
+///     fn parse(entries: &VocabularyEntries<'_>) -> Option<Self> {
+///         Some(Self {
+///             name: entries.value("series")?.to_string(),
+///             index: entries.value("series_index")?.parse().ok()?,
+///         })
+///     }
+/// }
+///
+/// let epub = rbook::Epub::new("tests/ebooks/vendor_epub").unwrap();
+/// let series = epub.metadata().custom::<Series>().unwrap();
+///
+/// assert_eq!("rbook Examples", series.name);
+/// assert_eq!(2.0, series.index);
+/// ```
+pub trait Vocabulary: Sized {
+    /// Prefix of the vocabulary, such as `acme`, excluding the colon.
+    const PREFIX: &'static str;
+
+    /// Parse the entries of the vocabulary. Returns [None] if the
+    /// entries do not form a valid value, such as missing entries.
+    fn parse(entries: &VocabularyEntries<'_>) -> Option<Self>;
+}
+
+/// Metadata entries that belong to a [Vocabulary], looked up by their
+/// name excluding the prefix, i.e., `series` for `calibre:series`.
+#[derive(Clone, Debug)]
+pub struct VocabularyEntries<'a> {
+    metadata: &'a Metadata,
+    prefix: &'static str,
+    elements: Vec<&'a Element>,
+}
+
+impl<'a> VocabularyEntries<'a> {
+    pub(super) fn new(
+        metadata: &'a Metadata,
+        prefix: &'static str,
+        elements: Vec<&'a Element>,
+    ) -> Self {
+        Self {
+            metadata,
+            prefix,
+            elements,
+        }
+    }
+
+    /// Retrieve all entries of the vocabulary in the order they appear.
+    pub fn elements(&self) -> &[&'a Element] {
+        &self.elements
+    }
+
+    /// Retrieve the first entry with a certain name.
+    pub fn get(&self, name: &str) -> Option<&'a Element> {
+        self.elements
+            .iter()
+            .find(|element| element.name() == name)
+            .copied()
+    }
+
+    /// Retrieve all entries with a certain name.
+    pub fn get_all(&self, name: &str) -> Vec<&'a Element> {
+        self.elements
+            .iter()
+            .filter(|element| element.name() == name)
+            .copied()
+            .collect()
+    }
+
+    /// Retrieve the trimmed value of the first entry with a certain name.
+    pub fn value(&self, name: &str) -> Option<&'a str> {
+        self.get(name).map(|element| element.value().trim())
+    }
+
+    /// Retrieve the IRI the prefix of the vocabulary is mapped to by
+    /// the `prefix` attribute of the package, if declared.
+    pub fn iri(&self) -> Option<&'a str> {
+        self.metadata.prefix_iri(self.prefix)
+    }
+
+    /// Check if the vocabulary has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+}
@@ -1,4 +1,4 @@
-use std::borrow::Borrow;
+use std::borrow::{Borrow, Cow};
 use std::ops::Deref;
 
 use crate::utility::{Shared, Weak};
@@ -9,33 +9,89 @@ pub(super) const ID: &str = "id";
 pub(super) const HREF: &str = "href";
 pub(super) const SRC: &str = "src";
 
+// Retrieve a shared instance of an attribute name or value that recurs
+// throughout packages, which avoids retaining an allocation for every
+// attribute of large packages
+fn intern(string: String) -> Cow<'static, str> {
+    let interned = match string.as_str() {
+        // Names
+        "id" => ID,
+        "href" => HREF,
+        "src" => SRC,
+        "media-type" => "media-type",
+        "properties" => "properties",
+        "fallback" => "fallback",
+        "media-overlay" => "media-overlay",
+        "idref" => "idref",
+        "linear" => "linear",
+        "refines" => "refines",
+        "property" => "property",
+        "scheme" => "scheme",
+        "name" => "name",
+        "content" => "content",
+        "dir" => "dir",
+        "xml:lang" => "xml:lang",
+        "epub:type" => "epub:type",
+        "opf:role" => "opf:role",
+        "opf:file-as" => "opf:file-as",
+        "opf:scheme" => "opf:scheme",
+        "opf:event" => "opf:event",
+        // Values
+        "yes" => "yes",
+        "no" => "no",
+        "nav" => "nav",
+        "cover-image" => "cover-image",
+        "scripted" => "scripted",
+        "svg" => "svg",
+        "mathml" => "mathml",
+        "remote-resources" => "remote-resources",
+        "application/xhtml+xml" => "application/xhtml+xml",
+        "application/x-dtbncx+xml" => "application/x-dtbncx+xml",
+        "application/smil+xml" => "application/smil+xml",
+        "text/css" => "text/css",
+        "image/jpeg" => "image/jpeg",
+        "image/png" => "image/png",
+        "image/gif" => "image/gif",
+        "image/svg+xml" => "image/svg+xml",
+        "image/webp" => "image/webp",
+        "font/otf" => "font/otf",
+        "font/ttf" => "font/ttf",
+        "font/woff" => "font/woff",
+        "font/woff2" => "font/woff2",
+        "audio/mpeg" => "audio/mpeg",
+        "audio/mp4" => "audio/mp4",
+        _ => return Cow::Owned(string),
+    };
+    Cow::Borrowed(interned)
+}
+
 /// Conveniently find elements or their value using very
 /// basic CSS selector-like strings.
 ///
 /// - [Self::find] Finds the first element that matches.
 /// - [Self::find_all] Finds all elements that match.
 /// - [Self::find_value] Finds the first element that matches
-/// and returns its value.
+///   and returns its value.
 /// - [Self::final_all_value] Finds all elements that match and
-/// returns their values.
+///   returns their values.
 ///
 /// # Basic syntax:
 /// `{element name}[attribute=value][...] > {direct descendant
 /// element} > {...}`
 ///
 /// - [Element]s are separated by a greater than symbol `>`
-/// and are identified by their [name](Element::name).
+///   and are identified by their [name](Element::name).
 ///     - Element names can be foregone using a wildcard
-/// symbol `*`.
+///       symbol `*`.
 /// - Elements that follow any other elements must be its
-/// direct descendant.
+///   direct descendant.
 /// - [Attribute]s are optional and may be encased within
-/// brackets after the name of an element.
+///   brackets after the name of an element.
 ///     - Supplying only an attribute [name](Attribute::name),
-/// only checks if the element contains the attribute.
+///       only checks if the element contains the attribute.
 ///     - Supplying the attribute name and
-/// [value](Attribute::value) separated by an equals symbol `=`,
-/// will then check if the attribute equals the supplied value.
+///       [value](Attribute::value) separated by an equals symbol `=`,
+///       will then check if the attribute equals the supplied value.
 ///
 /// # Examples:
 /// Searching using [element names](Element::name) and wildcards `*`:
@@ -178,6 +234,24 @@ impl TempElement {
         utility::contains_attribute(&self.attributes, name)
     }
 
+    // Convert into an element that is not shared, such as a spine
+    // `itemref`, which is not the parent of its children
+    pub(crate) fn convert(self) -> Element {
+        let children = self.children.map(|vec| {
+            vec.into_iter()
+                .map(|child| child.convert_to_shared(Weak::new()))
+                .collect()
+        });
+
+        Element {
+            name: self.name,
+            value: self.value,
+            attributes: self.attributes,
+            children,
+            parent: Weak::new(),
+        }
+    }
+
     pub(crate) fn convert_to_shared(self, parent: Weak<Element>) -> Shared<Element> {
         Shared::new_cyclic(|weak| {
             let children = self.children.map(|vec| {
@@ -217,7 +291,7 @@ impl TempElement {
 /// let child_element = element.get_child("role").unwrap();
 /// assert_eq!("aut", child_element.value());
 /// ```
-#[derive(Debug, Default)]
+#[derive(Clone, Debug, Default)]
 pub struct Element {
     pub(super) name: String,
     pub(super) value: String,
@@ -353,15 +427,18 @@ impl Deref for Parent {
 ///
 /// assert_eq!("application/xhtml+xml", value);
 /// ```
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Attribute {
-    name: String,
-    value: String,
+    name: Cow<'static, str>,
+    value: Cow<'static, str>,
 }
 
 impl Attribute {
     pub(crate) fn new(name: String, value: String) -> Self {
-        Self { name, value }
+        Self {
+            name: intern(name),
+            value: intern(value),
+        }
     }
 
     pub fn name(&self) -> &str {
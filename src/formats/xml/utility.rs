@@ -18,6 +18,20 @@ pub(crate) fn get_attribute<'a>(attributes: &'a [Attribute], field: &str) -> Opt
         .map(|attribute| attribute.value())
 }
 
+// Retrieve the attributes that are not modeled, excluding namespace declarations
+pub(crate) fn extra_attributes<'a>(
+    attributes: &'a [Attribute],
+    modeled: &[&str],
+) -> Vec<&'a Attribute> {
+    attributes
+        .iter()
+        .filter(|attribute| {
+            let name = attribute.name();
+            name != "xmlns" && !name.starts_with("xmlns:") && !modeled.contains(&name)
+        })
+        .collect()
+}
+
 pub(crate) fn contains_attribute(attributes: &[Attribute], field: &str) -> bool {
     attributes
         .iter()
@@ -26,9 +40,9 @@ pub(crate) fn contains_attribute(attributes: &[Attribute], field: &str) -> bool
 
 // Find a specific attribute that equals a certain value for an element
 pub(crate) fn equals_attribute_by_value(element: &Element, field: &str, value: &str) -> bool {
-    element.get_attribute(field).map_or(false, |attribute| {
-        attribute.split_whitespace().any(|slice| slice == value)
-    })
+    element
+        .get_attribute(field)
+        .is_some_and(|attribute| attribute.split_whitespace().any(|slice| slice == value))
 }
 
 // Find the first element that possesses a specific attribute
@@ -0,0 +1,3 @@
+//! Interoperability with other publication formats and reading systems.
+
+pub mod rwpm;
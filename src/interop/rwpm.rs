@@ -0,0 +1,222 @@
+//! Export of an epub as a Readium Web Publication Manifest (RWPM),
+//! allowing web readers built on Readium to consume parsed epubs.
+//!
+//! Hrefs within the manifest are paths within the epub archive, such
+//! as `OPS/chapter_001.xhtml`, and are therefore relative to the
+//! location the epub is served from. A `self` link is not included as
+//! the location of the publication is unknown.
+//!
+//! # Examples
+//! Exporting a manifest:
+//! ```
+//! # use rbook::Ebook;
+//! use rbook::interop::rwpm;
+//!
+//! let epub = rbook::Epub::new("tests/ebooks/example_epub").unwrap();
+//! let json = rwpm::to_json(&epub);
+//!
+//! assert!(json.starts_with(r#"{"@context":"https://readium.org/webpub-manifest/context.jsonld""#));
+//! assert!(json.contains(r#"{"href":"EPUB/c1.xhtml","type":"application/xhtml+xml"}"#));
+//! ```
+
+use std::fmt::Write;
+
+use crate::formats::epub::{constants, Epub};
+use crate::formats::xml::Element;
+
+const CONTEXT: &str = "https://readium.org/webpub-manifest/context.jsonld";
+const BOOK_TYPE: &str = "http://schema.org/Book";
+
+/// Serialize an epub into a Readium Web Publication Manifest.
+///
+/// The manifest contains:
+/// - `metadata`: The title, identifier, language, dates, description,
+///   authors, contributors, publishers, subjects, and reading progression.
+/// - `readingOrder`: The manifest items referenced by the spine.
+/// - `resources`: All other manifest items. The cover image and
+///   navigation document are marked with the `cover` and `contents`
+///   relations.
+/// - `toc`: The table of contents in its nested form.
+///
+/// Fields without a value are omitted.
+pub fn to_json(epub: &Epub) -> String {
+    let mut json = String::new();
+    let manifest = Value::Object(vec![
+        ("@context", Value::from(CONTEXT)),
+        ("metadata", metadata(epub)),
+        ("readingOrder", Value::Array(reading_order(epub))),
+        ("resources", Value::Array(resources(epub))),
+        ("toc", Value::Array(toc(epub, epub.toc().elements()))),
+    ]);
+
+    manifest.write(&mut json);
+    json
+}
+
+fn metadata(epub: &Epub) -> Value {
+    let metadata = epub.metadata();
+    let text = |element: Option<&Element>| element.map(|element| Value::from(element.value()));
+    let list = |elements: Vec<&Element>| {
+        let values: Vec<_> = elements
+            .into_iter()
+            .map(|element| Value::from(element.value()))
+            .collect();
+        (!values.is_empty()).then_some(Value::Array(values))
+    };
+    let reading_progression = epub
+        .spine()
+        .get_attribute(constants::PAGE_PROGRESSION_DIRECTION)
+        .filter(|direction| ["ltr", "rtl"].contains(direction))
+        .unwrap_or("auto");
+
+    let fields = [
+        ("@type", Some(Value::from(BOOK_TYPE))),
+        ("title", text(metadata.title())),
+        ("identifier", text(metadata.unique_identifier())),
+        ("language", text(metadata.language())),
+        ("modified", text(metadata.modified())),
+        ("published", text(metadata.date())),
+        ("description", text(metadata.description())),
+        ("author", list(metadata.creators())),
+        ("contributor", list(metadata.contributors())),
+        ("publisher", list(metadata.publisher())),
+        ("subject", list(metadata.subject())),
+        ("readingProgression", Some(Value::from(reading_progression))),
+    ];
+
+    Value::Object(
+        fields
+            .into_iter()
+            .filter_map(|(name, value)| Some((name, value?)))
+            .collect(),
+    )
+}
+
+fn reading_order(epub: &Epub) -> Vec<Value> {
+    epub.spine()
+        .elements()
+        .into_iter()
+        .filter_map(|spine_element| epub.manifest().by_id(spine_element.name()))
+        .map(|element| link(epub, element, None))
+        .collect()
+}
+
+fn resources(epub: &Epub) -> Vec<Value> {
+    let cover = epub.cover_image();
+    let nav = epub.manifest().by_property(constants::NAV_PROPERTY);
+    let is = |other: Option<&Element>, element: &Element| {
+        other.is_some_and(|other| std::ptr::eq(other, element))
+    };
+
+    epub.manifest()
+        .elements()
+        .into_iter()
+        .filter(|element| !epub.spine().contains_idref(element.name()))
+        .map(|element| {
+            let rel = if is(cover, element) {
+                Some("cover")
+            } else if is(nav, element) {
+                Some("contents")
+            } else {
+                None
+            };
+            link(epub, element, rel)
+        })
+        .collect()
+}
+
+fn toc(epub: &Epub, elements: Vec<&Element>) -> Vec<Value> {
+    elements
+        .into_iter()
+        .map(|element| {
+            let mut fields = vec![
+                ("href", Value::from(epub.toc_archive_href(element.value()))),
+                ("title", Value::from(element.name())),
+            ];
+            let children = toc(epub, element.children());
+
+            if !children.is_empty() {
+                fields.push(("children", Value::Array(children)));
+            }
+            Value::Object(fields)
+        })
+        .collect()
+}
+
+// Create a link object from a manifest element
+fn link(epub: &Epub, element: &Element, rel: Option<&str>) -> Value {
+    let mut fields = vec![("href", Value::from(epub.archive_href(element.value())))];
+
+    if let Some(media_type) = element.get_attribute(constants::MEDIA_TYPE) {
+        fields.push(("type", Value::from(media_type)));
+    }
+    if let Some(rel) = rel {
+        fields.push(("rel", Value::from(rel)));
+    }
+
+    Value::Object(fields)
+}
+
+// Minimal json representation, as only serialization is required
+enum Value {
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(&'static str, Value)>),
+}
+
+impl Value {
+    fn write(&self, json: &mut String) {
+        match self {
+            Self::String(string) => write_string(json, string),
+            Self::Array(values) => {
+                json.push('[');
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        json.push(',');
+                    }
+                    value.write(json);
+                }
+                json.push(']');
+            }
+            Self::Object(fields) => {
+                json.push('{');
+                for (index, (name, value)) in fields.iter().enumerate() {
+                    if index > 0 {
+                        json.push(',');
+                    }
+                    write_string(json, name);
+                    json.push(':');
+                    value.write(json);
+                }
+                json.push('}');
+            }
+        }
+    }
+}
+
+impl<T: Into<String>> From<T> for Value {
+    fn from(string: T) -> Self {
+        Self::String(string.into())
+    }
+}
+
+fn write_string(json: &mut String, string: &str) {
+    json.push('"');
+
+    for character in string.chars() {
+        match character {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            character if character.is_control() => {
+                // Writing to a string cannot fail
+                let _ = write!(json, "\\u{:04x}", character as u32);
+            }
+            character => json.push(character),
+        }
+    }
+
+    json.push('"');
+}
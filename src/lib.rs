@@ -52,6 +52,10 @@ mod archive;
 mod formats;
 mod utility;
 
+pub mod interop;
+pub mod library;
+pub mod lint;
+
 #[cfg(feature = "reader")]
 mod reader;
 #[cfg(feature = "statistics")]
@@ -65,7 +69,22 @@ pub use self::statistics::Stats;
 
 pub mod epub {
     //! Access to the contents that make up an epub.
-    pub use super::formats::epub::{Guide, Manifest, Metadata, Spine, Toc};
+    pub use super::archive::{Compression, DecodedName, EntryInfo};
+    #[cfg(feature = "reader")]
+    pub use super::formats::epub::EpubReaderSettings;
+    #[cfg(feature = "image-meta")]
+    pub use super::formats::epub::ImageFormat;
+    #[cfg(feature = "relators")]
+    pub use super::formats::epub::Relator;
+    pub use super::formats::epub::{
+        AppleDisplayOptions, Collection, ContentDecryptor, Duration, EpubSettings, FallbackChain,
+        FilenameDecoding, Guide, HeadingOptions, ImageInfo, LayoutHints, LocalizedMetadata,
+        Manifest, MediaMetadata, Metadata, ParseLevel, ReadingSystemProfile, RemoteReferences,
+        Resource, SearchKeyGroup, SearchKeyMap, SearchKeyMatch, Spine, SpineWeights, Spread,
+        TextDirection, TitleKind, TitleStyle, Toc, VendorMetadata, Vocabulary, VocabularyEntries,
+    };
+    #[cfg(feature = "classification")]
+    pub use super::formats::epub::{Classification, ClassificationScheme};
 }
 
 pub mod result {
@@ -79,6 +98,12 @@ pub mod result {
 #[cfg(feature = "reader")]
 pub mod read {
     //! Access to reader contents.
+    pub use super::reader::annotation::{TextPositionSelector, TextQuoteSelector};
     pub use super::reader::content::{Content, ContentType};
-    pub use super::reader::ReaderIter;
+    #[cfg(feature = "html")]
+    pub use super::reader::dom::{Dom, DomElement, DomNode};
+    #[cfg(feature = "segmentation")]
+    pub use super::reader::segmentation::Segment;
+    pub use super::reader::text::TextMap;
+    pub use super::reader::{ErrorBehavior, ReaderIter};
 }
@@ -0,0 +1,231 @@
+//! Scanning of directories that contain ebooks.
+//!
+//! # Examples
+//! Scanning a directory:
+//! ```
+//! use rbook::library::{self, ScanOptions};
+//!
+//! let scan = library::scan("tests/ebooks", &ScanOptions::new()).unwrap();
+//!
+//! let record = scan
+//!     .records()
+//!     .iter()
+//!     .find(|record| record.path().ends_with("moby-dick.epub"))
+//!     .unwrap();
+//!
+//! assert_eq!(Some("Moby-Dick"), record.title());
+//! assert_eq!(vec!["Herman Melville"], record.authors());
+//! ```
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+
+use crate::formats::epub::{constants, Epub, EpubSettings, ParseLevel};
+use crate::formats::{EbookError, EbookResult};
+
+/// Options to customize the behavior of [scan].
+#[derive(Clone, Debug)]
+pub struct ScanOptions {
+    recursive: bool,
+    include_cover: bool,
+    threads: usize,
+}
+
+impl ScanOptions {
+    /// Create options with default values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Scan subdirectories. Default is `true`.
+    pub fn recursive(mut self, recursive: bool) -> Self {
+        self.recursive = recursive;
+        self
+    }
+
+    /// Retrieve the cover image data of each ebook. Default is `false`.
+    pub fn include_cover(mut self, include_cover: bool) -> Self {
+        self.include_cover = include_cover;
+        self
+    }
+
+    /// Number of threads used to open ebooks. Default is `1`.
+    /// A value of `0` is treated as `1`.
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = threads;
+        self
+    }
+}
+
+impl Default for ScanOptions {
+    fn default() -> Self {
+        Self {
+            recursive: true,
+            include_cover: false,
+            threads: 1,
+        }
+    }
+}
+
+/// Lightweight summary of an ebook found during a [scan].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LibraryRecord {
+    path: PathBuf,
+    title: Option<String>,
+    authors: Vec<String>,
+    cover: Option<Vec<u8>>,
+}
+
+impl LibraryRecord {
+    /// Retrieve the path of the ebook file or directory.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Retrieve the main title of the ebook.
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    /// Retrieve the names of all creators of the ebook.
+    pub fn authors(&self) -> Vec<&str> {
+        self.authors.iter().map(String::as_str).collect()
+    }
+
+    /// Retrieve the cover image data of the ebook if
+    /// [include_cover](ScanOptions::include_cover) is enabled.
+    pub fn cover(&self) -> Option<&[u8]> {
+        self.cover.as_deref()
+    }
+}
+
+/// Result of a [scan]. Ebooks that fail to open do not stop a scan,
+/// and are available from [errors()](Self::errors) instead.
+#[derive(Debug)]
+pub struct Scan {
+    records: Vec<LibraryRecord>,
+    errors: Vec<(PathBuf, EbookError)>,
+}
+
+impl Scan {
+    /// Retrieve records of all ebooks that were opened successfully,
+    /// ordered by path.
+    pub fn records(&self) -> &[LibraryRecord] {
+        &self.records
+    }
+
+    /// Retrieve the paths and errors of ebooks that failed to open,
+    /// ordered by path.
+    pub fn errors(&self) -> &[(PathBuf, EbookError)] {
+        &self.errors
+    }
+}
+
+/// Walk a directory and open each supported ebook, creating a
+/// [LibraryRecord] for each. Ebooks are opened with
+/// [ParseLevel::MetadataOnly](crate::epub::ParseLevel::MetadataOnly),
+/// or [ParseLevel::Structure](crate::epub::ParseLevel::Structure)
+/// if covers are included.
+///
+/// Supported ebooks are files with the `.epub` extension and unzipped
+/// epub directories, which contain `META-INF/container.xml`.
+///
+/// # Errors
+/// If the given directory or one of its subdirectories cannot be read,
+/// an [EbookError] will be returned.
+pub fn scan<P: AsRef<Path>>(dir: P, options: &ScanOptions) -> EbookResult<Scan> {
+    let mut paths = Vec::new();
+    find_ebooks(dir.as_ref(), options.recursive, &mut paths)?;
+    paths.sort();
+
+    let threads = options.threads.max(1);
+    let chunk_size = (paths.len() / threads + usize::from(paths.len() % threads != 0)).max(1);
+
+    // Each thread opens its own portion of the ebooks
+    let results: Vec<_> = thread::scope(|scope| {
+        let handles: Vec<_> = paths
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|path| (path, create_record(path, options)))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap_or_default())
+            .collect()
+    });
+
+    let mut scan = Scan {
+        records: Vec::new(),
+        errors: Vec::new(),
+    };
+
+    for (path, result) in results {
+        match result {
+            Ok(record) => scan.records.push(record),
+            Err(error) => scan.errors.push((path.clone(), error)),
+        }
+    }
+
+    Ok(scan)
+}
+
+fn find_ebooks(dir: &Path, recursive: bool, paths: &mut Vec<PathBuf>) -> EbookResult<()> {
+    let entries = fs::read_dir(dir).map_err(|error| EbookError::IO {
+        cause: "Unable to read directory".to_string(),
+        description: format!("Path: '{:?}': {error}", dir.display()),
+    })?;
+
+    for entry in entries.filter_map(Result::ok) {
+        let path = entry.path();
+
+        if path.is_dir() {
+            // Unzipped epub directories are not scanned further
+            if path.join(constants::CONTAINER).is_file() {
+                paths.push(path);
+            } else if recursive {
+                find_ebooks(&path, recursive, paths)?;
+            }
+        } else if path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("epub"))
+        {
+            paths.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+fn create_record(path: &Path, options: &ScanOptions) -> EbookResult<LibraryRecord> {
+    // Covers require the manifest
+    let parse_level = match options.include_cover {
+        true => ParseLevel::Structure,
+        false => ParseLevel::MetadataOnly,
+    };
+    let epub = Epub::open_with(path, EpubSettings::new().parse_level(parse_level))?;
+    let metadata = epub.metadata();
+
+    let cover = match (options.include_cover, epub.cover_image()) {
+        (true, Some(cover_image)) => Some(epub.read_bytes_file(cover_image.value())?),
+        _ => None,
+    };
+
+    Ok(LibraryRecord {
+        path: path.to_path_buf(),
+        title: metadata.title().map(|title| title.value().to_string()),
+        authors: metadata
+            .creators()
+            .into_iter()
+            .map(|creator| creator.value().to_string())
+            .collect(),
+        cover,
+    })
+}
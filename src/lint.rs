@@ -0,0 +1,363 @@
+//! Quality checks of an epub, structured as rules that produce findings.
+//!
+//! Rules implement the [Rule] trait and are grouped into a [RuleSet],
+//! which is run using [Epub::lint]. The rules of rbook are available
+//! using [RuleSet::recommended], and custom rules, such as those of a
+//! publisher's house style, may be registered alongside them.
+//!
+//! # Examples
+//! Registering a custom rule:
+//! ```
+//! use rbook::{Ebook, Epub};
+//! use rbook::epub::Resource;
+//! use rbook::lint::{Finding, Rule, RuleSet, Severity};
+//!
+//! // Every chapter must have exactly one `h1` heading
+//! struct SingleHeading;
+//!
+//! impl Rule for SingleHeading {
+//!     fn id(&self) -> &str {
+//!         "single-heading"
+//!     }
+//!
+//!     fn check(&self, epub: &Epub) -> Vec<Finding> {
+//!         epub.spine()
+//!             .elements()
+//!             .into_iter()
+//!             .filter_map(|itemref| {
+//!                 let content = epub.read_resource(Resource::Id(itemref.name())).ok()?;
+//!                 let headings = content.matches("<h1").count();
+//!
+//!                 (headings != 1).then(|| {
+//!                     Finding::new(Severity::Warning, format!("Found {headings} h1 headings"))
+//!                         .at(itemref.name())
+//!                 })
+//!             })
+//!             .collect()
+//!     }
+//! }
+//!
+//! let epub = Epub::new("tests/ebooks/fallback_epub").unwrap();
+//! let rules = RuleSet::recommended().rule(SingleHeading);
+//! let findings = epub.lint(&rules);
+//!
+//! // Each chapter has a single heading
+//! assert!(findings.iter().all(|finding| finding.rule() != "single-heading"));
+//!
+//! // The fallback of an image that is not referenced by any chapter
+//! let unreachable = findings
+//!     .iter()
+//!     .find(|finding| finding.rule() == "unreachable-content")
+//!     .unwrap();
+//! assert_eq!(Severity::Warning, unreachable.severity());
+//! assert_eq!(Some("chart-table"), unreachable.location());
+//! ```
+
+use std::collections::HashSet;
+use std::fmt::{Debug, Formatter};
+
+use crate::formats::epub::{Epub, Resource};
+
+/// A check that inspects an epub and reports its findings.
+///
+/// See the [module](self) documentation for an example.
+pub trait Rule {
+    /// Retrieve the identifier of the rule, such as `spine-references`,
+    /// which is recorded in each of its [findings](Finding::rule).
+    fn id(&self) -> &str;
+
+    /// Inspect an epub, returning a finding for each problem.
+    fn check(&self, epub: &Epub) -> Vec<Finding>;
+}
+
+/// The severity of a [Finding], ordered from least to most severe.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// Noteworthy, but not a problem.
+    Info,
+    /// A problem that reading systems generally tolerate.
+    Warning,
+    /// A problem that violates the epub specification or
+    /// prevents content from being displayed.
+    Error,
+}
+
+/// A problem reported by a [Rule].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finding {
+    rule: String,
+    severity: Severity,
+    message: String,
+    location: Option<String>,
+}
+
+impl Finding {
+    /// Create a finding with a message describing the problem.
+    pub fn new<S: Into<String>>(severity: Severity, message: S) -> Self {
+        Self {
+            rule: String::new(),
+            severity,
+            message: message.into(),
+            location: None,
+        }
+    }
+
+    /// Set where the problem is located, such as a manifest `id`
+    /// or a path within the container.
+    pub fn at<S: Into<String>>(mut self, location: S) -> Self {
+        self.location = Some(location.into());
+        self
+    }
+
+    /// Retrieve the identifier of the rule that reported the finding.
+    pub fn rule(&self) -> &str {
+        &self.rule
+    }
+
+    /// Retrieve the severity of the finding.
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// Retrieve the message describing the problem.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Retrieve where the problem is located, if known.
+    pub fn location(&self) -> Option<&str> {
+        self.location.as_deref()
+    }
+}
+
+/// The rules to run using [Epub::lint].
+#[derive(Default)]
+pub struct RuleSet {
+    rules: Vec<Box<dyn Rule>>,
+}
+
+impl RuleSet {
+    /// Create an empty rule set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a rule set containing the rules of rbook:
+    /// - `spine-references`: Spine entries must reference a manifest item.
+    /// - `missing-resources`: Manifest items must exist within the container.
+    /// - `href-case`: Manifest hrefs should match the case of their files,
+    ///   reporting items that only resolve when ignoring case, such as
+    ///   when [href_case_insensitive](crate::epub::EpubSettings::href_case_insensitive)
+    ///   is enabled.
+    /// - `required-metadata`: The title, language, and unique identifier
+    ///   must be present, along with the modified date for epub 3.
+    /// - `unreachable-content`: Content documents should be reachable
+    ///   from the spine.
+    /// - `undeclared-resources`: Files within the container should be
+    ///   declared in the manifest.
+    pub fn recommended() -> Self {
+        Self::new()
+            .rule(SpineReferences)
+            .rule(MissingResources)
+            .rule(HrefCase)
+            .rule(RequiredMetadata)
+            .rule(UnreachableContent)
+            .rule(UndeclaredResources)
+    }
+
+    /// Add a rule to the set.
+    pub fn rule<R: Rule + 'static>(mut self, rule: R) -> Self {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    /// Retrieve the identifiers of the rules in the set.
+    pub fn ids(&self) -> Vec<&str> {
+        self.rules.iter().map(|rule| rule.id()).collect()
+    }
+
+    pub(crate) fn run(&self, epub: &Epub) -> Vec<Finding> {
+        self.rules
+            .iter()
+            .flat_map(|rule| {
+                rule.check(epub).into_iter().map(|mut finding| {
+                    finding.rule = rule.id().to_string();
+                    finding
+                })
+            })
+            .collect()
+    }
+}
+
+impl Debug for RuleSet {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("RuleSet")
+            .field("rules", &self.ids())
+            .finish()
+    }
+}
+
+struct SpineReferences;
+
+impl Rule for SpineReferences {
+    fn id(&self) -> &str {
+        "spine-references"
+    }
+
+    fn check(&self, epub: &Epub) -> Vec<Finding> {
+        epub.spine()
+            .elements()
+            .into_iter()
+            .filter(|itemref| epub.manifest().by_id(itemref.name()).is_none())
+            .map(|itemref| {
+                Finding::new(
+                    Severity::Error,
+                    "Spine entry does not reference a manifest item",
+                )
+                .at(itemref.name())
+            })
+            .collect()
+    }
+}
+
+struct MissingResources;
+
+impl Rule for MissingResources {
+    fn id(&self) -> &str {
+        "missing-resources"
+    }
+
+    fn check(&self, epub: &Epub) -> Vec<Finding> {
+        epub.manifest()
+            .elements()
+            .into_iter()
+            .filter(|element| !element.value().contains("://"))
+            .filter(|element| epub.resource_info(Resource::Id(element.name())).is_err())
+            .map(|element| {
+                Finding::new(
+                    Severity::Error,
+                    format!("Resource '{}' does not exist", element.value()),
+                )
+                .at(element.name())
+            })
+            .collect()
+    }
+}
+
+struct HrefCase;
+
+impl Rule for HrefCase {
+    fn id(&self) -> &str {
+        "href-case"
+    }
+
+    fn check(&self, epub: &Epub) -> Vec<Finding> {
+        epub.manifest()
+            .elements()
+            .into_iter()
+            .filter(|element| !element.value().contains("://"))
+            .filter_map(|element| {
+                let path = epub.case_mismatched_path(element.value())?;
+
+                Some(
+                    Finding::new(
+                        Severity::Warning,
+                        format!(
+                            "Resource '{}' only matches '{path}' when ignoring case",
+                            element.value()
+                        ),
+                    )
+                    .at(element.name()),
+                )
+            })
+            .collect()
+    }
+}
+
+struct RequiredMetadata;
+
+impl Rule for RequiredMetadata {
+    fn id(&self) -> &str {
+        "required-metadata"
+    }
+
+    fn check(&self, epub: &Epub) -> Vec<Finding> {
+        let metadata = epub.metadata();
+        let mut findings = Vec::new();
+        let required = [
+            ("dc:title", metadata.title().is_some()),
+            ("dc:language", metadata.language().is_some()),
+            ("dc:identifier", metadata.unique_identifier().is_some()),
+        ];
+
+        for (name, is_present) in required {
+            if !is_present {
+                findings.push(Finding::new(
+                    Severity::Error,
+                    format!("Missing required '{name}' metadata"),
+                ));
+            }
+        }
+        if metadata.version().starts_with('3') && metadata.modified().is_none() {
+            findings.push(Finding::new(
+                Severity::Warning,
+                "Missing 'dcterms:modified' metadata required by epub 3",
+            ));
+        }
+
+        findings
+    }
+}
+
+struct UnreachableContent;
+
+impl Rule for UnreachableContent {
+    fn id(&self) -> &str {
+        "unreachable-content"
+    }
+
+    fn check(&self, epub: &Epub) -> Vec<Finding> {
+        epub.unreachable_content()
+            .into_iter()
+            .map(|element| {
+                Finding::new(
+                    Severity::Warning,
+                    "Content document is not reachable from the spine",
+                )
+                .at(element.name())
+            })
+            .collect()
+    }
+}
+
+struct UndeclaredResources;
+
+impl Rule for UndeclaredResources {
+    fn id(&self) -> &str {
+        "undeclared-resources"
+    }
+
+    fn check(&self, epub: &Epub) -> Vec<Finding> {
+        let Ok(entries) = epub.container_entries() else {
+            return Vec::new();
+        };
+        let package = epub.root_file().to_string_lossy().replace('\\', "/");
+        let declared: HashSet<_> = epub
+            .manifest()
+            .elements()
+            .into_iter()
+            .map(|element| epub.archive_href(element.value()))
+            .collect();
+
+        entries
+            .iter()
+            .map(|entry| entry.path())
+            .filter(|path| *path != "mimetype" && *path != package)
+            .filter(|path| !path.starts_with("META-INF/"))
+            .filter(|path| !declared.contains(*path))
+            .map(|path| {
+                Finding::new(Severity::Info, "File is not declared in the manifest").at(path)
+            })
+            .collect()
+    }
+}
@@ -1,20 +1,54 @@
+pub mod annotation;
 pub mod content;
+#[cfg(feature = "html")]
+pub mod dom;
+#[cfg(feature = "segmentation")]
+pub mod segmentation;
+pub mod text;
 
+#[cfg(feature = "multi-thread")]
+use std::cell::{OnceCell as LocalOnceCell, RefCell};
+use std::collections::HashMap;
+#[cfg(feature = "multi-thread")]
+use std::collections::VecDeque;
 use std::fmt::Debug;
+#[cfg(feature = "multi-thread")]
+use std::fmt::Formatter;
+#[cfg(feature = "multi-thread")]
+use std::sync::atomic::{AtomicBool, Ordering};
+#[cfg(feature = "multi-thread")]
+use std::sync::mpsc::{self, Receiver, Sender};
+#[cfg(feature = "multi-thread")]
+use std::sync::Arc;
+#[cfg(feature = "multi-thread")]
+use std::thread;
 use thiserror::Error;
 
+use crate::formats::epub::SpineWeights;
 use crate::formats::EbookError;
 use crate::reader::content::Content;
+use crate::utility::OnceCell;
 
 /// Result type with [ReaderError] as the error.
 pub type ReaderResult<T> = Result<T, ReaderError>;
 
+// Loads the data of a page, run on a background thread
+#[cfg(feature = "multi-thread")]
+pub(crate) type PageLoader = Box<dyn FnOnce() -> Result<Vec<u8>, EbookError> + Send>;
+
 pub(crate) trait Readable: Debug {
     fn page_count(&self) -> usize;
+    // Relative weights of the pages used to calculate reading progress
+    fn weights(&self) -> SpineWeights;
     // Reader navigation using a string
     fn navigate_str(&self, path: &str) -> Option<ReaderResult<usize>>;
     // Reader navigation using an index
-    fn navigate(&self, index: usize) -> Option<ReaderResult<Content>>;
+    fn navigate(&self, index: usize) -> Option<ReaderResult<Content<'_>>>;
+    // Retrieve a loader of the data of a page to run on a background thread
+    #[cfg(feature = "multi-thread")]
+    fn load_page(&self, index: usize) -> Option<PageLoader>;
+    // Reader navigation using an index and page data that was already loaded
+    fn navigate_loaded(&self, index: usize, data: Vec<u8>) -> Option<ReaderResult<Content<'_>>>;
 }
 
 /// Possible errors for [Reader]
@@ -28,7 +62,41 @@ pub enum ReaderError {
     InvalidReference { cause: String, description: String },
     /// When retrieval of content fails, such as invalid utf-8.
     #[error("[NoContent Error]{0}")]
-    NoContent(EbookError),
+    NoContent(#[source] EbookError),
+}
+
+/// Behavior of a [Reader] when the content of a page cannot be retrieved,
+/// such as a missing or unreadable file.
+///
+/// # Examples
+/// Replacing unreadable pages:
+/// ```
+/// use rbook::Ebook;
+/// use rbook::epub::EpubReaderSettings;
+/// use rbook::read::ErrorBehavior;
+///
+/// let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+///
+/// let placeholder = ErrorBehavior::Replace("<p>Unavailable</p>".to_string());
+/// let settings = EpubReaderSettings::new().on_error(placeholder);
+/// let reader = epub.reader_with(settings);
+///
+/// assert!(reader.iter().all(|content| content.is_ok()));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum ErrorBehavior {
+    /// Return the error. Sequential reading may continue afterward.
+    #[default]
+    Stop,
+    /// Continue to the next page that can be retrieved when using
+    /// [next_page()](Reader::next_page),
+    /// [previous_page()](Reader::previous_page), or
+    /// [iter()](Reader::iter). Pages retrieved directly by index or
+    /// string still return the error.
+    Skip,
+    /// Return content with the given placeholder as its data instead.
+    /// Information such as the path of the page is retained if possible.
+    Replace(String),
 }
 
 /// Reader that allows traversal of an ebook file by file.
@@ -112,14 +180,87 @@ pub enum ReaderError {
 #[derive(Debug, Clone)]
 pub struct Reader<'a> {
     ebook: &'a dyn Readable,
+    // Indexes of the ebook pages that the reader traverses
+    pages: Vec<usize>,
+    on_error: ErrorBehavior,
     current_index: usize,
+    // Weights of the traversed pages, retrieved upon the
+    // first progress calculation
+    weights: OnceCell<SpineWeights>,
+    #[cfg(feature = "multi-thread")]
+    prefetch: Prefetch,
 }
 
 impl<'a> Reader<'a> {
     pub(crate) fn new(ebook: &'a dyn Readable) -> Self {
+        Self::with_pages(
+            ebook,
+            (0..ebook.page_count()).collect(),
+            ErrorBehavior::Stop,
+        )
+    }
+
+    // Create a reader that only traverses the given ebook pages
+    pub(crate) fn with_pages(
+        ebook: &'a dyn Readable,
+        pages: Vec<usize>,
+        on_error: ErrorBehavior,
+    ) -> Self {
         Self {
             ebook,
+            pages,
+            on_error,
             current_index: 0,
+            weights: OnceCell::new(),
+            #[cfg(feature = "multi-thread")]
+            prefetch: Prefetch::default(),
+        }
+    }
+
+    // Set the number of pages after the current index to load in the background
+    #[cfg(feature = "multi-thread")]
+    pub(crate) fn set_prefetch(&mut self, count: usize) {
+        self.prefetch.count = count;
+        self.prefetch_from(self.current_index + 1);
+    }
+
+    // Load pages in the background starting from the given index
+    #[cfg(feature = "multi-thread")]
+    fn prefetch_from(&self, start: usize) {
+        let end = (start + self.prefetch.count).min(self.page_count());
+        let mut pending = self.prefetch.pending.borrow_mut();
+
+        // Discard pages outside the prefetch range, cancelling their
+        // loading if the worker has not started it yet
+        pending.retain(|(index, _)| (start..end).contains(index));
+
+        for index in start..end {
+            if pending
+                .iter()
+                .all(|(pending_index, _)| *pending_index != index)
+            {
+                let page = self.ebook.load_page(self.pages[index]).and_then(|loader| {
+                    self.prefetch.worker.get_or_init(Worker::spawn).load(loader)
+                });
+
+                if let Some(page) = page {
+                    pending.push_back((index, page));
+                }
+            }
+        }
+    }
+
+    // Retrieve a page that was loaded in the background, if any
+    #[cfg(feature = "multi-thread")]
+    fn take_prefetched(&self, page_index: usize) -> Option<ReaderResult<Content<'a>>> {
+        let mut pending = self.prefetch.pending.borrow_mut();
+        let position = pending.iter().position(|(index, _)| *index == page_index)?;
+        let (_, page) = pending.remove(position)?;
+
+        // Fallback to loading the page on the current thread if it failed to load
+        match page.data.recv() {
+            Ok(Ok(data)) => self.ebook.navigate_loaded(self.pages[page_index], data),
+            _ => None,
         }
     }
 
@@ -133,7 +274,49 @@ impl<'a> Reader<'a> {
     /// The maximum value of the reader index is `page_count - 1`,
     /// similar to an array.
     pub fn page_count(&self) -> usize {
-        self.ebook.page_count()
+        self.pages.len()
+    }
+
+    /// Retrieve the reading progress of the ebook, ranging from
+    /// `0.0` to `1.0`, at the reader's current index. The
+    /// `page_fraction` is how far into the current page the reader
+    /// is, such as the scroll position.
+    ///
+    /// Pages are weighted by their size. Pages that are not part of the
+    /// default reading order, or that the reader does not traverse, do
+    /// not contribute to progress. For an epub, the weights are retrieved from
+    /// [Epub::spine_weights](crate::Epub::spine_weights) upon the first
+    /// call and reused by the reader afterwards, making it inexpensive
+    /// to calculate progress frequently.
+    ///
+    /// Returns `None` if progress cannot be determined, such as when
+    /// no page has any weight.
+    ///
+    /// # Examples
+    /// Retrieving progress:
+    /// ```
+    /// # use rbook::Ebook;
+    /// # let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    /// let mut reader = epub.reader();
+    /// assert_eq!(Some(0.0), reader.progress(0.0));
+    ///
+    /// reader.set_current_page(143);
+    /// assert_eq!(Some(1.0), reader.progress(1.0));
+    /// ```
+    pub fn progress(&self, page_fraction: f64) -> Option<f64> {
+        self.weights
+            .get_or_init(|| {
+                let weights = self.ebook.weights();
+                let weights = weights.weights();
+
+                SpineWeights::new(
+                    self.pages
+                        .iter()
+                        .map(|page| weights.get(*page).copied().unwrap_or_default())
+                        .collect(),
+                )
+            })
+            .progress(self.current_index, page_fraction)
     }
 
     /// Retrieve an iterator to iterate over all the pages of
@@ -141,7 +324,7 @@ impl<'a> Reader<'a> {
     ///
     /// The retrieved iterator does not update the internal
     /// index of the [Reader] instance.
-    pub fn iter(&self) -> ReaderIter {
+    pub fn iter(&self) -> ReaderIter<'_> {
         ReaderIter {
             reader: self,
             index: 0,
@@ -154,8 +337,13 @@ impl<'a> Reader<'a> {
     /// # Errors
     /// Possible errors are described in [ReaderError].
     pub fn current_page(&self) -> ReaderResult<Content<'a>> {
-        self.fetch_page(self.current_index)
-            .expect("Should be within bounds")
+        // The reader has no pages if the ebook has an empty spine
+        self.fetch_page(self.current_index).unwrap_or_else(|| {
+            Err(ReaderError::InvalidReference {
+                cause: "No pages".to_string(),
+                description: "Please ensure the spine contains at least one element.".to_string(),
+            })
+        })
     }
 
     /// Retrieve the next page content.
@@ -163,12 +351,19 @@ impl<'a> Reader<'a> {
     /// # Errors
     /// Possible errors are described in [ReaderError].
     pub fn next_page(&mut self) -> Option<ReaderResult<Content<'a>>> {
-        if self.current_index < self.page_count() - 1 {
-            self.current_index += 1;
-            self.set_current_page(self.current_index)
-        } else {
-            None
+        let start = self.current_index;
+
+        while self.current_index + 1 < self.page_count() {
+            let content = self.set_current_page(self.current_index + 1);
+
+            if !self.is_skipped(&content) {
+                return content;
+            }
         }
+
+        // Restore the index if no page could be retrieved
+        self.current_index = start;
+        None
     }
 
     /// Retrieve the previous page content.
@@ -176,12 +371,24 @@ impl<'a> Reader<'a> {
     /// # Errors
     /// Possible errors are described in [ReaderError].
     pub fn previous_page(&mut self) -> Option<ReaderResult<Content<'a>>> {
-        if self.current_index > 0 {
-            self.current_index -= 1;
-            self.set_current_page(self.current_index)
-        } else {
-            None
+        let start = self.current_index;
+
+        while self.current_index > 0 {
+            let content = self.set_current_page(self.current_index - 1);
+
+            if !self.is_skipped(&content) {
+                return content;
+            }
         }
+
+        // Restore the index if no page could be retrieved
+        self.current_index = start;
+        None
+    }
+
+    // Check if sequential reading should continue past a page
+    fn is_skipped(&self, content: &Option<ReaderResult<Content<'a>>>) -> bool {
+        self.on_error == ErrorBehavior::Skip && matches!(content, Some(Err(_)))
     }
 
     /// Retrieve the content of a page and update the
@@ -192,7 +399,12 @@ impl<'a> Reader<'a> {
     pub fn set_current_page(&mut self, page_index: usize) -> Option<ReaderResult<Content<'a>>> {
         if page_index < self.page_count() {
             self.current_index = page_index;
-            self.fetch_page(page_index)
+            let content = self.fetch_page(page_index);
+
+            #[cfg(feature = "multi-thread")]
+            self.prefetch_from(page_index + 1);
+
+            content
         } else {
             None
         }
@@ -204,7 +416,7 @@ impl<'a> Reader<'a> {
     /// # Errors
     /// Possible errors are described in [ReaderError].
     pub fn set_current_page_str(&mut self, path: &str) -> Option<ReaderResult<Content<'a>>> {
-        match self.ebook.navigate_str(path) {
+        match self.navigate_str(path) {
             Some(Ok(index)) => self.set_current_page(index),
             Some(Err(error)) => Some(Err(error)),
             _ => None,
@@ -217,7 +429,28 @@ impl<'a> Reader<'a> {
     /// # Errors
     /// Possible errors are described in [ReaderError].
     pub fn fetch_page(&self, page_index: usize) -> Option<ReaderResult<Content<'a>>> {
-        self.ebook.navigate(page_index)
+        let page = *self.pages.get(page_index)?;
+
+        #[cfg(feature = "multi-thread")]
+        let content = match self.take_prefetched(page_index) {
+            Some(content) => content,
+            None => self.ebook.navigate(page)?,
+        };
+        #[cfg(not(feature = "multi-thread"))]
+        let content = self.ebook.navigate(page)?;
+
+        match (content, &self.on_error) {
+            (Err(_), ErrorBehavior::Replace(placeholder)) => {
+                let data = placeholder.clone().into_bytes();
+
+                // Fallback to content without information if the page cannot be resolved
+                Some(match self.ebook.navigate_loaded(page, data.clone()) {
+                    Some(Ok(content)) => Ok(content),
+                    _ => Ok(Content::new(data, HashMap::new(), Vec::new())),
+                })
+            }
+            (content, _) => Some(content),
+        }
     }
 
     /// Retrieve the content of a page without updating the
@@ -226,12 +459,129 @@ impl<'a> Reader<'a> {
     /// # Errors
     /// Possible errors are described in [ReaderError].
     pub fn fetch_page_str(&self, path: &str) -> Option<ReaderResult<Content<'a>>> {
-        match self.ebook.navigate_str(path) {
+        match self.navigate_str(path) {
             Some(Ok(index)) => self.fetch_page(index),
             Some(Err(error)) => Some(Err(error)),
             _ => None,
         }
     }
+
+    // Retrieve the reader index of a page using a string value.
+    // Pages that the reader does not traverse are not found.
+    fn navigate_str(&self, path: &str) -> Option<ReaderResult<usize>> {
+        match self.ebook.navigate_str(path)? {
+            Ok(page) => self.pages.iter().position(|index| *index == page).map(Ok),
+            Err(error) => Some(Err(error)),
+        }
+    }
+}
+
+// Pages of a reader that are loaded ahead of time
+#[cfg(feature = "multi-thread")]
+#[derive(Default)]
+struct Prefetch {
+    count: usize,
+    pending: RefCell<VecDeque<(usize, PendingPage)>>,
+    // Spawned upon the first page to prefetch
+    worker: LocalOnceCell<Worker>,
+}
+
+#[cfg(feature = "multi-thread")]
+impl Clone for Prefetch {
+    // Pages being loaded are not shared between clones
+    fn clone(&self) -> Self {
+        Self {
+            count: self.count,
+            ..Self::default()
+        }
+    }
+}
+
+#[cfg(feature = "multi-thread")]
+impl Debug for Prefetch {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        let pending: Vec<_> = self
+            .pending
+            .borrow()
+            .iter()
+            .map(|(index, _)| *index)
+            .collect();
+
+        fmt.debug_struct("Prefetch")
+            .field("count", &self.count)
+            .field("pending", &pending)
+            .finish()
+    }
+}
+
+// A page queued to be loaded by a worker
+#[cfg(feature = "multi-thread")]
+struct PendingPage {
+    cancelled: Arc<AtomicBool>,
+    data: Receiver<Result<Vec<u8>, EbookError>>,
+}
+
+#[cfg(feature = "multi-thread")]
+impl Drop for PendingPage {
+    // Avoid loading pages that will never be retrieved
+    fn drop(&mut self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+}
+
+// A page for a worker to load, along with where to send its data
+#[cfg(feature = "multi-thread")]
+struct PageJob {
+    loader: PageLoader,
+    cancelled: Arc<AtomicBool>,
+    data: Sender<Result<Vec<u8>, EbookError>>,
+}
+
+// A single background thread that loads the pages of a reader in order,
+// bounding the number of threads regardless of the prefetch count.
+//
+// The thread is detached, so dropping a reader does not wait on a page
+// being loaded. Once the worker is dropped, the thread skips the
+// remaining cancelled pages and stops.
+#[cfg(feature = "multi-thread")]
+struct Worker {
+    jobs: Sender<PageJob>,
+}
+
+#[cfg(feature = "multi-thread")]
+impl Worker {
+    fn spawn() -> Self {
+        let (jobs, receiver) = mpsc::channel::<PageJob>();
+        thread::spawn(move || {
+            for job in receiver {
+                if !job.cancelled.load(Ordering::Relaxed) {
+                    // The page may have been discarded while loading
+                    let _ = job.data.send((job.loader)());
+                }
+            }
+        });
+
+        Self { jobs }
+    }
+
+    // Queue a page to be loaded. Returns `None` if the worker stopped.
+    fn load(&self, loader: PageLoader) -> Option<PendingPage> {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let (data, receiver) = mpsc::channel();
+
+        self.jobs
+            .send(PageJob {
+                loader,
+                cancelled: Arc::clone(&cancelled),
+                data,
+            })
+            .ok()?;
+
+        Some(PendingPage {
+            cancelled,
+            data: receiver,
+        })
+    }
 }
 
 impl<'a> IntoIterator for &'a Reader<'_> {
@@ -256,13 +606,15 @@ impl<'a> Iterator for ReaderIter<'a> {
     type Item = ReaderResult<Content<'a>>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.index >= self.reader.page_count() {
-            self.index -= 1;
-            None
-        } else {
+        while self.index < self.reader.page_count() {
             let current = self.reader.fetch_page(self.index);
             self.index += 1;
-            current
+
+            if !self.reader.is_skipped(&current) {
+                return current;
+            }
         }
+
+        None
     }
 }
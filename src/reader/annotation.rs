@@ -0,0 +1,222 @@
+//! Selectors of the W3C Web Annotation Data Model to anchor annotations,
+//! such as highlights, to the text of reader content.
+//!
+//! Selectors refer to the text retrieved using
+//! [Content::text](super::content::Content::text), where positions are
+//! counted in characters (unicode code points) as defined by the
+//! specification.
+//!
+//! Storing both a [TextQuoteSelector] and a [TextPositionSelector] for an
+//! annotation allows it to be re-anchored after minor content changes,
+//! as the quote is searched for near its previous position.
+
+use std::cmp::Reverse;
+
+/// Selector that identifies text by its character range,
+/// equivalent to a `TextPositionSelector`.
+///
+/// # Examples
+/// Creating a quote from a range of text:
+/// ```
+/// use rbook::read::TextPositionSelector;
+///
+/// let text = "Call me Ishmael. Some years ago";
+/// let position = TextPositionSelector::new(8, 15);
+/// let quote = position.quote(text, 5).unwrap();
+///
+/// assert_eq!(Some("Ishmael"), position.exact(text));
+/// assert_eq!("l me ", quote.prefix());
+/// assert_eq!(". Som", quote.suffix());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TextPositionSelector {
+    start: usize,
+    end: usize,
+}
+
+impl TextPositionSelector {
+    /// Create a selector from a start (inclusive) and end (exclusive)
+    /// character position. The positions are swapped if `end` is less
+    /// than `start`.
+    pub fn new(start: usize, end: usize) -> Self {
+        Self {
+            start: start.min(end),
+            end: start.max(end),
+        }
+    }
+
+    /// Retrieve the start character position (inclusive).
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Retrieve the end character position (exclusive).
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Retrieve the selected text. Returns [None] if the range
+    /// exceeds the given text.
+    pub fn exact<'a>(&self, text: &'a str) -> Option<&'a str> {
+        let start = byte_index(text, self.start)?;
+        let end = byte_index(text, self.end)?;
+        Some(&text[start..end])
+    }
+
+    /// Convert the selector into a [TextQuoteSelector] with up to
+    /// `context` characters of prefix and suffix. Returns [None] if
+    /// the range exceeds the given text.
+    pub fn quote(&self, text: &str, context: usize) -> Option<TextQuoteSelector> {
+        let exact = self.exact(text)?;
+        let prefix_start = byte_index(text, self.start.saturating_sub(context))?;
+        let start = byte_index(text, self.start)?;
+        let end = byte_index(text, self.end)?;
+        let suffix_end = byte_index(text, self.end + context).unwrap_or(text.len());
+
+        Some(TextQuoteSelector::with_context(
+            &text[prefix_start..start],
+            exact,
+            &text[end..suffix_end],
+        ))
+    }
+}
+
+/// Selector that identifies text by quoting it along with its
+/// surrounding text, equivalent to a `TextQuoteSelector`.
+///
+/// # Examples
+/// Anchoring a quote that occurs multiple times:
+/// ```
+/// use rbook::read::TextQuoteSelector;
+///
+/// let text = "the sea, the ship, the sea again";
+/// let quote = TextQuoteSelector::with_context("the ", "sea", " again");
+/// let position = quote.position(text).unwrap();
+///
+/// assert_eq!(23, position.start());
+/// assert_eq!(26, position.end());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TextQuoteSelector {
+    exact: String,
+    prefix: String,
+    suffix: String,
+}
+
+impl TextQuoteSelector {
+    /// Create a selector that quotes the given text without context.
+    pub fn new(exact: &str) -> Self {
+        Self::with_context("", exact, "")
+    }
+
+    /// Create a selector that quotes the given text along with the
+    /// text immediately before (prefix) and after (suffix) it.
+    pub fn with_context(prefix: &str, exact: &str, suffix: &str) -> Self {
+        Self {
+            exact: exact.to_string(),
+            prefix: prefix.to_string(),
+            suffix: suffix.to_string(),
+        }
+    }
+
+    /// Retrieve the quoted text.
+    pub fn exact(&self) -> &str {
+        &self.exact
+    }
+
+    /// Retrieve the text immediately before the quoted text.
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Retrieve the text immediately after the quoted text.
+    pub fn suffix(&self) -> &str {
+        &self.suffix
+    }
+
+    /// Locate the quote within the given text.
+    ///
+    /// If the quote occurs multiple times, the occurrence whose
+    /// surrounding text best matches the prefix and suffix is selected,
+    /// preferring the first. Partial matches of the prefix and suffix are
+    /// accepted, allowing text surrounding the quote to change. Returns
+    /// [None] if the quote does not occur.
+    pub fn position(&self, text: &str) -> Option<TextPositionSelector> {
+        self.locate(text, None)
+    }
+
+    /// Locate the quote within the given text, similar to
+    /// [position](Self::position), preferring the occurrence closest to
+    /// a previous start position when the prefix and suffix match equally.
+    ///
+    /// # Examples
+    /// Re-anchoring after content is inserted:
+    /// ```
+    /// use rbook::read::{TextPositionSelector, TextQuoteSelector};
+    ///
+    /// let previous = TextPositionSelector::new(13, 16);
+    /// let quote = TextQuoteSelector::new("sea");
+    ///
+    /// let text = "sea air. The sea, the sea.";
+    /// let position = quote.position_near(text, previous.start()).unwrap();
+    ///
+    /// assert_eq!(TextPositionSelector::new(13, 16), position);
+    /// ```
+    pub fn position_near(&self, text: &str, start: usize) -> Option<TextPositionSelector> {
+        self.locate(text, Some(start))
+    }
+
+    fn locate(&self, text: &str, hint: Option<usize>) -> Option<TextPositionSelector> {
+        let chars: Vec<char> = text.chars().collect();
+        let exact: Vec<char> = self.exact.chars().collect();
+        let prefix: Vec<char> = self.prefix.chars().collect();
+        let suffix: Vec<char> = self.suffix.chars().collect();
+
+        if exact.is_empty() || exact.len() > chars.len() {
+            return None;
+        }
+
+        let start = chars
+            .windows(exact.len())
+            .enumerate()
+            .filter(|(_, window)| *window == exact.as_slice())
+            .map(|(start, _)| start)
+            .min_by_key(|&start| {
+                let end = start + exact.len();
+                let score =
+                    common_suffix(&prefix, &chars[..start]) + common_prefix(&suffix, &chars[end..]);
+                let distance = hint.map_or(0, |hint| hint.abs_diff(start));
+
+                (Reverse(score), distance)
+            })?;
+
+        Some(TextPositionSelector::new(start, start + exact.len()))
+    }
+}
+
+// Convert a character position into a byte position
+fn byte_index(text: &str, char_index: usize) -> Option<usize> {
+    text.char_indices()
+        .map(|(index, _)| index)
+        .chain(std::iter::once(text.len()))
+        .nth(char_index)
+}
+
+// Count the characters at the end of `expected` that precede a match
+fn common_suffix(expected: &[char], text: &[char]) -> usize {
+    expected
+        .iter()
+        .rev()
+        .zip(text.iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count()
+}
+
+// Count the characters at the start of `expected` that follow a match
+fn common_prefix(expected: &[char], text: &[char]) -> usize {
+    expected
+        .iter()
+        .zip(text)
+        .take_while(|(a, b)| a == b)
+        .count()
+}
@@ -3,6 +3,14 @@ use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 
+use crate::formats::EbookResult;
+#[cfg(feature = "html")]
+use crate::reader::dom::Dom;
+#[cfg(feature = "segmentation")]
+use crate::reader::segmentation::{self, Segment};
+use crate::reader::text::{self, TextMap};
+use crate::xml::Element;
+
 /// Used to retrieve specific information about retrieved
 /// [Content] from a [Reader](super::Reader).
 #[derive(Debug)]
@@ -64,22 +72,178 @@ impl ContentType {
 /// // Retrieve the path
 /// assert_eq!("OPS/cover.xhtml", content.get_content(ContentType::Path).unwrap());
 /// ```
-#[derive(Debug, PartialEq, Eq)]
+/// Retrieving the resources required to display the content:
+/// ```
+/// # use rbook::Ebook;
+/// # let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+/// # let mut reader = epub.reader();
+/// # let content = reader.current_page().unwrap();
+/// let resources = content.resources();
+///
+/// assert_eq!(2, resources.len());
+/// assert_eq!("css/stylesheet.css", resources[0].value());
+/// assert_eq!("images/9780316000000.jpg", resources[1].value());
+/// ```
+#[derive(Debug)]
 pub struct Content<'a> {
     bytes: Vec<u8>,
     fields: HashMap<&'static str, Cow<'a, str>>,
+    resources: Vec<&'a Element>,
 }
 
 impl<'a> Content<'a> {
-    pub(crate) fn new(bytes: Vec<u8>, fields: HashMap<&'static str, Cow<'a, str>>) -> Self {
-        Self { bytes, fields }
+    pub(crate) fn new(
+        bytes: Vec<u8>,
+        fields: HashMap<&'static str, Cow<'a, str>>,
+        resources: Vec<&'a Element>,
+    ) -> Self {
+        Self {
+            bytes,
+            fields,
+            resources,
+        }
+    }
+
+    /// Parse the content into a [Dom] to access its elements, such as
+    /// headings, footnote links, or sections with a certain `epub:type`.
+    ///
+    /// # Errors
+    /// If the content cannot be parsed, an
+    /// [EbookError](crate::result::EbookError) will be returned.
+    ///
+    /// # Examples
+    /// Retrieving sections by their `epub:type`:
+    /// ```
+    /// # use rbook::Ebook;
+    /// # let epub = rbook::Epub::new("tests/ebooks/childrens-literature.epub").unwrap();
+    /// let content = epub.reader().fetch_page(2).unwrap().unwrap();
+    /// let dom = content.dom().unwrap();
+    ///
+    /// assert!(!dom.find_all("section").is_empty());
+    /// ```
+    #[cfg(feature = "html")]
+    pub fn dom(&self) -> EbookResult<Dom> {
+        Dom::parse(&self.bytes)
+    }
+
+    /// Retrieve the resources referenced by the content, such as images
+    /// and stylesheets, that are required to display it.
+    ///
+    /// For an epub, the retrieved elements are manifest elements, in the
+    /// order they are first referenced. External resources and resources
+    /// not declared in the manifest are excluded.
+    pub fn resources(&self) -> &[&'a Element] {
+        &self.resources
     }
 
     /// Retrieve the content data in the form of a string.
-    pub fn as_lossy_str(&self) -> Cow<str> {
+    pub fn as_lossy_str(&self) -> Cow<'_, str> {
         String::from_utf8_lossy(&self.bytes)
     }
 
+    /// Retrieve the text of the content, equivalent to the
+    /// `textContent` of the `body` element excluding scripts and styles.
+    ///
+    /// Character references are decoded and whitespace is preserved,
+    /// making the text suitable for
+    /// [annotation selectors](crate::read::TextQuoteSelector).
+    ///
+    /// # Errors
+    /// If the content cannot be parsed, an
+    /// [EbookError](crate::result::EbookError) will be returned.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// # let epub = rbook::Epub::new("tests/ebooks/example_epub").unwrap();
+    /// let content = epub.reader().fetch_page(2).unwrap().unwrap();
+    /// let text = content.text().unwrap();
+    ///
+    /// assert!(text.trim().starts_with("rbook c1"));
+    /// ```
+    pub fn text(&self) -> EbookResult<String> {
+        self.text_map().map(TextMap::into_text)
+    }
+
+    /// Retrieve the text of the content along with a [TextMap] that maps
+    /// positions within the text to byte offsets within the data, such as
+    /// highlighting search results in the rendered document.
+    ///
+    /// # Errors
+    /// If the content cannot be parsed, an
+    /// [EbookError](crate::result::EbookError) will be returned.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// # let epub = rbook::Epub::new("tests/ebooks/example_epub").unwrap();
+    /// let content = epub.reader().fetch_page(2).unwrap().unwrap();
+    /// let text_map = content.text_map().unwrap();
+    ///
+    /// let offset = text_map.source_offset(0).unwrap();
+    /// assert_eq!(Some(0), text_map.text_position(offset));
+    /// ```
+    pub fn text_map(&self) -> EbookResult<TextMap> {
+        text::extract(&self.bytes)
+    }
+
+    /// Split the text of the content into paragraphs, which are
+    /// separated by block elements, such as `p`, `li`, and headings.
+    ///
+    /// # Errors
+    /// If the content cannot be parsed, an
+    /// [EbookError](crate::result::EbookError) will be returned.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// # let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    /// let content = epub.reader().fetch_page(6).unwrap().unwrap();
+    /// let paragraphs = content.paragraphs().unwrap();
+    ///
+    /// assert_eq!("Chapter 1. Loomings.", paragraphs[0].text());
+    /// assert!(paragraphs[1].text().starts_with("Call me Ishmael."));
+    /// ```
+    #[cfg(feature = "segmentation")]
+    pub fn paragraphs(&self) -> EbookResult<Vec<Segment>> {
+        self.text_map()
+            .map(|text_map| segmentation::paragraphs(&text_map))
+    }
+
+    /// Split the text of the content into sentences, such as queuing
+    /// text-to-speech or generating excerpts. Sentences never span
+    /// multiple [paragraphs](Self::paragraphs).
+    ///
+    /// Sentences end with punctuation such as `.`, `!`, `?`, and `。`,
+    /// followed by any closing quotes or brackets. Periods of
+    /// abbreviations and initials, such as `Mr.` and `J.`, or followed
+    /// by a lowercase letter do not end a sentence.
+    ///
+    /// # Errors
+    /// If the content cannot be parsed, an
+    /// [EbookError](crate::result::EbookError) will be returned.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use rbook::Ebook;
+    /// # let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    /// let content = epub.reader().fetch_page(6).unwrap().unwrap();
+    /// let sentences = content.sentences().unwrap();
+    ///
+    /// assert_eq!("Chapter 1.", sentences[0].text());
+    /// assert_eq!("Loomings.", sentences[1].text());
+    /// assert_eq!("Call me Ishmael.", sentences[2].text());
+    /// ```
+    #[cfg(feature = "segmentation")]
+    pub fn sentences(&self) -> EbookResult<Vec<Segment>> {
+        self.text_map()
+            .map(|text_map| segmentation::sentences(&text_map))
+    }
+
     /// Retrieve specific information about the content.
     ///
     /// See [ContentType] for available options.
@@ -90,6 +254,16 @@ impl<'a> Content<'a> {
     }
 }
 
+// Resources are derived from the bytes of the content,
+// which makes comparing them redundant
+impl PartialEq for Content<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes == other.bytes && self.fields == other.fields
+    }
+}
+
+impl Eq for Content<'_> {}
+
 impl<'a> Deref for Content<'a> {
     type Target = [u8];
 
@@ -0,0 +1,320 @@
+use lol_html::{doc_text, element};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::formats::epub;
+use crate::formats::xml::{utility as xmlutil, Attribute};
+use crate::formats::EbookResult;
+use crate::utility;
+
+const HREF: &str = "href";
+const EPUB_TYPE: &str = "epub:type";
+const NOTE_REFERENCE: &str = "noteref";
+const HEADINGS: [&str; 6] = ["h1", "h2", "h3", "h4", "h5", "h6"];
+
+/// Parsed tree of an xhtml document retrieved using
+/// [Content::dom](super::content::Content::dom).
+///
+/// Element names are compared without their prefix/namespace, such as
+/// `svg:image` matching `image`. Character references within text and
+/// attribute values are decoded.
+///
+/// # Examples
+/// Retrieving the headings of a chapter:
+/// ```
+/// # use rbook::Ebook;
+/// let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+/// let mut reader = epub.reader();
+///
+/// let content = reader.set_current_page(6).unwrap().unwrap();
+/// let dom = content.dom().unwrap();
+///
+/// let headings = dom.headings();
+/// assert_eq!("Chapter 1. Loomings.", headings[0].text());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dom {
+    // Root of the document that contains all top-level nodes
+    document: DomElement,
+}
+
+impl Dom {
+    pub(crate) fn parse(data: &[u8]) -> EbookResult<Self> {
+        let stack = Rc::new(RefCell::new(vec![DomElement::default()]));
+
+        let element_handler = element!("*", |element| {
+            let dom_element = DomElement {
+                name: element.tag_name(),
+                attributes: xmlutil::copy_attributes(element.attributes())
+                    .into_iter()
+                    .map(|attribute| {
+                        Attribute::new(
+                            attribute.name().to_string(),
+                            utility::decode_entities(attribute.value()),
+                        )
+                    })
+                    .collect(),
+                children: Vec::new(),
+            };
+
+            // Elements without content are added directly to their parent
+            if element.is_self_closing() || !element.can_have_content() {
+                push_node(&stack, DomNode::Element(dom_element));
+                return Ok(());
+            }
+
+            stack.borrow_mut().push(dom_element);
+
+            let stack = Rc::clone(&stack);
+            element.on_end_tag(move |end| {
+                close_element(&stack, &end.name());
+                Ok(())
+            })?;
+
+            Ok(())
+        });
+
+        let text_handler = doc_text!(|text| {
+            push_text(&stack, text.as_str());
+            Ok(())
+        });
+
+        epub::parse_xhtml_data(vec![element_handler], vec![text_handler], data)?;
+
+        // Close elements that were not explicitly closed
+        while stack.borrow().len() > 1 {
+            close_top(&stack);
+        }
+
+        let mut document = stack.take().pop().unwrap_or_default();
+        document.decode_text();
+
+        Ok(Self { document })
+    }
+
+    /// Retrieve the root element of the document, such as `html`.
+    pub fn root(&self) -> Option<&DomElement> {
+        self.document.elements().into_iter().next()
+    }
+
+    /// Retrieve all elements of the document with the given name.
+    pub fn find_all(&self, name: &str) -> Vec<&DomElement> {
+        self.document.find_all(name)
+    }
+
+    /// Retrieve the first element of the document with the given name.
+    pub fn find(&self, name: &str) -> Option<&DomElement> {
+        self.document.find(name)
+    }
+
+    /// Retrieve all elements with an `epub:type` that contains
+    /// the given value, such as `footnote` or `chapter`.
+    pub fn by_epub_type(&self, epub_type: &str) -> Vec<&DomElement> {
+        self.document.by_epub_type(epub_type)
+    }
+
+    /// Retrieve all heading elements, `h1` to `h6`, in document order.
+    pub fn headings(&self) -> Vec<&DomElement> {
+        self.document
+            .descendants()
+            .into_iter()
+            .filter(|element| HEADINGS.contains(&element.local_name()))
+            .collect()
+    }
+
+    /// Retrieve all `a` elements that have an `href` attribute.
+    pub fn links(&self) -> Vec<&DomElement> {
+        self.find_all("a")
+            .into_iter()
+            .filter(|element| element.get_attribute(HREF).is_some())
+            .collect()
+    }
+
+    /// Retrieve all links to footnotes, indicated by an `epub:type`
+    /// of `noteref`.
+    pub fn footnote_links(&self) -> Vec<&DomElement> {
+        self.links()
+            .into_iter()
+            .filter(|element| element.has_epub_type(NOTE_REFERENCE))
+            .collect()
+    }
+}
+
+/// Node of a [Dom], which is either an element or text.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DomNode {
+    Element(DomElement),
+    Text(String),
+}
+
+/// Element of a [Dom] along with its attributes and child nodes.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DomElement {
+    name: String,
+    attributes: Vec<Attribute>,
+    children: Vec<DomNode>,
+}
+
+impl DomElement {
+    /// Retrieve the name of the element including its prefix, if any.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Retrieve all attributes
+    pub fn attributes(&self) -> &[Attribute] {
+        &self.attributes
+    }
+
+    /// Retrieve the value from a specified attribute. Namespace/prefix
+    /// may be omitted from the argument.
+    pub fn get_attribute(&self, name: &str) -> Option<&str> {
+        xmlutil::get_attribute(&self.attributes, &name.to_lowercase())
+    }
+
+    /// Retrieve all child nodes, including text.
+    pub fn children(&self) -> &[DomNode] {
+        &self.children
+    }
+
+    /// Retrieve all child elements, excluding text.
+    pub fn elements(&self) -> Vec<&DomElement> {
+        self.children
+            .iter()
+            .filter_map(|node| match node {
+                DomNode::Element(element) => Some(element),
+                DomNode::Text(_) => None,
+            })
+            .collect()
+    }
+
+    /// Retrieve the text of the element and all of its descendants.
+    pub fn text(&self) -> String {
+        let mut text = String::new();
+        self.collect_text(&mut text);
+        text
+    }
+
+    /// Retrieve all descendant elements with the given name.
+    pub fn find_all(&self, name: &str) -> Vec<&DomElement> {
+        let name = name.trim().to_lowercase();
+
+        self.descendants()
+            .into_iter()
+            .filter(|element| element.local_name().to_lowercase() == name)
+            .collect()
+    }
+
+    /// Retrieve the first descendant element with the given name.
+    pub fn find(&self, name: &str) -> Option<&DomElement> {
+        self.find_all(name).into_iter().next()
+    }
+
+    /// Retrieve all descendant elements with an `epub:type` that
+    /// contains the given value.
+    pub fn by_epub_type(&self, epub_type: &str) -> Vec<&DomElement> {
+        self.descendants()
+            .into_iter()
+            .filter(|element| element.has_epub_type(epub_type))
+            .collect()
+    }
+
+    // Retrieve all descendant elements in document order
+    fn descendants(&self) -> Vec<&DomElement> {
+        let mut descendants = Vec::new();
+
+        for element in self.elements() {
+            descendants.push(element);
+            descendants.extend(element.descendants());
+        }
+
+        descendants
+    }
+
+    fn local_name(&self) -> &str {
+        self.name
+            .split_once(':')
+            .map_or(self.name.as_str(), |(_, name)| name)
+    }
+
+    fn has_epub_type(&self, epub_type: &str) -> bool {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.name() == EPUB_TYPE)
+            .is_some_and(|attribute| {
+                attribute
+                    .value()
+                    .split_whitespace()
+                    .any(|value| value == epub_type)
+            })
+    }
+
+    fn collect_text(&self, text: &mut String) {
+        for node in &self.children {
+            match node {
+                DomNode::Element(element) => element.collect_text(text),
+                DomNode::Text(value) => text.push_str(value),
+            }
+        }
+    }
+
+    // Text is decoded once parsing finishes, as character
+    // references may be split between chunks of text
+    fn decode_text(&mut self) {
+        for node in &mut self.children {
+            match node {
+                DomNode::Element(element) => element.decode_text(),
+                DomNode::Text(value) => *value = utility::decode_entities(value),
+            }
+        }
+    }
+}
+
+fn push_node(stack: &RefCell<Vec<DomElement>>, node: DomNode) {
+    if let Some(parent) = stack.borrow_mut().last_mut() {
+        parent.children.push(node);
+    }
+}
+
+fn push_text(stack: &RefCell<Vec<DomElement>>, text: &str) {
+    if text.is_empty() {
+        return;
+    }
+
+    if let Some(parent) = stack.borrow_mut().last_mut() {
+        // Merge consecutive chunks of text
+        match parent.children.last_mut() {
+            Some(DomNode::Text(value)) => value.push_str(text),
+            _ => parent.children.push(DomNode::Text(text.to_string())),
+        }
+    }
+}
+
+// Close the most recent open element with the given name
+// along with any elements opened after it
+fn close_element(stack: &RefCell<Vec<DomElement>>, name: &str) {
+    let is_open = stack
+        .borrow()
+        .iter()
+        .skip(1)
+        .any(|element| element.name.eq_ignore_ascii_case(name));
+
+    while is_open && stack.borrow().len() > 1 {
+        if close_top(stack).eq_ignore_ascii_case(name) {
+            break;
+        }
+    }
+}
+
+// Close the most recent open element, returning its name
+fn close_top(stack: &RefCell<Vec<DomElement>>) -> String {
+    let mut stack = stack.borrow_mut();
+    let element = stack.pop().unwrap_or_default();
+    let name = element.name.clone();
+
+    if let Some(parent) = stack.last_mut() {
+        parent.children.push(DomNode::Element(element));
+    }
+
+    name
+}
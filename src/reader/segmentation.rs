@@ -0,0 +1,164 @@
+use crate::reader::annotation::TextPositionSelector;
+use crate::reader::text::TextMap;
+
+// Characters that end a sentence
+const TERMINATORS: [char; 11] = ['.', '!', '?', '…', '‼', '⁇', '⁈', '⁉', '。', '！', '？'];
+// Terminators of languages that do not separate sentences with spaces
+const FULLWIDTH_TERMINATORS: [char; 3] = ['。', '！', '？'];
+// Characters that may follow a terminator within the same sentence
+const CLOSING: [char; 14] = [
+    '"', '\'', ')', ']', '}', '»', '›', '”', '’', '」', '』', '）', '】', '〕',
+];
+// Abbreviations that do not end a sentence when followed by a period
+const ABBREVIATIONS: [&str; 15] = [
+    "mr", "mrs", "ms", "dr", "st", "jr", "sr", "prof", "rev", "gen", "capt", "col", "lt", "vs",
+    "etc",
+];
+
+/// Segment of the text of reader content, such as a paragraph or
+/// sentence, retrieved using
+/// [Content::sentences](super::content::Content::sentences) and
+/// [Content::paragraphs](super::content::Content::paragraphs).
+///
+/// Positions are counted in characters, matching the positions of a
+/// [TextMap] and [annotation selectors](TextPositionSelector).
+/// Whitespace surrounding a segment is excluded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Segment {
+    text: String,
+    start: usize,
+    end: usize,
+}
+
+impl Segment {
+    /// Retrieve the text of the segment.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Retrieve the start character position (inclusive).
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Retrieve the end character position (exclusive).
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Retrieve the position of the segment as an annotation selector.
+    pub fn position(&self) -> TextPositionSelector {
+        TextPositionSelector::new(self.start, self.end)
+    }
+}
+
+// Split text into paragraphs where block elements start or end
+pub(crate) fn paragraphs(text_map: &TextMap) -> Vec<Segment> {
+    let chars: Vec<char> = text_map.text().chars().collect();
+    let mut boundaries = vec![0];
+    boundaries.extend(text_map.breaks());
+    boundaries.push(chars.len());
+
+    boundaries
+        .windows(2)
+        .filter_map(|range| segment(&chars, range[0], range[1]))
+        .collect()
+}
+
+// Split each paragraph into sentences
+pub(crate) fn sentences(text_map: &TextMap) -> Vec<Segment> {
+    let chars: Vec<char> = text_map.text().chars().collect();
+
+    paragraphs(text_map)
+        .into_iter()
+        .flat_map(|paragraph| {
+            let mut sentences = Vec::new();
+            let mut start = paragraph.start;
+
+            for end in sentence_ends(&chars, paragraph.start, paragraph.end) {
+                sentences.extend(segment(&chars, start, end));
+                start = end;
+            }
+
+            sentences.extend(segment(&chars, start, paragraph.end));
+            sentences
+        })
+        .collect()
+}
+
+// Retrieve the positions after each sentence within a range
+fn sentence_ends(chars: &[char], start: usize, end: usize) -> Vec<usize> {
+    let mut ends = Vec::new();
+    let mut index = start;
+
+    while index < end {
+        let character = chars[index];
+        index += 1;
+
+        if !TERMINATORS.contains(&character) {
+            continue;
+        }
+
+        // Include consecutive terminators and closing punctuation
+        while index < end
+            && (TERMINATORS.contains(&chars[index]) || CLOSING.contains(&chars[index]))
+        {
+            index += 1;
+        }
+
+        let is_fullwidth = FULLWIDTH_TERMINATORS.contains(&character);
+        let is_followed_by_space = index == end || chars[index].is_whitespace();
+
+        if is_fullwidth || (is_followed_by_space && !is_continuation(chars, index, end, character))
+        {
+            ends.push(index);
+        }
+    }
+
+    ends
+}
+
+// Check if a period does not end a sentence, such as abbreviations,
+// initials, or the following text starting with a lowercase letter
+fn is_continuation(chars: &[char], index: usize, end: usize, terminator: char) -> bool {
+    if terminator != '.' {
+        return false;
+    }
+
+    let next = chars[index..end]
+        .iter()
+        .find(|character| !character.is_whitespace());
+    if next.is_some_and(|character| character.is_lowercase()) {
+        return true;
+    }
+
+    // Retrieve the word preceding the period
+    let period = chars[..index]
+        .iter()
+        .rposition(|character| *character == '.')
+        .unwrap_or_default();
+    let word_start = chars[..period]
+        .iter()
+        .rposition(|character| !character.is_alphabetic())
+        .map_or(0, |position| position + 1);
+    let word: String = chars[word_start..period].iter().collect();
+
+    let is_initial = word.chars().count() == 1 && word.chars().all(char::is_uppercase);
+    is_initial || ABBREVIATIONS.contains(&word.to_lowercase().as_str())
+}
+
+// Create a segment from a range, excluding surrounding whitespace
+fn segment(chars: &[char], mut start: usize, mut end: usize) -> Option<Segment> {
+    while start < end && chars[start].is_whitespace() {
+        start += 1;
+    }
+    while end > start && chars[end - 1].is_whitespace() {
+        end -= 1;
+    }
+
+    (start < end).then(|| Segment {
+        text: chars[start..end].iter().collect(),
+        start,
+        end,
+    })
+}
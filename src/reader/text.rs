@@ -0,0 +1,247 @@
+use lol_html::{doc_text, element};
+use std::cell::{Cell, RefCell};
+use std::ops::Range;
+use std::rc::Rc;
+
+use crate::formats::epub;
+use crate::formats::EbookResult;
+use crate::utility;
+
+// Elements within the body that do not contain readable text
+const SKIPPED_ELEMENTS: &str = "script, style";
+// Elements that separate paragraphs of text
+#[cfg(feature = "segmentation")]
+const BLOCK_ELEMENTS: &str = "address, article, aside, blockquote, dd, div, dl, dt, figcaption, \
+    figure, footer, h1, h2, h3, h4, h5, h6, header, hr, li, nav, ol, p, pre, section, table, td, \
+    th, tr, ul";
+
+/// Mapping between positions within the text of reader content and
+/// byte offsets within its original xhtml data, retrieved using
+/// [Content::text_map](super::content::Content::text_map).
+///
+/// Text positions are counted in characters (unicode code points),
+/// matching the positions of [annotation selectors](crate::read::TextPositionSelector).
+/// Byte offsets refer to the data of the content, such as
+/// [Content::as_lossy_str](super::content::Content::as_lossy_str), or its
+/// utf-8 conversion if the content is encoded using utf-16.
+///
+/// A decoded character reference, such as `&amp;`, is a single
+/// character of text that spans the entire reference in the data.
+///
+/// # Examples
+/// Highlighting a search hit within the original data:
+/// ```
+/// # use rbook::Ebook;
+/// # let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+/// let content = epub.reader().fetch_page(6).unwrap().unwrap();
+/// let text_map = content.text_map().unwrap();
+/// let data = content.as_lossy_str();
+///
+/// let start = text_map.text().find("Call me Ishmael.").unwrap();
+/// let start = text_map.text()[..start].chars().count();
+/// let range = text_map.source_range(start, start + 16).unwrap();
+///
+/// assert_eq!("Call me Ishmael.", &data[range.clone()]);
+/// assert_eq!(Some(start), text_map.text_position(range.start));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TextMap {
+    text: String,
+    // Byte range within the data of each character of text
+    spans: Vec<Range<usize>>,
+    // Text positions where block elements start or end
+    #[cfg(feature = "segmentation")]
+    breaks: Vec<usize>,
+}
+
+impl TextMap {
+    /// Retrieve the extracted text, equivalent to
+    /// [Content::text](super::content::Content::text).
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Retrieve the number of characters of the text.
+    pub fn len(&self) -> usize {
+        self.spans.len()
+    }
+
+    /// Check if there is no text.
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// Retrieve the byte offset within the data where the character at
+    /// the given text position starts. Returns [None] if the position is
+    /// out of range.
+    pub fn source_offset(&self, position: usize) -> Option<usize> {
+        self.spans.get(position).map(|span| span.start)
+    }
+
+    /// Retrieve the byte range within the data that covers the text from
+    /// a start (inclusive) to an end (exclusive) position. The range may
+    /// include markup, such as tags, located between the characters.
+    /// Returns [None] if the positions are out of range or `end` is
+    /// less than `start`.
+    pub fn source_range(&self, start: usize, end: usize) -> Option<Range<usize>> {
+        if start > end || end > self.spans.len() {
+            return None;
+        }
+        if start == end {
+            // An empty range is located where the next character starts
+            let offset = match self.spans.get(start) {
+                Some(span) => span.start,
+                None => self.spans.last().map_or(0, |span| span.end),
+            };
+            return Some(offset..offset);
+        }
+
+        Some(self.spans[start].start..self.spans[end - 1].end)
+    }
+
+    /// Retrieve the position of the character of text that the given byte
+    /// offset within the data belongs to. Returns [None] if the offset
+    /// is located within markup or outside the body.
+    pub fn text_position(&self, offset: usize) -> Option<usize> {
+        let position = self.spans.partition_point(|span| span.end <= offset);
+
+        self.spans
+            .get(position)
+            .filter(|span| span.contains(&offset))
+            .map(|_| position)
+    }
+
+    pub(crate) fn into_text(self) -> String {
+        self.text
+    }
+
+    #[cfg(feature = "segmentation")]
+    pub(crate) fn breaks(&self) -> &[usize] {
+        &self.breaks
+    }
+}
+
+// Extract the text of the body, equivalent to the `textContent` of the
+// `body` element excluding scripts and styles. Whitespace is preserved.
+pub(crate) fn extract(data: &[u8]) -> EbookResult<TextMap> {
+    // (byte offset, raw text)
+    let chunks = RefCell::new(Vec::<(usize, String)>::new());
+    let written = Rc::new(Cell::new(0_usize));
+    // Byte offsets of block element tags
+    #[cfg(feature = "segmentation")]
+    let breaks = Rc::new(RefCell::new(Vec::new()));
+    let in_body = Rc::new(Cell::new(false));
+    let skipped = Rc::new(Cell::new(0_usize));
+
+    let body_handler = element!("body", |element| {
+        in_body.set(true);
+
+        let in_body = Rc::clone(&in_body);
+        element.on_end_tag(move |_| {
+            in_body.set(false);
+            Ok(())
+        })?;
+
+        Ok(())
+    });
+
+    let skipped_handler = element!(SKIPPED_ELEMENTS, |element| {
+        if element.is_self_closing() || !element.can_have_content() {
+            return Ok(());
+        }
+
+        skipped.set(skipped.get() + 1);
+
+        let skipped = Rc::clone(&skipped);
+        element.on_end_tag(move |_| {
+            skipped.set(skipped.get().saturating_sub(1));
+            Ok(())
+        })?;
+
+        Ok(())
+    });
+
+    // Text handlers are called before the chunk is written to the output
+    let text_handler = doc_text!(|chunk| {
+        let text = chunk.as_str();
+
+        if in_body.get() && skipped.get() == 0 && !text.is_empty() {
+            let offset = written.get();
+            let mut chunks = chunks.borrow_mut();
+
+            // Merge adjacent chunks, as character
+            // references may be split between them
+            match chunks.last_mut() {
+                Some((start, previous)) if *start + previous.len() == offset => {
+                    previous.push_str(text)
+                }
+                _ => chunks.push((offset, text.to_string())),
+            }
+        }
+        Ok(())
+    });
+
+    #[cfg_attr(not(feature = "segmentation"), allow(unused_mut))]
+    let mut element_handlers = vec![body_handler, skipped_handler];
+
+    #[cfg(feature = "segmentation")]
+    element_handlers.push(element!(BLOCK_ELEMENTS, |element| {
+        breaks.borrow_mut().push(written.get());
+
+        if element.is_self_closing() || !element.can_have_content() {
+            return Ok(());
+        }
+
+        let breaks = Rc::clone(&breaks);
+        let written = Rc::clone(&written);
+        element.on_end_tag(move |_| {
+            breaks.borrow_mut().push(written.get());
+            Ok(())
+        })?;
+
+        Ok(())
+    }));
+
+    epub::parse_xhtml_data_with_output(
+        element_handlers,
+        vec![text_handler],
+        data,
+        |output: &[u8]| written.set(written.get() + output.len()),
+    )?;
+
+    let mut text_map = TextMap::default();
+
+    for (offset, raw) in chunks.take() {
+        decode_chunk(&mut text_map, offset, &raw);
+    }
+
+    // Convert byte offsets into the positions of the following text
+    #[cfg(feature = "segmentation")]
+    {
+        let spans = &text_map.spans;
+        let mut positions: Vec<_> = breaks
+            .take()
+            .into_iter()
+            .map(|offset| spans.partition_point(|span| span.start < offset))
+            .collect();
+        positions.dedup();
+        text_map.breaks = positions;
+    }
+
+    Ok(text_map)
+}
+
+fn decode_chunk(text_map: &mut TextMap, offset: usize, raw: &str) {
+    let mut index = 0;
+
+    while let Some(character) = raw[index..].chars().next() {
+        let (character, length) = match character {
+            '&' => utility::decode_entity(&raw[index..]).unwrap_or(('&', 1)),
+            _ => (character, character.len_utf8()),
+        };
+
+        text_map.text.push(character);
+        text_map.spans.push(offset + index..offset + index + length);
+        index += length;
+    }
+}
@@ -1,15 +1,15 @@
 use std::borrow::Cow;
-use std::fs::{File, Metadata};
+use std::fs::Metadata;
 use std::path::{Path, PathBuf};
 
 use crate::formats::EbookError;
 use crate::result::EbookResult;
 
 #[cfg(feature = "multi-thread")]
-pub use std::sync::{Arc as Shared, Mutex as Lock, Weak};
+pub use std::sync::{Arc as Shared, Mutex as Lock, OnceLock as OnceCell, Weak};
 #[cfg(not(feature = "multi-thread"))]
 pub use std::{
-    cell::RefCell as Lock,
+    cell::{OnceCell, RefCell as Lock},
     rc::{Rc as Shared, Weak},
 };
 
@@ -22,13 +22,6 @@ pub(crate) fn split_where(string: &str, character: char) -> Option<(&str, &str)>
         .map(|(left, right)| (left, &right[1..]))
 }
 
-pub(crate) fn get_file<P: AsRef<Path>>(path: P) -> EbookResult<File> {
-    File::open(&path).map_err(|error| EbookError::IO {
-        cause: "Unable to open file".to_string(),
-        description: format!("File path: '{:?}': {error}", path.as_ref()),
-    })
-}
-
 pub(crate) fn get_path_metadata<P: AsRef<Path>>(path: P) -> EbookResult<Metadata> {
     path.as_ref().metadata().map_err(|error| EbookError::IO {
         cause: "Unable to access path metadata".to_string(),
@@ -43,7 +36,7 @@ pub(crate) fn get_parent_path<P: AsRef<Path>>(path: &P) -> &Path {
 }
 
 // Function to normalize paths. ex: `EPUB//.//OPS/../../toc.ncx` -> `toc.ncx`
-pub(crate) fn normalize_path<P: AsRef<Path>>(path: &P) -> Cow<Path> {
+pub(crate) fn normalize_path<P: AsRef<Path>>(path: &P) -> Cow<'_, Path> {
     let mut stack = Vec::new();
     let mut is_normalized = true;
 
@@ -72,7 +65,7 @@ pub(crate) fn normalize_path<P: AsRef<Path>>(path: &P) -> Cow<Path> {
 }
 
 // Support for UTF-16 by converting it to UTF-8
-pub(crate) fn to_utf8(data: &[u8]) -> Cow<[u8]> {
+pub(crate) fn to_utf8(data: &[u8]) -> Cow<'_, [u8]> {
     // Check if a utf-16 byte order mark (bom) exists
     if data.starts_with(b"\xFF\xFE") || data.starts_with(b"\xFE\xFF") {
         // Determine byte order for little endian (le) and big endian (be)
@@ -93,9 +86,77 @@ pub(crate) fn to_utf8(data: &[u8]) -> Cow<[u8]> {
     }
 }
 
+// Characters of code page 437 from 0x80 to 0xFF.
+// Lower characters are equivalent to ascii.
+const CP437_HIGH: &str = "ÇüéâäàåçêëèïîìÄÅÉæÆôöòûùÿÖÜ¢£¥₧ƒáíóúñÑªº¿⌐¬½¼¡«»░▒▓│┤╡╢╖╕╣║╗╝╜╛┐\
+    └┴┬├─┼╞╟╚╔╩╦╠═╬╧╨╤╥╙╘╒╓╫╪┘┌█▄▌▐▀αßΓπΣσµτΦΘΩδ∞φε∩≡±≥≤⌠⌡÷≈°∙·√ⁿ²■\u{a0}";
+
+// Decode data encoded with code page 437, the legacy encoding of zip file names
+pub(crate) fn from_cp437(data: &[u8]) -> String {
+    data.iter()
+        .map(|&byte| match byte {
+            0x00..=0x7F => byte as char,
+            _ => CP437_HIGH
+                .chars()
+                .nth(byte as usize - 0x80)
+                .unwrap_or('\u{FFFD}'),
+        })
+        .collect()
+}
+
 pub(crate) fn split_exclude<'a>(input: &'a str, excluded: &[char]) -> Vec<&'a str> {
     input
-        .split(|c| excluded.iter().any(|exclude| c == *exclude))
+        .split(|c| excluded.contains(&c))
         .filter(|s| excluded.iter().any(|exclude| s != &exclude.to_string()))
         .collect()
 }
+
+// Decode character references, such as `&amp;` and `&#8212;`
+pub(crate) fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        match decode_entity(rest) {
+            Some((character, length)) => {
+                decoded.push(character);
+                rest = &rest[length..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+// Decode the character reference at the start of the text, returning
+// the character and the length of the reference in bytes
+pub(crate) fn decode_entity(text: &str) -> Option<(char, usize)> {
+    let end = text.strip_prefix('&')?.find(';')? + 1;
+    decode_reference(&text[1..end]).map(|character| (character, end + 1))
+}
+
+fn decode_reference(name: &str) -> Option<char> {
+    match name {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some('\u{a0}'),
+        _ => {
+            let code = match name.strip_prefix("#x").or_else(|| name.strip_prefix("#X")) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => name.strip_prefix('#')?.parse().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
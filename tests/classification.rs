@@ -0,0 +1,49 @@
+#[cfg(feature = "classification")]
+mod classification {
+    use rbook::epub::{Classification, ClassificationScheme};
+    use rbook::Ebook;
+
+    #[test]
+    fn subject_classification_test() {
+        let epub = rbook::Epub::new("tests/ebooks/subjects_epub").unwrap();
+        let classifications = epub.metadata().classifications();
+
+        // Subjects without an authority are excluded
+        assert_eq!(4, epub.metadata().subject().len());
+        assert_eq!(3, classifications.len());
+
+        let bisac = &classifications[0];
+        assert_eq!(ClassificationScheme::Bisac, bisac.scheme());
+        assert_eq!(vec!["Fiction", "General"], bisac.categories());
+
+        let thema = &classifications[1];
+        assert_eq!(ClassificationScheme::Thema, thema.scheme());
+        assert_eq!("FBA", thema.code());
+        assert_eq!(
+            "Fiction and Related items / Fiction: general and literary / Modern and contemporary fiction",
+            thema.path()
+        );
+
+        let bic = &classifications[2];
+        assert_eq!(ClassificationScheme::Bic, bic.scheme());
+        assert_eq!(vec!["Fiction & related items", "Fantasy"], bic.categories());
+    }
+
+    #[test]
+    fn classification_lookup_test() {
+        // Uncovered subcategories end the path early
+        let bisac = Classification::new("BISAC", "com051010").unwrap();
+        assert_eq!("COM051010", bisac.code());
+        assert_eq!(vec!["Computers"], bisac.categories());
+
+        let thema = Classification::new("Thema", "UMX").unwrap();
+        assert_eq!(
+            vec!["Computing and Information Technology"],
+            thema.categories()
+        );
+
+        let unknown = Classification::new("BIC", "0").unwrap();
+        assert!(unknown.categories().is_empty());
+        assert!(Classification::new("DDC", "813.54").is_none());
+    }
+}
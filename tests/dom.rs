@@ -0,0 +1,51 @@
+#[cfg(feature = "html")]
+mod dom {
+    use rbook::Ebook;
+
+    #[test]
+    fn dom_test() {
+        let epub = rbook::Epub::new("tests/ebooks/content_epub").unwrap();
+        let content = epub.reader().fetch_page(0).unwrap().unwrap();
+        let dom = content.dom().unwrap();
+
+        assert_eq!("html", dom.root().unwrap().name());
+        assert_eq!("Example", dom.find("title").unwrap().text());
+
+        let headings = dom.headings();
+        assert_eq!(1, headings.len());
+        assert_eq!("rbook c1", headings[0].text());
+
+        // Character references are decoded
+        let paragraphs = dom.find_all("p");
+        assert_eq!(2, paragraphs.len());
+        assert_eq!("Parsing & reading1.", paragraphs[0].text());
+
+        // Void elements do not contain the text that follows them
+        let paragraph = paragraphs[1];
+        assert_eq!("See c2.", paragraph.text());
+        assert_eq!(2, paragraph.elements().len());
+
+        assert_eq!(2, dom.links().len());
+
+        let footnote_links = dom.footnote_links();
+        assert_eq!(1, footnote_links.len());
+        assert_eq!(Some("#n1"), footnote_links[0].get_attribute("href"));
+
+        let footnotes = dom.by_epub_type("footnote");
+        assert_eq!(1, footnotes.len());
+        assert_eq!(Some("n1"), footnotes[0].get_attribute("id"));
+        assert_eq!("Footnote \u{2014} example", footnotes[0].text());
+    }
+
+    #[test]
+    fn dom_chapter_test() {
+        let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+        let content = epub.reader().fetch_page(6).unwrap().unwrap();
+        let dom = content.dom().unwrap();
+
+        let chapter = &dom.by_epub_type("chapter")[0];
+        assert_eq!("section", chapter.name());
+        assert_eq!("Chapter 1. Loomings.", chapter.find("h1").unwrap().text());
+        assert!(dom.find_all("p").len() > 10);
+    }
+}
@@ -0,0 +1,41 @@
+use rbook::interop::rwpm;
+use rbook::Ebook;
+
+#[test]
+fn rwpm_test() {
+    let epub = rbook::Epub::new("tests/ebooks/example_epub").unwrap();
+    let json = rwpm::to_json(&epub);
+
+    assert!(json.contains(concat!(
+        r#""metadata":{"@type":"http://schema.org/Book","title":"Directory Example","#,
+        r#""identifier":"https://github.com/devinsterling/rbook","language":"en","#,
+    )));
+    assert!(json.contains(r#""author":["Devin Sterling"]"#));
+    assert!(json.contains(r#""readingProgression":"ltr""#));
+
+    // Spine items are within the reading order, all others are resources
+    assert!(json.contains(concat!(
+        r#""readingOrder":[{"href":"EPUB/cover.xhtml","type":"application/xhtml+xml"},"#,
+        r#"{"href":"toc.xhtml","type":"application/xhtml+xml"},"#,
+    )));
+    assert!(json.contains(r#""resources":[]"#));
+
+    // Toc hrefs are relative to the archive
+    assert!(json.contains(r#""toc":[{"href":"EPUB/cover.xhtml","title":"The Cover"}"#));
+
+    let epub = rbook::Epub::new("tests/ebooks/fallback_epub").unwrap();
+    let json = rwpm::to_json(&epub);
+
+    assert!(json.contains(concat!(
+        r#""resources":[{"href":"OEBPS/chart.svg","type":"image/svg+xml"},"#,
+        r#"{"href":"OEBPS/chart.png","type":"image/png"},"#,
+    )));
+
+    let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    let json = rwpm::to_json(&epub);
+
+    assert!(json
+        .contains(r#"{"href":"OPS/images/9780316000000.jpg","type":"image/jpeg","rel":"cover"}"#));
+    assert!(json.contains(r#"{"href":"OPS/titlepage.xhtml","title":"Moby-Dick"}"#));
+    assert!(json.contains(r#""title":"Original Transcriber’s Notes:""#));
+}
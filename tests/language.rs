@@ -0,0 +1,17 @@
+#[cfg(feature = "language-detection")]
+mod language {
+    use rbook::Ebook;
+
+    #[test]
+    fn detect_language_test() {
+        for (path, expected) in [
+            ("tests/ebooks/moby-dick.epub", Some("en")),
+            ("tests/ebooks/childrens-literature.epub", Some("en")),
+            ("tests/ebooks/example_epub", Some("en")),
+            ("tests/ebooks/dictionary_epub", Some("en")),
+        ] {
+            let epub = rbook::Epub::new(path).unwrap();
+            assert_eq!(expected, epub.detect_language(), "{path}");
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use rbook::library::{self, ScanOptions};
+
+#[test]
+fn scan_test() {
+    let options = ScanOptions::new().include_cover(true).threads(3);
+    let scan = library::scan("tests/ebooks", &options).unwrap();
+
+    // Epub files and unzipped epub directories
+    let paths: Vec<_> = scan.records().iter().map(|record| record.path()).collect();
+    assert!(paths
+        .iter()
+        .any(|path| path.ends_with("childrens-literature.epub")));
+    assert!(paths.iter().any(|path| path.ends_with("example_epub")));
+
+    let record = scan
+        .records()
+        .iter()
+        .find(|record| record.path().ends_with("moby-dick.epub"))
+        .unwrap();
+    assert!(record
+        .cover()
+        .is_some_and(|cover| cover.starts_with(b"\xFF\xD8")));
+
+    // The toc is not parsed, which is otherwise inaccessible for this epub
+    assert!(paths.iter().any(|path| path.ends_with("backslash_epub")));
+    assert!(scan.errors().is_empty());
+
+    // Results do not depend on the number of threads
+    let sequential =
+        library::scan("tests/ebooks", &ScanOptions::new().include_cover(true)).unwrap();
+    assert_eq!(sequential.records(), scan.records());
+}
+
+#[test]
+fn scan_error_test() {
+    use std::fs;
+
+    let root = std::env::temp_dir().join(format!("rbook_scan_test_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join("nested")).unwrap();
+    fs::copy(
+        "tests/ebooks/moby-dick.epub",
+        root.join("nested/moby-dick.epub"),
+    )
+    .unwrap();
+    fs::write(root.join("invalid.epub"), b"not a zip archive").unwrap();
+
+    // Ebooks that fail to open are collected instead of ending the scan
+    let scan = library::scan(&root, &ScanOptions::new()).unwrap();
+    assert_eq!(1, scan.records().len());
+    assert_eq!(1, scan.errors().len());
+    assert!(scan.errors()[0].0.ends_with("invalid.epub"));
+
+    let scan = library::scan(&root, &ScanOptions::new().recursive(false)).unwrap();
+    assert!(scan.records().is_empty());
+
+    fs::remove_dir_all(&root).unwrap();
+}
@@ -0,0 +1,143 @@
+use rbook::lint::{Finding, Rule, RuleSet, Severity};
+use rbook::{Ebook, Epub};
+use std::io::{Cursor, Write};
+
+const CONTAINER: &str = r#"<container><rootfiles>
+    <rootfile full-path="content.opf" media-type="application/oebps-package+xml"/>
+</rootfiles></container>"#;
+
+fn build_epub(files: &[(&str, &str)]) -> Cursor<Vec<u8>> {
+    let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    for (name, data) in files {
+        writer
+            .start_file(*name, zip::write::FileOptions::default())
+            .unwrap();
+        writer.write_all(data.as_bytes()).unwrap();
+    }
+    writer.finish().unwrap()
+}
+
+#[test]
+fn recommended_rules_test() {
+    let package = r#"<package version="3.0" unique-identifier="uid">
+        <metadata>
+            <dc:identifier id="uid">id</dc:identifier>
+            <dc:title>Title</dc:title>
+        </metadata>
+        <manifest>
+            <item id="nav" href="nav.xhtml" media-type="application/xhtml+xml" properties="nav"/>
+            <item id="c1" href="c1.xhtml" media-type="application/xhtml+xml"/>
+            <item id="c2" href="c2.xhtml" media-type="application/xhtml+xml"/>
+            <item id="c3" href="c3.xhtml" media-type="application/xhtml+xml"/>
+        </manifest>
+        <spine>
+            <itemref idref="c1"/>
+            <itemref idref="c2"/>
+            <itemref idref="missing"/>
+        </spine>
+    </package>"#;
+    let epub = Epub::read_from(build_epub(&[
+        ("mimetype", "application/epub+zip"),
+        ("META-INF/container.xml", CONTAINER),
+        ("content.opf", package),
+        (
+            "nav.xhtml",
+            r#"<nav epub:type="toc"><ol><li><a href="c1.xhtml">1</a></li></ol></nav>"#,
+        ),
+        ("c1.xhtml", "<html><body><h1>c1</h1></body></html>"),
+        ("c3.xhtml", "<html><body><h1>c3</h1></body></html>"),
+        ("orphan.css", "p {}"),
+    ]))
+    .unwrap();
+
+    let rules = RuleSet::recommended();
+    let findings: Vec<_> = epub
+        .lint(&rules)
+        .into_iter()
+        .map(|finding| {
+            (
+                finding.rule().to_string(),
+                finding.severity(),
+                finding.location().map(str::to_string),
+            )
+        })
+        .collect();
+    let location = |location: &str| Some(location.to_string());
+
+    assert_eq!(
+        vec![
+            (
+                "spine-references".to_string(),
+                Severity::Error,
+                location("missing")
+            ),
+            (
+                "missing-resources".to_string(),
+                Severity::Error,
+                location("c2")
+            ),
+            ("required-metadata".to_string(), Severity::Error, None),
+            ("required-metadata".to_string(), Severity::Warning, None),
+            (
+                "unreachable-content".to_string(),
+                Severity::Warning,
+                location("c3")
+            ),
+            (
+                "undeclared-resources".to_string(),
+                Severity::Info,
+                location("orphan.css")
+            ),
+        ],
+        findings
+    );
+    assert_eq!(
+        vec![
+            "spine-references",
+            "missing-resources",
+            "href-case",
+            "required-metadata",
+            "unreachable-content",
+            "undeclared-resources"
+        ],
+        rules.ids()
+    );
+
+    // A well-formed epub has no errors
+    let epub = Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    assert!(epub
+        .lint(&rules)
+        .iter()
+        .all(|finding| finding.severity() < Severity::Error));
+}
+
+#[test]
+fn custom_rule_test() {
+    struct MinimumChapters(usize);
+
+    impl Rule for MinimumChapters {
+        fn id(&self) -> &str {
+            "minimum-chapters"
+        }
+
+        fn check(&self, epub: &Epub) -> Vec<Finding> {
+            match epub.spine().len() < self.0 {
+                true => vec![Finding::new(Severity::Error, "Too few chapters").at("spine")],
+                false => Vec::new(),
+            }
+        }
+    }
+
+    let epub = Epub::new("tests/ebooks/example_epub").unwrap();
+    let findings = epub.lint(&RuleSet::new().rule(MinimumChapters(10)));
+
+    assert_eq!(1, findings.len());
+    assert_eq!("minimum-chapters", findings[0].rule());
+    assert_eq!("Too few chapters", findings[0].message());
+    assert_eq!(Some("spine"), findings[0].location());
+
+    assert!(epub
+        .lint(&RuleSet::new().rule(MinimumChapters(1)))
+        .is_empty());
+    assert!(epub.lint(&RuleSet::new()).is_empty());
+}
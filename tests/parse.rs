@@ -1,5 +1,11 @@
+use rbook::epub::{
+    AppleDisplayOptions, Duration, EpubSettings, ParseLevel, Resource, TextDirection, TitleKind,
+    TitleStyle,
+};
+use rbook::result::{ArchiveError, EbookError};
 use rbook::xml::Find;
 use rbook::Ebook;
+use std::io::Cursor;
 
 #[test]
 fn metadata_test() {
@@ -193,6 +199,33 @@ fn toc_test() {
     assert_eq!("bodymatter", attribute);
 }
 
+#[test]
+fn toc_semantics_test() {
+    let epub = rbook::Epub::new("tests/ebooks/content_epub").unwrap();
+    let toc = epub.toc();
+
+    let page_list = toc.page_list();
+    assert_eq!(2, page_list.len());
+    assert!(page_list.iter().all(|element| toc.is_hidden(element)));
+    assert!(!toc
+        .elements_flat()
+        .iter()
+        .any(|element| toc.is_hidden(element)));
+
+    let landmarks = toc.landmarks();
+    assert_eq!(vec!["toc"], toc.types(landmarks[0]));
+    assert!(toc.types(toc.elements()[0]).is_empty());
+
+    // Nav elements with types that do not have a dedicated method
+    let illustrations = toc.get("loi");
+    assert_eq!(1, illustrations.len());
+    assert_eq!("c1.xhtml#figure1", illustrations[0].value());
+
+    let mut nav_types = toc.nav_types();
+    nav_types.sort_unstable();
+    assert_eq!(vec!["landmarks", "loi", "page-list", "toc"], nav_types);
+}
+
 #[test]
 fn directory_test() {
     let epub = rbook::Epub::new("tests/ebooks/example_epub").unwrap();
@@ -223,3 +256,1646 @@ fn directory_test() {
 
     assert_eq!(None, epub.cover_image());
 }
+
+#[test]
+fn dictionary_test() {
+    let epub = rbook::Epub::new("tests/ebooks/dictionary_epub").unwrap();
+    assert!(epub.metadata().is_dictionary());
+
+    let search_key_maps = epub.search_key_maps().unwrap();
+    assert_eq!(1, search_key_maps.len());
+
+    let search_key_map = search_key_maps.first().unwrap();
+    assert_eq!(Some("en"), search_key_map.language());
+    assert_eq!(2, search_key_map.groups().len());
+
+    let group = search_key_map.groups().first().unwrap();
+    let key = group.matches().first().unwrap();
+    assert_eq!("dict/entries.xhtml#book", group.href());
+    assert_eq!("book", key.value());
+    assert_eq!(["books", "booked"], key.forms());
+
+    assert_eq!(
+        vec!["dict/entries.xhtml#book"],
+        search_key_map.lookup("BOOKS")
+    );
+    assert_eq!(
+        vec!["dict/entries.xhtml#read"],
+        search_key_map.lookup("reader")
+    );
+    assert!(search_key_map.lookup("write").is_empty());
+
+    // Resolved hrefs can be used to read entries
+    let (path, _) = group.href().split_once('#').unwrap();
+    assert!(epub.read_file(path).unwrap().contains("<dfn>book</dfn>"));
+
+    let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    assert!(!epub.metadata().is_dictionary());
+    assert!(epub.search_key_maps().unwrap().is_empty());
+}
+
+#[test]
+fn prefix_test() {
+    let epub = rbook::Epub::new("tests/ebooks/prefix_epub").unwrap();
+    let metadata = epub.metadata();
+
+    assert_eq!(
+        vec![
+            ("rbook", "https://github.com/devinsterling/rbook#"),
+            ("acme", "https://example.org/terms/"),
+        ],
+        metadata.prefixes()
+    );
+    assert_eq!(
+        "https://example.org/terms/edition",
+        metadata.expand_property("acme:edition").unwrap()
+    );
+
+    // Reserved prefixes do not need to be declared
+    assert_eq!(
+        "http://purl.org/dc/terms/modified",
+        metadata.expand_property("dcterms:modified").unwrap()
+    );
+    assert_eq!(
+        "http://www.idpf.org/vocab/rendition/#layout",
+        metadata.expand_property("rendition:layout").unwrap()
+    );
+    assert_eq!(None, metadata.expand_property("modified"));
+    assert_eq!(None, metadata.expand_property("other:modified"));
+
+    // Declared prefixes take priority over reserved prefixes
+    let package = r#"<package version="3.0" unique-identifier="uid" prefix="dcterms: https://example.org/terms/">
+        <metadata>
+            <dc:identifier id="uid">id</dc:identifier>
+            <dc:title>Title</dc:title>
+        </metadata>
+        <manifest>
+            <item id="nav" href="nav.xhtml" media-type="application/xhtml+xml" properties="nav"/>
+        </manifest>
+        <spine><itemref idref="nav"/></spine>
+    </package>"#;
+    let epub = rbook::Epub::read_from(build_epub(&[
+        ("META-INF/container.xml", CONTAINER),
+        ("content.opf", package),
+        (
+            "nav.xhtml",
+            r#"<nav epub:type="toc"><ol><li><a href="nav.xhtml">1</a></li></ol></nav>"#,
+        ),
+    ]))
+    .unwrap();
+    assert_eq!(
+        "https://example.org/terms/modified",
+        epub.metadata().expand_property("dcterms:modified").unwrap()
+    );
+
+    let epub = rbook::Epub::new("tests/ebooks/childrens-literature.epub").unwrap();
+    assert!(epub.metadata().prefixes().is_empty());
+}
+
+#[test]
+fn collection_test() {
+    let epub = rbook::Epub::new("tests/ebooks/collection_epub").unwrap();
+
+    // Collection metadata does not leak into the package metadata
+    assert_eq!(1, epub.metadata().get("title").len());
+    assert_eq!(1, epub.metadata().get("modified").len());
+
+    assert_eq!(1, epub.collections().len());
+    let collection = epub.collections().first().unwrap();
+    assert_eq!("preview", collection.role());
+
+    let metadata = collection.metadata();
+    assert_eq!(2, metadata.len());
+    assert_eq!("title", metadata[0].name());
+    assert_eq!("Collection Example Preview", metadata[0].value());
+    assert_eq!("modified", metadata[1].name());
+    assert_eq!("2023-04-02T09:00:00Z", metadata[1].value());
+
+    let links = collection.links();
+    assert_eq!(1, links.len());
+    assert_eq!("c1.xhtml", links[0].value());
+
+    let nested = collection.collections();
+    assert_eq!(1, nested.len());
+    assert_eq!("manifest", nested[0].role());
+    assert_eq!("c1.xhtml", nested[0].links()[0].value());
+
+    assert!(epub.collections_by_role("index").is_empty());
+
+    let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    assert!(epub.collections().is_empty());
+}
+
+#[test]
+fn resource_test() {
+    let epub = rbook::Epub::new("tests/ebooks/example_epub").unwrap();
+
+    let by_id = epub.read_resource_bytes(Resource::Id("c1")).unwrap();
+    let by_index = epub.read_resource_bytes(Resource::SpineIndex(2)).unwrap();
+    assert_eq!(by_id, by_index);
+
+    // Non-manifest files can be retrieved by href
+    assert!(epub
+        .read_resource(Resource::Href("META-INF/container.xml"))
+        .is_ok());
+
+    assert!(epub.read_resource(Resource::Id("missing")).is_err());
+    assert!(epub.read_resource(Resource::SpineIndex(4)).is_err());
+    assert!(epub.manifest_element(Resource::SpineIndex(4)).is_none());
+}
+
+#[test]
+fn href_case_insensitive_test() {
+    // Disabled by default
+    let epub = rbook::Epub::new("tests/ebooks/example_epub").unwrap();
+    assert!(epub.read_file("C1.xhtml").is_err());
+    assert!(epub.manifest().by_href("C1.xhtml").is_none());
+
+    let settings = EpubSettings::new().href_case_insensitive(true);
+    let epub = rbook::Epub::open_with("tests/ebooks/example_epub", settings).unwrap();
+    assert_eq!(
+        epub.read_file("c1.xhtml").unwrap(),
+        epub.read_file("C1.XHTML").unwrap()
+    );
+    assert_eq!("c1", epub.manifest().by_href("./C1.xhtml").unwrap().name());
+    assert!(epub.read_file("c3.xhtml").is_err());
+
+    // Hrefs of the manifest itself differ in case from the archive
+    let package = r#"<package version="3.0" unique-identifier="uid">
+        <metadata>
+            <dc:identifier id="uid">id</dc:identifier>
+            <dc:title>Title</dc:title>
+        </metadata>
+        <manifest>
+            <item id="nav" href="Nav.xhtml" media-type="application/xhtml+xml" properties="nav"/>
+            <item id="c1" href="Text/C1.xhtml" media-type="application/xhtml+xml"/>
+        </manifest>
+        <spine>
+            <itemref idref="c1"/>
+        </spine>
+    </package>"#;
+    let files = [
+        ("mimetype", "application/epub+zip"),
+        ("META-INF/container.xml", CONTAINER),
+        ("content.opf", package),
+        (
+            "nav.xhtml",
+            r#"<nav epub:type="toc"><ol><li><a href="Text/C1.xhtml">1</a></li></ol></nav>"#,
+        ),
+        ("text/c1.xhtml", "<html><body><p>Chapter</p></body></html>"),
+    ];
+    assert!(rbook::Epub::read_from(build_epub(&files)).is_err());
+
+    let settings = EpubSettings::new().href_case_insensitive(true);
+    let epub = rbook::Epub::read_from_with(build_epub(&files), settings).unwrap();
+    assert_eq!(1, epub.toc().elements().len());
+    assert!(epub.spine_weights().total() > 0);
+    assert!(epub.reader().fetch_page(0).unwrap().is_ok());
+
+    // The mismatches are reported as a diagnostic
+    let rules = rbook::lint::RuleSet::recommended();
+    let mismatches: Vec<_> = epub
+        .lint(&rules)
+        .into_iter()
+        .filter(|finding| finding.rule() == "href-case")
+        .map(|finding| finding.location().unwrap().to_string())
+        .collect();
+    assert_eq!(vec!["c1", "nav"], mismatches);
+}
+
+#[test]
+fn normalize_backslashes_test() {
+    // Toc cannot be found without normalization on most platforms
+    if !cfg!(windows) {
+        assert!(rbook::Epub::new("tests/ebooks/backslash_epub").is_err());
+    }
+
+    let settings = EpubSettings::new().normalize_backslashes(true);
+    let epub = rbook::Epub::open_with("tests/ebooks/backslash_epub", settings).unwrap();
+
+    let chapter = epub.manifest().by_id("chapter").unwrap();
+    assert_eq!("text/chapter.xhtml", chapter.value());
+    assert_eq!(Some("text/chapter.xhtml"), chapter.get_attribute("href"));
+    assert_eq!("../text/chapter.xhtml", epub.toc().elements()[0].value());
+    assert!(epub.read_file(chapter.value()).is_ok());
+}
+
+#[test]
+fn unsafe_path_test() {
+    let epub = rbook::Epub::new("tests/ebooks/example_epub").unwrap();
+
+    // Traversal outside of the ebook directory
+    let error = epub.read_file("../../../Cargo.toml").unwrap_err();
+    assert!(matches!(
+        error,
+        EbookError::Archive(ArchiveError::UnsafePath { .. })
+    ));
+}
+
+#[cfg(unix)]
+#[test]
+fn symlink_test() {
+    use std::fs;
+    use std::os::unix::fs::symlink;
+    use std::path::Path;
+
+    // Copy an unzipped epub to a temporary directory to add a symbolic link
+    let root = std::env::temp_dir().join(format!("rbook_symlink_test_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+
+    for file in [
+        "mimetype",
+        "toc.xhtml",
+        "META-INF/container.xml",
+        "EPUB/example.opf",
+        "EPUB/c1.xhtml",
+    ] {
+        let destination = root.join(file);
+        fs::create_dir_all(destination.parent().unwrap()).unwrap();
+        fs::copy(
+            Path::new("tests/ebooks/example_epub").join(file),
+            destination,
+        )
+        .unwrap();
+    }
+    symlink(root.join("EPUB/c1.xhtml"), root.join("EPUB/link.xhtml")).unwrap();
+
+    let epub = rbook::Epub::new(&root).unwrap();
+    assert!(epub.read_file("link.xhtml").is_ok());
+
+    let settings = EpubSettings::new().follow_symlinks(false);
+    let epub = rbook::Epub::open_with(&root, settings).unwrap();
+    assert!(epub.read_file("c1.xhtml").is_ok());
+    assert!(matches!(
+        epub.read_file("link.xhtml").unwrap_err(),
+        EbookError::Archive(ArchiveError::UnsafePath { .. })
+    ));
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn parse_level_test() {
+    let settings = EpubSettings::new().parse_level(ParseLevel::MetadataOnly);
+    let epub = rbook::Epub::open_with("tests/ebooks/childrens-literature.epub", settings).unwrap();
+    assert!(epub.metadata().title().is_some());
+    assert!(epub.spine().elements().is_empty());
+    assert!(epub.read_file("nav.xhtml").is_ok());
+
+    let settings = EpubSettings::new().parse_level(ParseLevel::Structure);
+    let epub = rbook::Epub::open_with("tests/ebooks/childrens-literature.epub", settings).unwrap();
+    assert!(!epub.manifest().elements().is_empty());
+    assert!(!epub.spine().elements().is_empty());
+    assert!(epub.toc().elements().is_empty());
+}
+
+#[test]
+fn lazy_toc_test() {
+    let settings = EpubSettings::new().lazy_toc(true);
+    let epub = rbook::Epub::open_with("tests/ebooks/childrens-literature.epub", settings).unwrap();
+    let eager = rbook::Epub::new("tests/ebooks/childrens-literature.epub").unwrap();
+    assert_eq!(eager.toc().elements_flat(), epub.toc().elements_flat());
+
+    // Toc errors no longer prevent opening an epub
+    assert!(rbook::Epub::new("tests/ebooks/backslash_epub").is_err() || cfg!(windows));
+    let settings = EpubSettings::new().lazy_toc(true);
+    let epub = rbook::Epub::open_with("tests/ebooks/backslash_epub", settings).unwrap();
+    assert!(!format!("{epub:?}").contains("Some(Toc"));
+    if !cfg!(windows) {
+        assert!(epub.try_toc().is_err());
+        assert!(epub.toc().elements().is_empty());
+        // The empty fallback does not hide the error
+        assert!(epub.try_toc().is_err());
+    }
+
+    let settings = EpubSettings::new().lazy_toc(true);
+    let epub = rbook::Epub::open_with("tests/ebooks/childrens-literature.epub", settings).unwrap();
+    assert_eq!(
+        eager.toc().elements_flat(),
+        epub.try_toc().unwrap().elements_flat()
+    );
+}
+
+#[test]
+fn error_context_test() {
+    use std::error::Error;
+    use std::fs;
+    use std::path::Path;
+
+    // Copy an unzipped epub to a temporary directory to replace its toc
+    let root = std::env::temp_dir().join(format!("rbook_error_test_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+
+    for file in ["mimetype", "META-INF/container.xml", "EPUB/example.opf"] {
+        let destination = root.join(file);
+        fs::create_dir_all(destination.parent().unwrap()).unwrap();
+        fs::copy(
+            Path::new("tests/ebooks/example_epub").join(file),
+            destination,
+        )
+        .unwrap();
+    }
+    fs::write(root.join("toc.xhtml"), "<html><body></body></html>").unwrap();
+
+    let error = rbook::Epub::new(&root).unwrap_err();
+    match &error {
+        EbookError::File { path, source } => {
+            assert!(path.ends_with("toc.xhtml"));
+            assert!(matches!(**source, EbookError::Parse { .. }));
+        }
+        _ => panic!("Expected a file error: {error:?}"),
+    }
+    assert!(error.source().is_some());
+    assert!(error.pretty().starts_with("while processing"));
+    assert!(error.pretty().contains("Missing toc element"));
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn filename_decoding_test() {
+    use rbook::epub::FilenameDecoding;
+
+    let epub = rbook::Epub::new("tests/ebooks/encoding.epub").unwrap();
+    let spine_element = epub.spine().elements()[0];
+    let href = epub.manifest().by_id(spine_element.name()).unwrap().value();
+    assert!(epub.read_file(href).unwrap().contains("Bonjour"));
+    assert_eq!("Notes", epub.read_file("café.txt").unwrap());
+    assert_eq!(2, epub.decoded_names().len());
+
+    // Names are decoded as declared by each file, which do not match the package
+    let settings = EpubSettings::new().filename_decoding(FilenameDecoding::Declared);
+    let epub = rbook::Epub::open_with("tests/ebooks/encoding.epub", settings).unwrap();
+    assert!(epub.read_file("chapitre_é.xhtml").is_err());
+    assert_eq!(1, epub.decoded_names().len());
+
+    let settings = EpubSettings::new().filename_decoding(FilenameDecoding::Utf8Lossy);
+    let epub = rbook::Epub::open_with("tests/ebooks/encoding.epub", settings).unwrap();
+    assert!(epub.read_file("chapitre_é.xhtml").is_ok());
+    assert_eq!("Notes", epub.read_file("caf\u{FFFD}.txt").unwrap());
+    assert_eq!(b"OEBPS/caf\x82.txt", epub.decoded_names()[1].raw());
+
+    // Unzipped directories are unaffected
+    let epub = rbook::Epub::new("tests/ebooks/example_epub").unwrap();
+    assert!(epub.decoded_names().is_empty());
+}
+
+#[test]
+fn fallback_test() {
+    let epub = rbook::Epub::new("tests/ebooks/fallback_epub").unwrap();
+    let manifest = epub.manifest();
+
+    let chart = manifest.by_id("chart").unwrap();
+    let mut chain = manifest.fallback_chain(chart);
+    assert_eq!(3, chain.by_ref().count());
+    assert!(!chain.is_cyclic());
+
+    let supported = manifest.resolve_fallback(chart, |media_type| media_type.contains("xhtml"));
+    assert_eq!("chart-table", supported.unwrap().name());
+    assert!(manifest.resolve_fallback(chart, |_| false).is_none());
+
+    // Cycles end the chain instead of looping indefinitely
+    let looped = manifest.by_id("loop-b").unwrap();
+    let mut chain = manifest.fallback_chain(looped);
+    let ids: Vec<_> = chain.by_ref().map(|element| element.name()).collect();
+    assert_eq!(vec!["loop-b", "loop-a"], ids);
+    assert!(chain.is_cyclic());
+    assert!(manifest.resolve_fallback(looped, |_| false).is_none());
+}
+
+#[test]
+fn vendor_metadata_test() {
+    let epub = rbook::Epub::new("tests/ebooks/vendor_epub").unwrap();
+    let vendor = epub.metadata().vendor();
+
+    assert_eq!(Some("rbook Examples"), vendor.calibre_series());
+    assert_eq!(Some(2.0), vendor.calibre_series_index());
+    assert_eq!(Some(8.0), vendor.calibre_rating());
+    assert_eq!(Some("Example, Vendor"), vendor.calibre_title_sort());
+    assert_eq!(Some("1.0.0"), vendor.ibooks_version());
+    assert_eq!(Some(true), vendor.ibooks_specified_fonts());
+    assert_eq!(4, vendor.entries("calibre").len());
+    assert!(vendor.entries("kobo").is_empty());
+
+    let options = epub.apple_display_options().unwrap().unwrap();
+    assert_eq!(Some(false), options.fixed_layout());
+    // Options of all platforms apply to specific platforms
+    assert_eq!(Some("true"), options.get("iphone", "specified-fonts"));
+    assert_eq!(None, options.get("ipad", "orientation-lock"));
+    assert_eq!(3, options.options().len());
+
+    let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    assert_eq!(None, epub.metadata().vendor().calibre_series());
+    assert!(epub.apple_display_options().unwrap().is_none());
+}
+
+#[test]
+fn apple_display_options_xml_test() {
+    let epub = rbook::Epub::new("tests/ebooks/vendor_epub").unwrap();
+    let options = epub
+        .apple_display_options()
+        .unwrap()
+        .unwrap()
+        .with_fixed_layout(true)
+        .with_option("ipad", "orientation-lock", "<none> & \"all\"");
+
+    // Existing options are replaced, new platforms are appended
+    assert_eq!(Some(true), options.fixed_layout());
+    assert_eq!(4, options.options().len());
+    assert_eq!(
+        concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+            "<display_options>\n",
+            "  <platform name=\"*\">\n",
+            "    <option name=\"specified-fonts\">true</option>\n",
+            "    <option name=\"fixed-layout\">true</option>\n",
+            "  </platform>\n",
+            "  <platform name=\"iphone\">\n",
+            "    <option name=\"orientation-lock\">portrait-only</option>\n",
+            "  </platform>\n",
+            "  <platform name=\"ipad\">\n",
+            "    <option name=\"orientation-lock\">&lt;none&gt; &amp; &quot;all&quot;</option>\n",
+            "  </platform>\n",
+            "</display_options>\n",
+        ),
+        options.to_xml()
+    );
+
+    assert_eq!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<display_options>\n</display_options>\n",
+        AppleDisplayOptions::new().to_xml()
+    );
+}
+
+#[test]
+fn rating_test() {
+    let epub = rbook::Epub::new("tests/ebooks/vendor_epub").unwrap();
+    assert_eq!(Some(4.0), epub.metadata().rating());
+    assert_eq!("Developers", epub.metadata().audience()[0].value());
+
+    // Ratings greater than 5 are on a scale from 0 to 10
+    let epub = rbook::Epub::new("tests/ebooks/dictionary_epub").unwrap();
+    assert_eq!(Some(4.5), epub.metadata().rating());
+    assert_eq!("Students", epub.metadata().audience()[0].value());
+
+    let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    assert_eq!(None, epub.metadata().rating());
+    assert!(epub.metadata().audience().is_empty());
+}
+
+#[test]
+fn image_info_test() {
+    // Zipped images are compressed
+    let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    let images = epub.images_with_info();
+    assert_eq!(epub.manifest().images().len(), images.len());
+    assert!(images
+        .iter()
+        .all(|image| image.compressed_size().is_some() && image.size().is_some()));
+
+    // Files within a directory are not compressed
+    let epub = rbook::Epub::new("tests/ebooks/fallback_epub").unwrap();
+    let images = epub.images_with_info();
+    assert_eq!(2, images.len());
+    assert_eq!("chart.svg", images[0].element().value());
+    assert_eq!(images[0].size(), images[0].compressed_size());
+
+    #[cfg(feature = "image-meta")]
+    {
+        use rbook::epub::ImageFormat;
+
+        // Svg dimensions are retrieved from the viewBox
+        assert_eq!(Some(ImageFormat::Svg), images[0].format());
+        assert_eq!(Some((400, 300)), images[0].dimensions());
+        assert_eq!(Some(ImageFormat::Png), images[1].format());
+        assert_eq!(Some((3, 2)), images[1].dimensions());
+
+        let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+        let cover = epub
+            .images_with_info()
+            .into_iter()
+            .find(|image| image.element().value() == "images/9780316000000.jpg")
+            .unwrap();
+        assert_eq!(Some(ImageFormat::Jpeg), cover.format());
+        assert_eq!("image/jpeg", cover.format().unwrap().media_type());
+        assert_eq!(Some((646, 902)), cover.dimensions());
+    }
+}
+
+#[test]
+fn collection_parity_test() {
+    let epub = rbook::Epub::new("tests/ebooks/example_epub").unwrap();
+
+    let manifest = epub.manifest();
+    assert_eq!(manifest.elements().len(), manifest.len());
+    assert!(!manifest.is_empty());
+    assert_eq!(
+        manifest.elements().last().copied(),
+        manifest.get(manifest.len() - 1)
+    );
+    assert!(manifest.contains_href("c1.xhtml"));
+    assert!(!manifest.contains_href("c3.xhtml"));
+
+    let spine = epub.spine();
+    assert_eq!(4, spine.len());
+    assert!(!spine.is_empty());
+    assert_eq!("c1", spine.get(2).unwrap().name());
+    assert!(spine.get(4).is_none());
+    assert!(spine.contains_idref("c2"));
+    assert!(!spine.contains_idref("chart"));
+
+    let toc = epub.toc();
+    assert_eq!(toc.elements_flat().len(), toc.len());
+    assert!(!toc.is_empty());
+    assert!(toc.contains_href("EPUB/c1.xhtml"));
+    assert!(!toc.contains_href("EPUB/c1.xhtml#missing"));
+}
+
+#[test]
+fn collection_iter_test() {
+    let epub = rbook::Epub::new("tests/ebooks/childrens-literature.epub").unwrap();
+
+    let mut ids = Vec::new();
+    for element in epub.manifest() {
+        ids.push(element.name());
+    }
+    assert_eq!(epub.manifest().len(), ids.len());
+
+    let idrefs: Vec<_> = epub
+        .spine()
+        .into_iter()
+        .map(|element| element.name())
+        .collect();
+    assert_eq!(vec!["cover", "nav", "s04"], idrefs);
+
+    // Toc elements are flattened
+    assert_eq!(epub.toc().len(), epub.toc().into_iter().count());
+    assert_eq!(
+        epub.guide().elements().len(),
+        epub.guide().into_iter().count()
+    );
+}
+
+#[test]
+fn spine_range_test() {
+    let epub = rbook::Epub::new("tests/ebooks/example_epub").unwrap();
+    let spine = epub.spine();
+
+    assert_eq!("cover", spine[0].name());
+    assert_eq!(
+        spine.elements(),
+        spine.get_range(..).unwrap().iter().collect::<Vec<_>>()
+    );
+    assert_eq!(vec!["toc", "c1"], names(&spine[1..3]));
+    assert_eq!(vec!["c1", "c2"], names(&spine[2..=3]));
+    assert_eq!(Vec::<&str>::new(), names(&spine[4..]));
+    assert_eq!(Some(&spine[1..3]), spine.get_range(1..3));
+    assert!(spine.get_range(3..5).is_none());
+
+    fn names(elements: &[rbook::xml::Element]) -> Vec<&str> {
+        elements.iter().map(|element| element.name()).collect()
+    }
+}
+
+#[test]
+fn remote_references_test() {
+    let epub = rbook::Epub::new("tests/ebooks/content_epub").unwrap();
+    let remote_references = epub.remote_references();
+    assert_eq!(2, remote_references.len());
+
+    let c1 = remote_references
+        .iter()
+        .find(|references| references.element().name() == "c1")
+        .unwrap();
+    assert!(c1.is_declared());
+    assert_eq!(vec!["https://example.com/c1.png"], c1.urls());
+
+    // Hyperlinks are not remote resources
+    let c2 = remote_references
+        .iter()
+        .find(|references| references.element().name() == "c2")
+        .unwrap();
+    assert!(!c2.is_declared());
+    assert_eq!(vec!["https://example.com/font.css"], c2.urls());
+
+    let epub = rbook::Epub::new("tests/ebooks/childrens-literature.epub").unwrap();
+    assert!(epub.remote_references().is_empty());
+}
+
+#[test]
+fn unreachable_content_test() {
+    let epub = rbook::Epub::new("tests/ebooks/fallback_epub").unwrap();
+    let unreachable: Vec<_> = epub
+        .unreachable_content()
+        .into_iter()
+        .map(|element| element.name())
+        .collect();
+    assert_eq!(vec!["chart-table"], unreachable);
+
+    for path in [
+        "tests/ebooks/moby-dick.epub",
+        "tests/ebooks/childrens-literature.epub",
+    ] {
+        let epub = rbook::Epub::new(path).unwrap();
+        assert!(epub.unreachable_content().is_empty(), "{path}");
+    }
+}
+
+#[test]
+fn custom_vocabulary_test() {
+    use rbook::epub::{Vocabulary, VocabularyEntries};
+
+    #[derive(Debug, PartialEq)]
+    struct Apple {
+        version: String,
+        specified_fonts: bool,
+        iri: Option<String>,
+    }
+
+    impl Vocabulary for Apple {
+        const PREFIX: &'static str = "ibooks";
+
+        fn parse(entries: &VocabularyEntries<'_>) -> Option<Self> {
+            Some(Self {
+                version: entries.value("version")?.to_string(),
+                specified_fonts: entries.value("specified-fonts")?.parse().ok()?,
+                iri: entries.iri().map(str::to_string),
+            })
+        }
+    }
+
+    let epub = rbook::Epub::new("tests/ebooks/vendor_epub").unwrap();
+    let apple = epub.metadata().custom::<Apple>().unwrap();
+    assert_eq!("1.0.0", apple.version);
+    assert!(apple.specified_fonts);
+    // Reserved prefix that is not declared by the package
+    assert_eq!(None, apple.iri);
+
+    // Vocabularies without entries
+    let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    assert_eq!(None, epub.metadata().custom::<Apple>());
+}
+
+#[test]
+fn reading_system_profile_test() {
+    use rbook::epub::ReadingSystemProfile;
+
+    let epub = rbook::Epub::new("tests/ebooks/fallback_epub").unwrap();
+    let manifest = epub.manifest();
+    let chart = manifest.by_id("chart").unwrap();
+
+    let profile = ReadingSystemProfile::new();
+    assert_eq!("chart", manifest.resolve(chart, &profile).unwrap().name());
+    assert!(profile.supports_media_type("Image/PNG; charset=binary"));
+    assert!(!profile.supports_media_type("application/x-loop"));
+
+    // Falls back to the image, then the table
+    let profile = profile.unsupported_media_type("image/svg+xml");
+    assert_eq!(
+        "chart-image",
+        manifest.resolve(chart, &profile).unwrap().name()
+    );
+    let profile = profile.unsupported_media_type("image/png");
+    assert_eq!(
+        "chart-table",
+        manifest.resolve(chart, &profile).unwrap().name()
+    );
+
+    // Scripted content is unsupported without scripting
+    let c2 = manifest.by_id("c2").unwrap();
+    assert!(manifest.resolve(c2, &profile).is_some());
+    assert!(manifest
+        .resolve(c2, &ReadingSystemProfile::new().scripting(false))
+        .is_none());
+
+    // Cyclic chains without supported elements end
+    let loop_a = manifest.by_id("loop-a").unwrap();
+    assert!(manifest.resolve(loop_a, &profile).is_none());
+    let profile = ReadingSystemProfile::new().media_types(["application/x-loop"]);
+    assert_eq!("loop-a", manifest.resolve(loop_a, &profile).unwrap().name());
+}
+
+#[test]
+fn spine_spreads_test() {
+    let package = r#"<package version="3.0" unique-identifier="id">
+        <metadata><dc:title>Spreads</dc:title></metadata>
+        <manifest>
+            <item id="nav" href="nav.xhtml" media-type="application/xhtml+xml" properties="nav"/>
+        </manifest>
+        <spine page-progression-direction="rtl">
+            <itemref idref="p1" properties="page-spread-left"/>
+            <itemref idref="p2"/>
+            <itemref idref="p3"/>
+            <itemref idref="p4" linear="no"/>
+            <itemref idref="p5" properties="rendition:page-spread-center"/>
+            <itemref idref="p6"/>
+            <itemref idref="p7" properties="page-spread-right"/>
+        </spine>
+    </package>"#;
+    let nav = r#"<nav epub:type="toc"><ol><li><a href="p1.xhtml">1</a></li></ol></nav>"#;
+
+    let epub = rbook::Epub::read_from(build_epub(&[
+        ("META-INF/container.xml", CONTAINER),
+        ("content.opf", package),
+        ("nav.xhtml", nav),
+    ]))
+    .unwrap();
+
+    let spreads: Vec<_> = epub
+        .spine()
+        .spreads()
+        .iter()
+        .map(|spread| {
+            (
+                spread.left().map(|element| element.name()),
+                spread.right().map(|element| element.name()),
+                spread.center().map(|element| element.name()),
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        vec![
+            // Left is the second side of right-to-left spreads
+            (Some("p1"), None, None),
+            (Some("p3"), Some("p2"), None),
+            (None, None, Some("p5")),
+            (None, Some("p6"), None),
+            (None, Some("p7"), None),
+        ],
+        spreads
+    );
+
+    // Entries are retrieved in spine order
+    let spread = epub.spine().spreads()[1];
+    let names: Vec<_> = spread
+        .elements()
+        .iter()
+        .map(|element| element.name())
+        .collect();
+    assert_eq!(vec!["p2", "p3"], names);
+}
+
+#[test]
+fn layout_hints_test() {
+    let package = r#"<package version="3.0" unique-identifier="id">
+        <metadata><dc:title>Layout</dc:title><dc:language>ja-JP</dc:language></metadata>
+        <manifest>
+            <item id="nav" href="nav.xhtml" media-type="application/xhtml+xml" properties="nav"/>
+            <item id="reset" href="reset.css" media-type="text/css"/>
+            <item id="style" href="style.css" media-type="text/css"/>
+        </manifest>
+        <spine><itemref idref="nav"/></spine>
+    </package>"#;
+    let nav = r#"<nav epub:type="toc"><ol><li><a href="nav.xhtml">1</a></li></ol></nav>"#;
+    let reset = "/* writing-mode: horizontal-tb; */ p { text-writing-mode: none }";
+    let style = "html { -epub-writing-mode: Vertical-RL !important; writing-mode: vertical-rl }";
+
+    let epub = rbook::Epub::read_from(build_epub(&[
+        ("META-INF/container.xml", CONTAINER),
+        ("content.opf", package),
+        ("nav.xhtml", nav),
+        ("reset.css", reset),
+        ("style.css", style),
+    ]))
+    .unwrap();
+    let hints = epub.layout_hints();
+
+    assert_eq!(None, hints.page_progression_direction());
+    assert_eq!(Some("ja-JP"), hints.language());
+    assert_eq!(Some("vertical-rl"), hints.writing_mode());
+    assert!(hints.is_vertical());
+    // Vertical right-to-left text progresses from right to left
+    assert!(hints.is_rtl());
+
+    let epub = rbook::Epub::new("tests/ebooks/example_epub").unwrap();
+    let hints = epub.layout_hints();
+
+    assert_eq!(Some("ltr"), hints.page_progression_direction());
+    assert!(!hints.is_rtl());
+    assert!(!hints.is_vertical());
+}
+
+#[test]
+fn display_title_test() {
+    let package = r##"<package version="3.0" unique-identifier="id">
+        <metadata>
+            <dc:title id="t1">Penguin Classics</dc:title>
+            <meta refines="#t1" property="title-type">collection</meta>
+            <dc:title id="t2">or, The Whale</dc:title>
+            <meta refines="#t2" property="title-type">subtitle</meta>
+            <meta refines="#t2" property="display-seq">2</meta>
+            <dc:title id="t3">Moby-Dick</dc:title>
+            <meta refines="#t3" property="title-type">main</meta>
+            <meta refines="#t3" property="display-seq">1</meta>
+            <dc:title id="t4">Second Edition</dc:title>
+            <meta refines="#t4" property="title-type">Edition</meta>
+            <dc:title id="t5">Moby-Dick; or, The Whale</dc:title>
+            <meta refines="#t5" property="title-type">extended</meta>
+        </metadata>
+        <manifest>
+            <item id="nav" href="nav.xhtml" media-type="application/xhtml+xml" properties="nav"/>
+        </manifest>
+        <spine><itemref idref="nav"/></spine>
+    </package>"##;
+    let nav = r#"<nav epub:type="toc"><ol><li><a href="nav.xhtml">1</a></li></ol></nav>"#;
+    let epub = rbook::Epub::read_from(build_epub(&[
+        ("META-INF/container.xml", CONTAINER),
+        ("content.opf", package),
+        ("nav.xhtml", nav),
+    ]))
+    .unwrap();
+    let metadata = epub.metadata();
+
+    // Titles are ordered by their display sequence
+    let title = |kind| metadata.titles_by_kind(kind)[0].value();
+    assert_eq!("Moby-Dick", title(TitleKind::Main));
+    assert_eq!("Second Edition", title(TitleKind::Edition));
+    assert_eq!("Moby-Dick; or, The Whale", title(TitleKind::Expanded));
+
+    assert_eq!(
+        Some("Moby-Dick"),
+        metadata.display_title(TitleStyle::Main).as_deref()
+    );
+    // Expanded titles are preferred over the main title and subtitles
+    assert_eq!(
+        Some("Moby-Dick; or, The Whale"),
+        metadata.display_title(TitleStyle::Full).as_deref()
+    );
+    assert_eq!(
+        Some("Moby-Dick; or, The Whale, Second Edition (Penguin Classics)"),
+        metadata.display_title(TitleStyle::Catalog).as_deref()
+    );
+    assert_eq!(TitleKind::Other, TitleKind::from_type("unknown"));
+}
+
+#[test]
+fn sort_key_test() {
+    let package = r##"<package version="3.0" unique-identifier="id">
+        <metadata>
+            <dc:title>The Collected Works</dc:title>
+            <dc:title>A Subtitle</dc:title>
+            <dc:creator>Martin Luther King Jr.</dc:creator>
+            <dc:creator id="c2">Herman Melville</dc:creator>
+            <meta refines="#c2" property="file-as">Melville, Herman</meta>
+            <dc:creator>Austen, Jane</dc:creator>
+            <dc:creator>紫式部</dc:creator>
+            <dc:contributor opf:file-as="Beethoven, Ludwig van">Ludwig van Beethoven</dc:contributor>
+        </metadata>
+        <manifest>
+            <item id="nav" href="nav.xhtml" media-type="application/xhtml+xml" properties="nav"/>
+        </manifest>
+        <spine><itemref idref="nav"/></spine>
+    </package>"##;
+    let nav = r#"<nav epub:type="toc"><ol><li><a href="nav.xhtml">1</a></li></ol></nav>"#;
+    let epub = rbook::Epub::read_from(build_epub(&[
+        ("META-INF/container.xml", CONTAINER),
+        ("content.opf", package),
+        ("nav.xhtml", nav),
+    ]))
+    .unwrap();
+    let metadata = epub.metadata();
+
+    let sort_names: Vec<_> = metadata
+        .sorted_creators()
+        .into_iter()
+        .map(|creator| metadata.sort_name(creator))
+        .collect();
+    assert_eq!(
+        vec![
+            "Austen, Jane",
+            "King, Martin Luther, Jr.",
+            "Melville, Herman",
+            "紫式部"
+        ],
+        sort_names
+    );
+
+    let contributor = metadata.contributors()[0];
+    assert_eq!("beethoven, ludwig van", metadata.sort_key(contributor));
+
+    let titles = metadata.get("title");
+    assert_eq!("Collected Works, The", metadata.sort_name(titles[0]));
+    assert_eq!("Subtitle, A", metadata.sort_name(titles[1]));
+
+    // The calibre title sort entry applies to the primary title
+    let epub = rbook::Epub::new("tests/ebooks/vendor_epub").unwrap();
+    let title = epub.metadata().title().unwrap();
+    assert_eq!("Example, Vendor", epub.metadata().sort_name(title));
+}
+
+#[test]
+fn fingerprint_test() {
+    use std::io::{Read, Write};
+
+    // Repackage an epub with reversed entries and no compression
+    let file = std::fs::File::open("tests/ebooks/moby-dick.epub").unwrap();
+    let mut archive = zip::ZipArchive::new(file).unwrap();
+    let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    let options =
+        zip::write::FileOptions::default().compression_method(zip::CompressionMethod::Stored);
+
+    for index in (0..archive.len()).rev() {
+        let mut entry = archive.by_index(index).unwrap();
+        let mut data = Vec::new();
+        entry.read_to_end(&mut data).unwrap();
+
+        writer.start_file(entry.name(), options).unwrap();
+        writer.write_all(&data).unwrap();
+    }
+
+    let original = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    let repackaged = rbook::Epub::read_from(writer.finish().unwrap()).unwrap();
+    assert_eq!(original.fingerprint(), repackaged.fingerprint());
+    assert!(original.equivalent_to(&repackaged));
+
+    let other = rbook::Epub::new("tests/ebooks/childrens-literature.epub").unwrap();
+    assert_ne!(original.fingerprint(), other.fingerprint());
+}
+
+#[test]
+fn foreign_elements_test() {
+    let package = r#"<package version="3.0" unique-identifier="id" xmlns:acme="https://example.org/acme">
+        <metadata><dc:title>Foreign</dc:title></metadata>
+        <manifest>
+            <item id="nav" href="nav.xhtml" media-type="application/xhtml+xml" properties="nav"/>
+            <acme:checksum for="nav" value="abc"/>
+        </manifest>
+        <spine>
+            <itemref idref="nav"/>
+            <acme:hint mode="scroll"/>
+        </spine>
+        <acme:rights holder="Acme"/>
+        <guide><reference type="toc" title="Contents" href="nav.xhtml"/></guide>
+    </package>"#;
+    let nav = r#"<nav epub:type="toc"><ol><li><a href="nav.xhtml">1</a></li></ol></nav>"#;
+    let epub = rbook::Epub::read_from(build_epub(&[
+        ("META-INF/container.xml", CONTAINER),
+        ("content.opf", package),
+        ("nav.xhtml", nav),
+    ]))
+    .unwrap();
+
+    let foreign = epub.foreign_elements();
+    assert_eq!(1, foreign.len());
+    assert_eq!("acme:rights", foreign[0].name());
+    assert_eq!(Some("Acme"), foreign[0].get_attribute("holder"));
+
+    let foreign = epub.manifest().foreign_elements();
+    assert_eq!(1, foreign.len());
+    assert_eq!("acme:checksum", foreign[0].name());
+    assert_eq!(Some("abc"), foreign[0].get_attribute("value"));
+    assert_eq!(1, epub.manifest().len());
+
+    let foreign = epub.spine().foreign_elements();
+    assert_eq!(1, foreign.len());
+    assert_eq!(Some("scroll"), foreign[0].get_attribute("mode"));
+    assert_eq!(1, epub.spine().len());
+}
+
+#[test]
+fn extra_attributes_test() {
+    let package = r#"<package version="3.0" unique-identifier="id" xml:base="https://example.org/" xmlns:acme="https://example.org/acme">
+        <metadata><dc:title>Attributes</dc:title></metadata>
+        <manifest id="manifest" acme:checksums="sha256">
+            <item id="nav" href="nav.xhtml" media-type="application/xhtml+xml" properties="nav"/>
+        </manifest>
+        <spine toc="ncx" page-progression-direction="ltr" acme:mode="scroll">
+            <itemref idref="nav"/>
+        </spine>
+    </package>"#;
+    let nav = r#"<nav epub:type="toc"><ol><li><a href="nav.xhtml">1</a></li></ol></nav>"#;
+    let epub = rbook::Epub::read_from(build_epub(&[
+        ("META-INF/container.xml", CONTAINER),
+        ("content.opf", package),
+        ("nav.xhtml", nav),
+    ]))
+    .unwrap();
+    let names = |attributes: Vec<&rbook::xml::Attribute>| -> Vec<(String, String)> {
+        attributes
+            .into_iter()
+            .map(|attribute| (attribute.name().to_string(), attribute.value().to_string()))
+            .collect()
+    };
+
+    assert_eq!(
+        vec![("xml:base".to_string(), "https://example.org/".to_string())],
+        names(epub.metadata().extra_package_attributes())
+    );
+    assert_eq!(
+        vec![("acme:checksums".to_string(), "sha256".to_string())],
+        names(epub.manifest().extra_attributes())
+    );
+    assert_eq!(Some("manifest"), epub.manifest().get_attribute("id"));
+    assert_eq!(
+        vec![("acme:mode".to_string(), "scroll".to_string())],
+        names(epub.spine().extra_attributes())
+    );
+}
+
+#[test]
+fn content_decryptor_test() {
+    use rbook::epub::ContentDecryptor;
+
+    struct Rot13;
+
+    impl ContentDecryptor for Rot13 {
+        fn decrypt(
+            &self,
+            path: &str,
+            algorithm: &str,
+            data: Vec<u8>,
+        ) -> Result<Vec<u8>, ArchiveError> {
+            assert_eq!("OEBPS/c1.xhtml", path);
+            assert_eq!("urn:example:rot13", algorithm);
+            Ok(data.into_iter().map(rot13).collect())
+        }
+    }
+
+    fn rot13(byte: u8) -> u8 {
+        match byte {
+            b'a'..=b'z' => (byte - b'a' + 13) % 26 + b'a',
+            b'A'..=b'Z' => (byte - b'A' + 13) % 26 + b'A',
+            _ => byte,
+        }
+    }
+
+    let container = r#"<container><rootfiles>
+        <rootfile full-path="OEBPS/content.opf" media-type="application/oebps-package+xml"/>
+    </rootfiles></container>"#;
+    let encryption = r#"<encryption xmlns="urn:oasis:names:tc:opendocument:xmlns:container">
+        <EncryptedData xmlns="http://www.w3.org/2001/04/xmlenc#">
+            <EncryptionMethod Algorithm="urn:example:rot13"/>
+            <KeyInfo><EncryptionMethod Algorithm="urn:example:key"/></KeyInfo>
+            <CipherData><CipherReference URI="OEBPS/c1.xhtml"/></CipherData>
+        </EncryptedData>
+    </encryption>"#;
+    let package = r#"<package version="3.0" unique-identifier="id">
+        <metadata><dc:title>Encrypted</dc:title></metadata>
+        <manifest>
+            <item id="nav" href="nav.xhtml" media-type="application/xhtml+xml" properties="nav"/>
+            <item id="c1" href="c1.xhtml" media-type="application/xhtml+xml"/>
+        </manifest>
+        <spine><itemref idref="c1"/></spine>
+    </package>"#;
+    let nav = r#"<nav epub:type="toc"><ol><li><a href="c1.xhtml">1</a></li></ol></nav>"#;
+    let chapter = "<html><body><p>Uryyb, jbeyq!</p></body></html>";
+    let files = [
+        ("META-INF/container.xml", container),
+        ("META-INF/encryption.xml", encryption),
+        ("OEBPS/content.opf", package),
+        ("OEBPS/nav.xhtml", nav),
+        ("OEBPS/c1.xhtml", chapter),
+    ];
+
+    let settings = EpubSettings::new().decryptor(Rot13);
+    let epub = rbook::Epub::read_from_with(build_epub(&files), settings).unwrap();
+    assert!(epub
+        .read_file("c1.xhtml")
+        .unwrap()
+        .contains("Hello, world!"));
+    // Files that are not listed as encrypted are read as is
+    assert!(epub.read_file("nav.xhtml").unwrap().contains("toc"));
+
+    // Without a decryptor, encrypted files are read as is
+    let epub = rbook::Epub::read_from(build_epub(&files)).unwrap();
+    assert!(epub.read_file("c1.xhtml").unwrap().contains("Uryyb"));
+}
+
+#[test]
+fn resource_info_test() {
+    use rbook::epub::Compression;
+
+    let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    let info = epub.resource_info(Resource::SpineIndex(0)).unwrap();
+    let data = epub.read_resource_bytes(Resource::SpineIndex(0)).unwrap();
+
+    assert_eq!("OPS/cover.xhtml", info.path());
+    assert_eq!(data.len() as u64, info.size());
+    assert_eq!(256, info.compressed_size());
+    assert_eq!(Some(0x762c7148), info.crc32());
+    assert_eq!(Some(Compression::Deflated), info.compression());
+    // 2015-04-24T17:26:04Z
+    let modified = std::time::UNIX_EPOCH + std::time::Duration::from_secs(1_429_896_364);
+    assert_eq!(Some(modified), info.modified());
+
+    // Files of unzipped epubs are not compressed
+    let epub = rbook::Epub::new("tests/ebooks/example_epub").unwrap();
+    let info = epub.resource_info(Resource::Id("c1")).unwrap();
+    assert_eq!("EPUB/c1.xhtml", info.path());
+    assert_eq!(None, info.compression());
+    assert_eq!(None, info.crc32());
+    assert_eq!(info.size(), info.compressed_size());
+
+    assert!(epub.resource_info(Resource::Id("missing")).is_err());
+}
+
+#[test]
+fn read_resource_ref_test() {
+    let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+
+    for resource in [
+        Resource::Id("cover-image"),
+        Resource::Href("images/9780316000000.jpg"),
+        Resource::SpineIndex(3),
+    ] {
+        let bytes = epub.read_resource_bytes(resource).unwrap();
+        let data = epub.read_resource_ref(resource).unwrap();
+        assert_eq!(bytes, data.as_ref());
+    }
+
+    assert!(epub.read_resource_ref(Resource::Id("missing")).is_err());
+
+    // Stored files of epubs held in memory are borrowed
+    use std::borrow::Cow;
+    use std::io::Write;
+    use zip::CompressionMethod::{Deflated, Stored};
+
+    let package = r#"<package version="3.0" unique-identifier="uid">
+        <metadata>
+            <dc:identifier id="uid">id</dc:identifier>
+            <dc:title>Title</dc:title>
+        </metadata>
+        <manifest>
+            <item id="nav" href="nav.xhtml" media-type="application/xhtml+xml" properties="nav"/>
+            <item id="image" href="image.png" media-type="image/png"/>
+        </manifest>
+        <spine><itemref idref="nav"/></spine>
+    </package>"#;
+    let nav = r#"<nav epub:type="toc"><ol><li><a href="nav.xhtml">1</a></li></ol></nav>"#;
+
+    let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    for (name, data, compression) in [
+        ("META-INF/container.xml", CONTAINER, Deflated),
+        ("content.opf", package, Deflated),
+        ("nav.xhtml", nav, Deflated),
+        ("image.png", "image data", Stored),
+    ] {
+        let options = zip::write::FileOptions::default().compression_method(compression);
+        writer.start_file(name, options).unwrap();
+        writer.write_all(data.as_bytes()).unwrap();
+    }
+    let epub = rbook::Epub::from_bytes(writer.finish().unwrap().into_inner()).unwrap();
+
+    let image = epub.read_resource_ref(Resource::Id("image")).unwrap();
+    assert!(matches!(image, Cow::Borrowed(b"image data")));
+    let nav_data = epub.read_resource_ref(Resource::Id("nav")).unwrap();
+    assert!(matches!(nav_data, Cow::Owned(_)));
+    assert_eq!(nav.as_bytes(), nav_data.as_ref());
+}
+
+#[test]
+fn spine_media_test() {
+    let package = r##"<package version="3.0" unique-identifier="uid" prefix="media: http://www.idpf.org/epub/vocab/overlays/#">
+        <metadata>
+            <dc:identifier id="uid">id</dc:identifier>
+            <dc:title>Title</dc:title>
+            <meta property="media:duration">0:10:00.5</meta>
+            <meta property="media:narrator">Joe Speaker</meta>
+            <meta property="media:active-class">-epub-media-overlay-active</meta>
+            <meta property="media:playback-active-class">-epub-media-overlay-playing</meta>
+            <meta property="media:duration" refines="#c1_overlay">0:04:00</meta>
+            <meta property="media:narrator" refines="#c1_overlay">Joe Speaker</meta>
+            <meta property="media:duration" refines="#c2_ref">0:06:00.5</meta>
+            <meta property="media:narrator" refines="#c2_ref">Jane Speaker</meta>
+            <meta property="custom" refines="#c2_ref">value</meta>
+            <meta property="media:duration" refines="#missing">0:01:00</meta>
+        </metadata>
+        <manifest>
+            <item id="nav" href="nav.xhtml" media-type="application/xhtml+xml" properties="nav"/>
+            <item id="c1" href="c1.xhtml" media-type="application/xhtml+xml" media-overlay="c1_overlay"/>
+            <item id="c1_overlay" href="c1.smil" media-type="application/smil+xml"/>
+            <item id="c2" href="c2.xhtml" media-type="application/xhtml+xml"/>
+        </manifest>
+        <spine>
+            <itemref idref="c1"/>
+            <itemref id="c2_ref" idref="c2"/>
+        </spine>
+    </package>"##;
+    let epub = rbook::Epub::read_from(build_epub(&[
+        ("META-INF/container.xml", CONTAINER),
+        ("content.opf", package),
+        (
+            "nav.xhtml",
+            r#"<nav epub:type="toc"><ol><li><a href="c1.xhtml">1</a></li></ol></nav>"#,
+        ),
+    ]))
+    .unwrap();
+
+    let c1 = &epub.spine()[0];
+    let c2 = &epub.spine()[1];
+
+    // Refinements of the media overlay
+    assert_eq!("c1_overlay", epub.media_overlay(c1).unwrap().name());
+    let media = epub.spine_media(c1);
+    assert_eq!(Duration::parse("4:00"), media.duration());
+    assert_eq!(vec!["Joe Speaker"], media.narrators());
+
+    // Refinements of the itemref
+    assert_eq!(None, epub.media_overlay(c2));
+    assert_eq!(3, c2.children().len());
+    assert_eq!(
+        Some("value"),
+        c2.get_child("custom").map(|child| child.value())
+    );
+    let media = epub.spine_media(c2);
+    assert_eq!(Duration::parse("6:00.5"), media.duration());
+    assert_eq!(vec!["Jane Speaker"], media.narrators());
+    assert_eq!(2, media.elements().len());
+
+    // Refinements are not top-level metadata
+    assert_eq!(1, epub.metadata().get("media:duration").len());
+
+    // Entries of the ebook
+    let media = epub.metadata().media();
+    assert_eq!(600.5, media.duration().unwrap().as_secs_f64());
+    assert_eq!(vec!["Joe Speaker"], media.narrators());
+    assert_eq!(Some("-epub-media-overlay-active"), media.active_class());
+    assert_eq!(
+        Some("-epub-media-overlay-playing"),
+        media.playback_active_class()
+    );
+}
+
+#[test]
+fn duration_test() {
+    let seconds = |value| Duration::parse(value).map(|duration| duration.as_secs_f64());
+
+    assert_eq!(Some(1949.0), seconds("0:32:29"));
+    assert_eq!(Some(1949.0), seconds(" 32:29 "));
+    assert_eq!(Some(62.5), seconds("00:01:02.500"));
+    assert_eq!(Some(360_000.0), seconds("100:00:00"));
+    assert_eq!(
+        std::time::Duration::new(1, 123_456_789),
+        Duration::parse("0:01.1234567891").unwrap().as_std()
+    );
+
+    // Timecount values
+    assert_eq!(Some(12.0), seconds("12"));
+    assert_eq!(Some(12.5), seconds("12.5s"));
+    assert_eq!(Some(0.5), seconds("500ms"));
+    assert_eq!(Some(210.0), seconds("3.5min"));
+    assert_eq!(Some(5400.0), seconds("1.5h"));
+
+    // Formatting
+    let format = |value| Duration::parse(value).unwrap().to_string();
+    assert_eq!("0:32:29", format("32:29"));
+    assert_eq!("0:01:02.5", format("00:01:02.500"));
+    assert_eq!("100:00:00.001", format("100:00:00.001"));
+    assert_eq!(
+        Duration::parse("12.25s"),
+        Duration::parse(&format("12.25s"))
+    );
+
+    for invalid in [
+        "", "s", "1.5sec", "ms", "1:2:3:4", "1:60:00", "0:60", "a:00", "0:01.", "0:01.5s", "-1:00",
+    ] {
+        assert_eq!(None, Duration::parse(invalid), "{invalid}");
+    }
+}
+
+#[test]
+fn matches_property_test() {
+    let package = r#"<package version="3.0" unique-identifier="uid" prefix="terms: http://purl.org/dc/terms/ a11y: http://example.com/a11y#">
+        <metadata>
+            <dc:identifier id="uid">id</dc:identifier>
+            <dc:title>Title</dc:title>
+            <meta property="terms:modified">2024-01-01T00:00:00Z</meta>
+            <meta property="a11y:certifiedBy">Acme</meta>
+            <meta property="acme:custom">value</meta>
+            <meta property="primary-writing-mode">horizontal-tb</meta>
+            <meta name="calibre:series" content="Series"/>
+        </metadata>
+        <manifest>
+            <item id="nav" href="nav.xhtml" media-type="application/xhtml+xml" properties="nav"/>
+        </manifest>
+        <spine><itemref idref="nav"/></spine>
+    </package>"#;
+    let epub = rbook::Epub::read_from(build_epub(&[
+        ("META-INF/container.xml", CONTAINER),
+        ("content.opf", package),
+        (
+            "nav.xhtml",
+            r#"<nav epub:type="toc"><ol><li><a href="nav.xhtml">1</a></li></ol></nav>"#,
+        ),
+    ]))
+    .unwrap();
+    let metadata = epub.metadata();
+    let find = |name| metadata.get(name)[0];
+
+    // Nonstandard prefix bound to the same IRI
+    let modified = find("modified");
+    assert!(metadata.matches_property(modified, "dcterms:modified"));
+    assert!(metadata.matches_property(modified, "terms:modified"));
+    assert_eq!(
+        Some("http://purl.org/dc/terms/modified"),
+        metadata.property_iri(modified).as_deref()
+    );
+
+    // Declared prefixes override reserved prefixes
+    let certified_by = find("certifiedBy");
+    assert!(!metadata.matches_property(
+        certified_by,
+        "http://www.idpf.org/epub/vocab/package/a11y/#certifiedBy"
+    ));
+    assert!(metadata.matches_property(certified_by, "http://example.com/a11y#certifiedBy"));
+
+    // Default vocabularies
+    let writing_mode = find("primary-writing-mode");
+    assert!(metadata.matches_property(writing_mode, "primary-writing-mode"));
+    assert!(!metadata.matches_property(writing_mode, "dcterms:primary-writing-mode"));
+    let title = metadata.title().unwrap();
+    assert!(metadata.matches_property(title, "dc:title"));
+    assert!(metadata.matches_property(title, "http://purl.org/dc/elements/1.1/title"));
+    assert!(!metadata.matches_property(title, "title"));
+
+    // Undeclared prefixes are compared by text
+    let custom = find("custom");
+    assert_eq!(None, metadata.property_iri(custom));
+    assert!(metadata.matches_property(custom, "acme:custom"));
+    assert!(!metadata.matches_property(custom, "other:custom"));
+
+    // Legacy meta elements
+    let series = find("calibre:series");
+    assert!(metadata.matches_property(series, "calibre:series"));
+    assert!(!metadata.matches_property(modified, "modified"));
+}
+
+#[test]
+fn generate_toc_from_headings_test() {
+    use rbook::epub::HeadingOptions;
+
+    let package = r#"<package version="3.0" unique-identifier="uid">
+        <metadata><dc:identifier id="uid">id</dc:identifier><dc:title>Title</dc:title></metadata>
+        <manifest>
+            <item id="nav" href="nav.xhtml" media-type="application/xhtml+xml" properties="nav"/>
+            <item id="c1" href="text/c1.xhtml" media-type="application/xhtml+xml"/>
+            <item id="c2" href="text/c2.xhtml" media-type="application/xhtml+xml"/>
+            <item id="notes" href="text/notes.xhtml" media-type="application/xhtml+xml"/>
+        </manifest>
+        <spine>
+            <itemref idref="c1"/>
+            <itemref idref="c2"/>
+            <itemref idref="notes" linear="no"/>
+        </spine>
+    </package>"#;
+    let c1 = r#"<html><head><title>Ignored</title></head><body>
+        <h1 id="part">Part <em>One</em></h1>
+        <h2 id="s1">Rock &amp; Roll</h2>
+        <h3>Deep</h3>
+        <h4 id="deeper">Too deep</h4>
+        <h2 id="empty"> </h2>
+        <h2 id="s2">Second</h2>
+    </body></html>"#;
+    let c2 = r#"<html><body><h2 id="orphan">Orphan</h2><h1>Part Two</h1></body></html>"#;
+    let notes = r#"<html><body><h1 id="notes">Notes</h1></body></html>"#;
+    let epub = rbook::Epub::read_from(build_epub(&[
+        ("META-INF/container.xml", CONTAINER),
+        ("content.opf", package),
+        (
+            "nav.xhtml",
+            r#"<nav epub:type="toc"><ol><li><a href="text/c1.xhtml">1</a></li></ol></nav>"#,
+        ),
+        ("text/c1.xhtml", c1),
+        ("text/c2.xhtml", c2),
+        ("text/notes.xhtml", notes),
+    ]))
+    .unwrap();
+
+    let toc = epub.generate_toc_from_headings(&HeadingOptions::new());
+    let flat: Vec<_> = toc
+        .elements_flat()
+        .into_iter()
+        .map(|element| (element.name(), element.value()))
+        .collect();
+    assert_eq!(
+        vec![
+            ("Part One", "text/c1.xhtml#part"),
+            ("Rock & Roll", "text/c1.xhtml#s1"),
+            ("Deep", "text/c1.xhtml"),
+            ("Second", "text/c1.xhtml#s2"),
+            ("Orphan", "text/c2.xhtml#orphan"),
+            ("Part Two", "text/c2.xhtml"),
+        ],
+        flat
+    );
+
+    // Nesting by level, continuing across documents
+    let top: Vec<_> = toc
+        .elements()
+        .into_iter()
+        .map(|element| element.name())
+        .collect();
+    assert_eq!(vec!["Part One", "Part Two"], top);
+    let part = toc.elements()[0];
+    let sections: Vec<_> = part
+        .children()
+        .into_iter()
+        .map(|element| element.name())
+        .collect();
+    assert_eq!(vec!["Rock & Roll", "Second", "Orphan"], sections);
+    assert_eq!("Deep", part.children()[0].children()[0].name());
+
+    // Custom levels and non-linear entries
+    let options = HeadingOptions::new()
+        .min_level(1)
+        .max_level(1)
+        .include_non_linear(true);
+    let toc = epub.generate_toc_from_headings(&options);
+    let names: Vec<_> = toc
+        .elements()
+        .into_iter()
+        .map(|element| element.name())
+        .collect();
+    assert_eq!(vec!["Part One", "Part Two", "Notes"], names);
+}
+
+#[test]
+fn localized_metadata_test() {
+    let package = r##"<package version="3.0" unique-identifier="uid" xml:lang="en">
+        <metadata>
+            <dc:identifier id="uid">id</dc:identifier>
+            <dc:language>en</dc:language>
+            <dc:language>fr</dc:language>
+            <dc:title id="t1">The Little Prince</dc:title>
+            <meta refines="#t1" property="title-type">main</meta>
+            <meta refines="#t1" property="alternate-script" xml:lang="ja">星の王子さま</meta>
+            <dc:title id="t2" xml:lang="fr-CA">Le Petit Prince</dc:title>
+            <meta refines="#t2" property="title-type">main</meta>
+            <dc:description>A pilot meets a prince.</dc:description>
+            <dc:description xml:lang="fr">Un aviateur rencontre un prince.</dc:description>
+            <dc:creator id="c1">Antoine de Saint-Exupéry</dc:creator>
+            <meta refines="#c1" property="alternate-script" xml:lang="ja">サン＝テグジュペリ</meta>
+            <meta property="dcterms:modified">2024-01-01T00:00:00Z</meta>
+        </metadata>
+        <manifest>
+            <item id="nav" href="nav.xhtml" media-type="application/xhtml+xml" properties="nav"/>
+        </manifest>
+        <spine><itemref idref="nav"/></spine>
+    </package>"##;
+    let epub = rbook::Epub::read_from(build_epub(&[
+        ("META-INF/container.xml", CONTAINER),
+        ("content.opf", package),
+        (
+            "nav.xhtml",
+            r#"<nav epub:type="toc"><ol><li><a href="nav.xhtml">1</a></li></ol></nav>"#,
+        ),
+    ]))
+    .unwrap();
+    let metadata = epub.metadata();
+
+    // Inherits the language of the package
+    let english = metadata.localized("en-US");
+    assert_eq!(Some("The Little Prince"), english.title());
+    assert_eq!(Some("A pilot meets a prince."), english.description());
+
+    // Regional variants match
+    let french = metadata.localized("FR");
+    assert_eq!("FR", french.language());
+    assert_eq!(Some("Le Petit Prince"), french.title());
+    assert_eq!(
+        Some("Un aviateur rencontre un prince."),
+        french.description()
+    );
+    assert_eq!(vec!["Antoine de Saint-Exupéry"], french.creators());
+
+    // Alternate scripts
+    let japanese = metadata.localized("ja");
+    assert_eq!(Some("星の王子さま"), japanese.title());
+    assert_eq!(vec!["サン＝テグジュペリ"], japanese.creators());
+    assert!(!japanese.is_available(metadata.description().unwrap()));
+
+    // Falls back to the default entries
+    let german = metadata.localized("de");
+    assert_eq!(Some("The Little Prince"), german.title());
+    assert_eq!(Some("A pilot meets a prince."), german.description());
+    assert_eq!(None, german.value(metadata.title().unwrap()));
+}
+
+#[test]
+fn text_direction_test() {
+    let package = r#"<package version="3.0" unique-identifier="uid" xml:lang="ar" dir="rtl">
+        <metadata>
+            <dc:identifier id="uid">id</dc:identifier>
+            <dc:language>ar</dc:language>
+            <dc:title>كتاب</dc:title>
+            <dc:creator xml:lang="en" dir="LTR">John Smith</dc:creator>
+            <dc:publisher dir="sideways">Publisher</dc:publisher>
+        </metadata>
+        <manifest>
+            <item id="nav" href="nav.xhtml" media-type="application/xhtml+xml" properties="nav"/>
+        </manifest>
+        <spine><itemref idref="nav"/></spine>
+    </package>"#;
+    let epub = rbook::Epub::read_from(build_epub(&[
+        ("META-INF/container.xml", CONTAINER),
+        ("content.opf", package),
+        (
+            "nav.xhtml",
+            r#"<nav epub:type="toc"><ol><li><a href="nav.xhtml">1</a></li></ol></nav>"#,
+        ),
+    ]))
+    .unwrap();
+    let metadata = epub.metadata();
+
+    assert_eq!(Some(TextDirection::Rtl), metadata.text_direction());
+    assert_eq!("rtl", metadata.text_direction().unwrap().as_str());
+
+    // Inherited from the package
+    let title = metadata.title().unwrap();
+    assert_eq!(Some(TextDirection::Rtl), metadata.text_direction_of(title));
+
+    let creator = metadata.creators()[0];
+    assert_eq!(
+        Some(TextDirection::Ltr),
+        metadata.text_direction_of(creator)
+    );
+
+    // Unrecognized values are ignored
+    let publisher = metadata.publisher()[0];
+    assert_eq!(
+        Some(TextDirection::Rtl),
+        metadata.text_direction_of(publisher)
+    );
+}
+
+#[test]
+fn container_entries_test() {
+    let mut zip = build_epub(&[
+        ("mimetype", "application/epub+zip"),
+        ("META-INF/container.xml", CONTAINER),
+        (
+            "content.opf",
+            r#"<package version="3.0" unique-identifier="uid">
+                <metadata><dc:identifier id="uid">id</dc:identifier><dc:title>Title</dc:title></metadata>
+                <manifest>
+                    <item id="nav" href="nav.xhtml" media-type="application/xhtml+xml" properties="nav"/>
+                    <item id="c1" href="c1.xhtml" media-type="application/xhtml+xml"/>
+                </manifest>
+                <spine><itemref idref="c1"/></spine>
+            </package>"#,
+        ),
+        (
+            "nav.xhtml",
+            r#"<nav epub:type="toc"><ol><li><a href="c1.xhtml">1</a></li></ol></nav>"#,
+        ),
+        ("c1.xhtml", "<html><body>c1</body></html>"),
+        ("orphan.xhtml", "<html><body>orphan</body></html>"),
+    ]);
+
+    // Directory entries are excluded
+    let mut writer = zip::ZipWriter::new_append(&mut zip).unwrap();
+    writer
+        .add_directory("images/", zip::write::FileOptions::default())
+        .unwrap();
+    writer.finish().unwrap();
+    drop(writer);
+
+    let epub = rbook::Epub::read_from(zip).unwrap();
+    let entries = epub.container_entries().unwrap();
+    let paths: Vec<_> = entries.iter().map(|entry| entry.path()).collect();
+
+    assert_eq!(
+        vec![
+            "mimetype",
+            "META-INF/container.xml",
+            "content.opf",
+            "nav.xhtml",
+            "c1.xhtml",
+            "orphan.xhtml"
+        ],
+        paths
+    );
+    assert_eq!(
+        b"<html><body>orphan</body></html>".len() as u64,
+        entries[5].size()
+    );
+
+    // Files of unzipped epubs are sorted by path
+    let epub = rbook::Epub::new("tests/ebooks/example_epub").unwrap();
+    let entries = epub.container_entries().unwrap();
+    let paths: Vec<_> = entries.iter().map(|entry| entry.path()).collect();
+
+    assert_eq!(7, paths.len());
+    assert_eq!("EPUB/c1.xhtml", paths[0]);
+    assert!(paths.contains(&"META-INF/container.xml"));
+    assert!(paths.contains(&"toc.xhtml"));
+}
+
+const CONTAINER: &str = r#"<container><rootfiles>
+    <rootfile full-path="content.opf" media-type="application/oebps-package+xml"/>
+</rootfiles></container>"#;
+
+// Create an in-memory epub archive from a list of files
+fn build_epub(files: &[(&str, &str)]) -> Cursor<Vec<u8>> {
+    use std::io::Write;
+
+    let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    for (name, data) in files {
+        writer
+            .start_file(*name, zip::write::FileOptions::default())
+            .unwrap();
+        writer.write_all(data.as_bytes()).unwrap();
+    }
+    writer.finish().unwrap()
+}
@@ -1,4 +1,5 @@
 use rbook::read::ContentType;
+use rbook::read::{TextPositionSelector, TextQuoteSelector};
 use rbook::result::ReaderError;
 use rbook::Ebook;
 
@@ -112,3 +113,247 @@ fn read_all_iterator_test2() {
         );
     }
 }
+
+#[test]
+fn progress_test() {
+    let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    let spine_weights = epub.spine_weights();
+    assert_eq!(144, spine_weights.weights().len());
+
+    // Progress increases monotonically throughout the ebook
+    let mut previous = 0.0;
+    for index in 0..144 {
+        let progress = spine_weights.progress(index, 0.5).unwrap();
+        assert!(progress >= previous);
+        previous = progress;
+    }
+
+    assert!(spine_weights.progress(144, 0.0).is_none());
+
+    // Reader progress is based on its current index
+    let mut reader = epub.reader();
+    reader.set_current_page(72);
+    assert_eq!(spine_weights.progress(72, 0.25), reader.progress(0.25));
+
+    // Non-linear entries do not advance progress
+    let epub = rbook::Epub::new("tests/ebooks/example_epub").unwrap();
+    let reader = epub.reader();
+    assert_eq!(Some(0.0), reader.progress(1.0));
+
+    // Entries the reader does not traverse do not contribute to progress
+    let epub = rbook::Epub::new("tests/ebooks/fallback_epub").unwrap();
+    let settings = rbook::epub::EpubReaderSettings::new().skip_property("scripted");
+    let mut reader = epub.reader_with(settings);
+    reader.set_current_page(reader.page_count() - 1);
+    assert_eq!(Some(1.0), reader.progress(1.0));
+}
+
+#[test]
+fn reader_filter_test() {
+    use rbook::epub::EpubReaderSettings;
+
+    let epub = rbook::Epub::new("tests/ebooks/fallback_epub").unwrap();
+
+    let settings = EpubReaderSettings::new()
+        .skip_property("scripted")
+        .content_filter(|spine_element, _| spine_element.get_attribute("linear") != Some("no"));
+    let mut reader = epub.reader_with(settings);
+    assert_eq!(2, reader.page_count());
+
+    // Reader indexes only count the remaining entries
+    let content = reader.current_page().unwrap();
+    assert_eq!("nav", content.get_content(ContentType::Id).unwrap());
+    let content = reader.next_page().unwrap().unwrap();
+    assert_eq!("c1", content.get_content(ContentType::Id).unwrap());
+    assert!(reader.next_page().is_none());
+    assert_eq!(2, reader.iter().count());
+
+    // Filtered entries cannot be navigated to
+    assert!(reader.fetch_page_str("c2.xhtml").is_none());
+    assert_eq!(
+        0,
+        reader
+            .set_current_page_str("nav.xhtml")
+            .unwrap()
+            .map(|_| 0)
+            .unwrap()
+    );
+    assert_eq!(0, reader.current_index());
+
+    let reader = epub.reader_with(EpubReaderSettings::new().xhtml_only(true));
+    assert_eq!(4, reader.page_count());
+
+    let reader = epub.reader_with(EpubReaderSettings::new().content_filter(|_, _| false));
+    assert_eq!(0, reader.page_count());
+    assert!(reader.fetch_page(0).is_none());
+}
+
+#[test]
+fn reader_profile_test() {
+    use rbook::epub::{EpubReaderSettings, ReadingSystemProfile};
+
+    let epub = rbook::Epub::new("tests/ebooks/fallback_epub").unwrap();
+    let reader = |profile| epub.reader_with(EpubReaderSettings::new().profile(profile));
+
+    assert_eq!(4, reader(ReadingSystemProfile::new()).page_count());
+
+    // The cover is pre-paginated and non-linear
+    let fixed_layout = reader(ReadingSystemProfile::new().fixed_layout(false));
+    assert_eq!(3, fixed_layout.page_count());
+    let content = fixed_layout.current_page().unwrap();
+    assert_eq!("nav", content.get_content(ContentType::Id).unwrap());
+    assert_eq!(
+        3,
+        reader(ReadingSystemProfile::new().skip_non_linear(true)).page_count()
+    );
+
+    // Spine entries without supported content are skipped
+    let no_scripting = reader(ReadingSystemProfile::new().scripting(false));
+    assert!(no_scripting.fetch_page_str("c2.xhtml").is_none());
+    assert_eq!(3, no_scripting.page_count());
+    let no_xhtml = ReadingSystemProfile::new().unsupported_media_type("application/xhtml+xml");
+    assert_eq!(0, reader(no_xhtml).page_count());
+}
+
+#[test]
+fn reader_error_behavior_test() {
+    use rbook::epub::EpubReaderSettings;
+    use rbook::read::ErrorBehavior;
+    use std::fs;
+    use std::path::Path;
+
+    // Copy an unzipped epub to a temporary directory without a chapter
+    let root = std::env::temp_dir().join(format!("rbook_reader_error_test_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+
+    for file in [
+        "mimetype",
+        "toc.xhtml",
+        "META-INF/container.xml",
+        "EPUB/example.opf",
+        "EPUB/cover.xhtml",
+        "EPUB/c2.xhtml",
+    ] {
+        let destination = root.join(file);
+        fs::create_dir_all(destination.parent().unwrap()).unwrap();
+        fs::copy(
+            Path::new("tests/ebooks/example_epub").join(file),
+            destination,
+        )
+        .unwrap();
+    }
+
+    let epub = rbook::Epub::new(&root).unwrap();
+
+    // Errors are returned by default
+    let mut reader = epub.reader();
+    reader.set_current_page(1);
+    assert!(matches!(
+        reader.next_page(),
+        Some(Err(ReaderError::NoContent(_)))
+    ));
+    assert!(reader.next_page().unwrap().is_ok());
+    assert_eq!(1, reader.iter().filter(Result::is_err).count());
+
+    // Missing chapters are skipped during sequential reading
+    let settings = EpubReaderSettings::new().on_error(ErrorBehavior::Skip);
+    let mut reader = epub.reader_with(settings);
+    reader.set_current_page(1);
+    let content = reader.next_page().unwrap().unwrap();
+    assert_eq!("c2", content.get_content(ContentType::Id).unwrap());
+    assert_eq!(3, reader.current_index());
+    let content = reader.previous_page().unwrap().unwrap();
+    assert_eq!("toc", content.get_content(ContentType::Id).unwrap());
+    assert_eq!(3, reader.iter().count());
+    assert!(reader.fetch_page(2).unwrap().is_err());
+
+    // Missing chapters are replaced with a placeholder
+    let placeholder = ErrorBehavior::Replace("<p>Unavailable</p>".to_string());
+    let reader = epub.reader_with(EpubReaderSettings::new().on_error(placeholder));
+    let content = reader.fetch_page(2).unwrap().unwrap();
+    assert_eq!("<p>Unavailable</p>", content.as_lossy_str());
+    assert_eq!("c1", content.get_content(ContentType::Id).unwrap());
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn annotation_selector_test() {
+    let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+    let content = epub.reader().fetch_page(6).unwrap().unwrap();
+    let text = content.text().unwrap();
+
+    // Markup, entities, and the head are excluded from the text
+    assert!(!text.contains('<'));
+    assert!(!text.contains("Moby-Dick"));
+    assert_eq!(
+        "Chapter 1. Loomings.",
+        text.trim_start().lines().next().unwrap()
+    );
+
+    // Quote to position
+    let quote = TextQuoteSelector::new("Call me Ishmael.");
+    let position = quote.position(&text).unwrap();
+    assert_eq!(Some("Call me Ishmael."), position.exact(&text));
+
+    // Position to quote
+    let quote = position.quote(&text, 8).unwrap();
+    assert_eq!("Call me Ishmael.", quote.exact());
+    assert_eq!(8, quote.prefix().chars().count());
+    assert!(quote.suffix().starts_with(" Some"));
+
+    // Re-anchoring after text is inserted before the quote
+    let changed = format!("Inserted text. {text}");
+    let anchored = quote.position_near(&changed, position.start()).unwrap();
+    assert_eq!(position.start() + 15, anchored.start());
+    assert_eq!(Some("Call me Ishmael."), anchored.exact(&changed));
+
+    // Out of range or missing quotes
+    assert_eq!(
+        None,
+        TextPositionSelector::new(0, text.len() + 1).exact(&text)
+    );
+    assert_eq!(
+        None,
+        TextQuoteSelector::new("Call me Ahab.").position(&text)
+    );
+}
+
+#[test]
+fn text_map_test() {
+    let epub = rbook::Epub::new("tests/ebooks/content_epub").unwrap();
+    let content = epub.reader().fetch_page(0).unwrap().unwrap();
+    let data = content.as_lossy_str();
+    let text_map = content.text_map().unwrap();
+    let text = text_map.text();
+
+    assert_eq!(content.text().unwrap(), text);
+    assert_eq!(text.chars().count(), text_map.len());
+
+    // Plain characters map to themselves
+    let start = text[..text.find("rbook c1").unwrap()].chars().count();
+    let range = text_map.source_range(start, start + 8).unwrap();
+    assert_eq!("rbook c1", &data[range.clone()]);
+    assert_eq!(Some(start), text_map.text_position(range.start));
+    assert_eq!(Some(start + 7), text_map.text_position(range.end - 1));
+
+    // Character references span the entire reference
+    let ampersand = text[..text.find('&').unwrap()].chars().count();
+    let range = text_map.source_range(ampersand, ampersand + 1).unwrap();
+    assert_eq!("&amp;", &data[range.clone()]);
+    assert_eq!(Some(ampersand), text_map.text_position(range.start + 3));
+
+    let dash = text[..text.find('\u{2014}').unwrap()].chars().count();
+    let range = text_map.source_range(dash - 1, dash + 2).unwrap();
+    assert_eq!(" &#8212; ", &data[range]);
+
+    // Ranges spanning elements include their markup
+    let reading = text[..text.find("reading").unwrap()].chars().count();
+    let range = text_map.source_range(reading, reading + 8).unwrap();
+    assert!(data[range].starts_with("reading<a"));
+
+    // Markup and the head are not part of the text
+    assert_eq!(None, text_map.text_position(data.find("<h1>").unwrap()));
+    assert_eq!(None, text_map.text_position(data.find("Example").unwrap()));
+    assert_eq!(None, text_map.source_range(0, text_map.len() + 1));
+}
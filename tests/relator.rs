@@ -0,0 +1,41 @@
+#[cfg(feature = "relators")]
+mod relator {
+    use rbook::epub::Relator;
+    use rbook::Ebook;
+
+    #[test]
+    fn contributor_roles_test() {
+        let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+        let contributors = epub.metadata().contributors();
+        let roles = Relator::from_contributor(contributors[0]);
+
+        assert_eq!(1, roles.len());
+        assert_eq!("mrk", roles[0].code());
+        assert_eq!("Markup editor", roles[0].display_name());
+
+        let epub = rbook::Epub::new("tests/ebooks/example_epub").unwrap();
+        let creator = epub.metadata().creators()[0];
+        let roles: Vec<_> = Relator::from_contributor(creator)
+            .iter()
+            .map(Relator::display_name)
+            .map(str::to_string)
+            .collect();
+
+        assert_eq!(vec!["Author"], roles);
+    }
+
+    #[test]
+    fn relator_lookup_test() {
+        assert_eq!(Some("Illustrator"), Relator::new(" ILL ").name());
+        assert_eq!("ill", Relator::new(" ILL ").code());
+
+        // Codes that are not covered retain their raw code
+        let unknown = Relator::new("xyz");
+        assert_eq!(None, unknown.name());
+        assert_eq!("xyz", unknown.display_name());
+
+        let spanish = |code: &str| (code == "trl").then(|| "Traductor".to_string());
+        assert_eq!("Traductor", Relator::new("trl").localized_name(spanish));
+        assert_eq!("xyz", unknown.localized_name(spanish));
+    }
+}
@@ -0,0 +1,77 @@
+#[cfg(feature = "segmentation")]
+mod segmentation {
+    use rbook::Ebook;
+
+    #[test]
+    fn paragraph_test() {
+        let epub = rbook::Epub::new("tests/ebooks/content_epub").unwrap();
+        let content = epub.reader().fetch_page(0).unwrap().unwrap();
+        let text = content.text().unwrap();
+        let paragraphs = content.paragraphs().unwrap();
+
+        let texts: Vec<_> = paragraphs
+            .iter()
+            .map(|paragraph| paragraph.text())
+            .collect();
+        assert_eq!(
+            vec![
+                "rbook c1",
+                "Parsing & reading1.",
+                "See c2.",
+                "Footnote \u{2014} example"
+            ],
+            texts
+        );
+
+        // Positions refer to the extracted text
+        for paragraph in &paragraphs {
+            assert_eq!(Some(paragraph.text()), paragraph.position().exact(&text));
+        }
+    }
+
+    #[test]
+    fn sentence_test() {
+        let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+        let content = epub.reader().fetch_page(6).unwrap().unwrap();
+        let text = content.text().unwrap();
+        let sentences = content.sentences().unwrap();
+
+        assert_eq!("Call me Ishmael.", sentences[2].text());
+        assert!(sentences[3].text().starts_with("Some years ago—never mind"));
+        assert!(sentences[3].text().ends_with("part of the world."));
+
+        // Closing quotes belong to the sentence they close
+        assert!(sentences
+            .iter()
+            .any(|sentence| sentence.text() == "“BLOODY BATTLE IN AFFGHANISTAN.”"));
+
+        for sentence in &sentences {
+            assert_eq!(Some(sentence.text()), sentence.position().exact(&text));
+            assert_eq!(sentence.text().trim(), sentence.text());
+        }
+    }
+
+    #[test]
+    fn sentence_rules_test() {
+        let epub = rbook::Epub::new("tests/ebooks/content_epub").unwrap();
+        let content = epub.reader().fetch_page(1).unwrap().unwrap();
+        let sentences = content.sentences().unwrap();
+
+        let texts: Vec<_> = sentences.iter().map(|sentence| sentence.text()).collect();
+        assert_eq!(
+            vec![
+                // Sentences do not span paragraphs
+                "rbook c2",
+                // Abbreviations, initials, and lowercase continuations
+                "Mr. J. Example reads e.g. epub files.",
+                // Consecutive terminators and closing quotes
+                "Does it work?!",
+                "“Yes.”",
+                // Fullwidth terminators do not require a space
+                "It does。",
+                "次の文",
+            ],
+            texts
+        );
+    }
+}
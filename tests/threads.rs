@@ -22,4 +22,58 @@ mod multi_thread {
             .into_iter()
             .for_each(|handle| handle.join().unwrap());
     }
+
+    #[test]
+    fn concurrent_reader_test() {
+        let epub = Arc::new(rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap());
+        let expected: Vec<_> = epub
+            .reader()
+            .iter()
+            .map(|content| content.unwrap().to_vec())
+            .collect();
+        let expected = Arc::new(expected);
+        let mut handles = Vec::new();
+
+        for i in 0..4 {
+            let epub = Arc::clone(&epub);
+            let expected = Arc::clone(&expected);
+            handles.push(thread::spawn(move || {
+                let reader = epub.reader();
+
+                // Each thread reads a different portion of the spine first
+                for offset in 0..reader.page_count() {
+                    let index = (offset + i * 36) % reader.page_count();
+                    let content = reader.fetch_page(index).unwrap().unwrap();
+                    assert_eq!(expected[index], content.to_vec());
+                }
+            }));
+        }
+
+        handles
+            .into_iter()
+            .for_each(|handle| handle.join().unwrap());
+    }
+
+    #[test]
+    fn prefetch_reader_test() {
+        use rbook::epub::EpubReaderSettings;
+
+        let epub = rbook::Epub::new("tests/ebooks/moby-dick.epub").unwrap();
+        let expected = epub.reader();
+        let mut reader = epub.reader_with(EpubReaderSettings::new().prefetch(3));
+
+        while let Some(content) = reader.next_page() {
+            let index = reader.current_index();
+            assert_eq!(
+                expected.fetch_page(index).unwrap().unwrap(),
+                content.unwrap()
+            );
+        }
+
+        // Jumping outside the prefetch range
+        let content = reader.set_current_page(10).unwrap().unwrap();
+        assert_eq!(expected.fetch_page(10).unwrap().unwrap(), content);
+        let content = reader.next_page().unwrap().unwrap();
+        assert_eq!(expected.fetch_page(11).unwrap().unwrap(), content);
+    }
 }
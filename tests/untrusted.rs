@@ -0,0 +1,112 @@
+use rbook::epub::EpubSettings;
+use rbook::result::{ArchiveError, EbookError};
+use std::io::{Cursor, Write};
+use zip::write::FileOptions;
+
+const CONTAINER: &str = r#"<?xml version="1.0"?>
+<container version="1.0" xmlns="urn:oasis:names:tc:opendocument:xmlns:container">
+    <rootfiles>
+        <rootfile full-path="OEBPS/content.opf" media-type="application/oebps-package+xml"/>
+    </rootfiles>
+</container>"#;
+
+fn zip(files: &[(&str, &[u8])]) -> Vec<u8> {
+    let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+
+    for (name, data) in files {
+        writer.start_file(*name, FileOptions::default()).unwrap();
+        writer.write_all(data).unwrap();
+    }
+    writer.finish().unwrap().into_inner()
+}
+
+// Access everything that may be derived from an epub
+fn exercise(epub: &rbook::Epub) {
+    let _ = epub.metadata().title();
+    let _ = epub.metadata().creators();
+    let _ = epub.toc().elements_flat();
+    let _ = epub.cover_image();
+    let _ = epub.spine_weights();
+    let _ = epub.unreachable_content();
+
+    let reader = epub.reader();
+    let _ = reader.current_page();
+    for content in reader.iter().flatten() {
+        let _ = content.text_map();
+    }
+}
+
+#[test]
+fn malformed_input_test() {
+    let moby_dick = std::fs::read("tests/ebooks/moby-dick.epub").unwrap();
+    let truncated_package = br#"<package version="3.0" unique-identifier="id"><metadata><dc:title"#;
+    let missing_references = br#"<package version="3.0" unique-identifier="missing">
+        <metadata><dc:title>Title</dc:title></metadata>
+        <manifest>
+            <item id="nav" href="nav.xhtml" media-type="application/xhtml+xml" properties="nav"/>
+            <item id="a" href="" media-type="application/xhtml+xml" fallback="a"/>
+        </manifest>
+        <spine><itemref idref="missing"/><itemref idref="a"/><itemref/></spine>
+    </package>"#;
+    let empty_spine = br#"<package version="3.0"><metadata/><manifest>
+        <item id="nav" href="nav.xhtml" media-type="application/xhtml+xml" properties="nav"/>
+    </manifest><spine/></package>"#;
+
+    let inputs = [
+        Vec::new(),
+        b"not a zip archive".to_vec(),
+        moby_dick[..moby_dick.len() / 2].to_vec(),
+        zip(&[]),
+        zip(&[("META-INF/container.xml", b"<container><rootfiles>")]),
+        zip(&[("META-INF/container.xml", CONTAINER.as_bytes())]),
+        zip(&[
+            ("META-INF/container.xml", CONTAINER.as_bytes()),
+            ("OEBPS/content.opf", truncated_package),
+        ]),
+        zip(&[
+            ("META-INF/container.xml", CONTAINER.as_bytes()),
+            ("OEBPS/content.opf", missing_references),
+            ("OEBPS/nav.xhtml", b"<nav epub:type=\"toc\"><ol><li><a>"),
+        ]),
+        zip(&[
+            ("META-INF/container.xml", CONTAINER.as_bytes()),
+            ("OEBPS/content.opf", empty_spine),
+            ("OEBPS/nav.xhtml", b""),
+        ]),
+    ];
+
+    for input in inputs {
+        let settings = [
+            EpubSettings::new(),
+            EpubSettings::untrusted(),
+            EpubSettings::untrusted().lazy_toc(true),
+        ];
+
+        for settings in settings {
+            // Malformed epubs must fail gracefully or remain accessible
+            if let Ok(epub) = rbook::Epub::read_from_with(Cursor::new(input.clone()), settings) {
+                exercise(&epub);
+            }
+        }
+    }
+}
+
+#[test]
+fn max_file_size_test() {
+    // Highly compressible data expands far beyond its stored size
+    let data = zip(&[
+        ("META-INF/container.xml", CONTAINER.as_bytes()),
+        ("OEBPS/content.opf", &vec![b' '; 2_000_000]),
+    ]);
+    assert!(data.len() < 100_000);
+
+    let settings = EpubSettings::new().max_file_size(1_000_000);
+    let result = rbook::Epub::read_from_with(Cursor::new(data), settings);
+    assert!(matches!(
+        result,
+        Err(EbookError::Archive(ArchiveError::CannotRead { .. }))
+    ));
+
+    let epub = rbook::Epub::open_untrusted("tests/ebooks/childrens-literature.epub").unwrap();
+    assert!(epub.reader().iter().all(|content| content.is_ok()));
+}